@@ -1,12 +1,26 @@
 use std::{
-    io,
+    fs, io,
+    ops::RangeInclusive,
     path::{Path, PathBuf},
 };
 
-use chrono::{Days, NaiveDate};
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-
-use crate::journal::{EntryKind, Journal};
+use chrono::{Datelike, Days, Local, Months, NaiveDate, NaiveDateTime, TimeDelta};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Position, Rect};
+
+use crate::{
+    cache::JournalCache,
+    calendar::CalendarPicker,
+    dates::{parse_date_prefix, parse_time_range_prefix, DateExpr},
+    day_index::DayIndex,
+    journal::{EntryKind, EntryState, Journal, JournalEntry, TimeRange},
+    keymap::{key_name, KeyAction, Keymap},
+    links::{expand_home, find_links, open_command},
+    notes::{new_note_contents, next_note_name, note_path, remove_unedited_note, LoadedNote},
+    recurring::{Recurrence, RecurringRule, RecurringRules},
+    reminders::{reminders_for, Reminder, ReminderScheduler, ReminderSettings},
+    settings::Settings,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Focus {
@@ -26,9 +40,26 @@ pub enum CommandAction {
     Add(EntryKind),
     Quit,
     Split,
+    Week,
+    Pane,
+    Goto,
+    Calendar,
     Complete,
     Cancel,
     Important,
+    Move,
+    Copy,
+    Timestamps,
+    Timeline,
+    Recurring,
+    Snooze,
+    Dismiss,
+    Messages,
+    Help,
+    Open,
+    Clip,
+    Editor,
+    Details,
 }
 
 impl CommandAction {
@@ -41,9 +72,26 @@ impl CommandAction {
             CommandAction::Add(EntryKind::Raw) => None,
             CommandAction::Quit => Some(":q"),
             CommandAction::Split => Some(":split"),
+            CommandAction::Week => Some(":week"),
+            CommandAction::Pane => Some(":pane"),
+            CommandAction::Goto => Some(":goto"),
+            CommandAction::Calendar => Some(":cal"),
             CommandAction::Complete => Some(":x"),
             CommandAction::Cancel => Some(":c"),
             CommandAction::Important => Some(":i"),
+            CommandAction::Move => Some(":move"),
+            CommandAction::Copy => Some(":copy"),
+            CommandAction::Timestamps => Some(":times"),
+            CommandAction::Timeline => Some(":timeline"),
+            CommandAction::Recurring => Some(":recur"),
+            CommandAction::Snooze => Some(":snooze"),
+            CommandAction::Dismiss => Some(":dismiss"),
+            CommandAction::Messages => Some(":messages"),
+            CommandAction::Help => Some(":help"),
+            CommandAction::Open => Some(":open"),
+            CommandAction::Clip => Some(":clip"),
+            CommandAction::Editor => Some(":editor"),
+            CommandAction::Details => Some(":details"),
         }
     }
 
@@ -53,19 +101,235 @@ impl CommandAction {
             CommandAction::Add(EntryKind::Event) => "Enter an event",
             CommandAction::Add(EntryKind::Feeling) => "Enter a feeling",
             CommandAction::Add(EntryKind::Task) => "Enter a task",
+            CommandAction::Pane => "Enter a date to show",
+            CommandAction::Goto => "Enter a date to go to",
             _ => "Command",
         }
     }
 }
 
+/// A new entry parsed from an entry command, before it is written to a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryDraft {
+    pub kind: EntryKind,
+    pub text: String,
+    pub date: Option<DateExpr>,
+    pub time: Option<TimeRange>,
+}
+
+impl EntryDraft {
+    pub fn new(kind: EntryKind, text: impl Into<String>) -> Self {
+        Self {
+            kind,
+            text: text.into(),
+            date: None,
+            time: None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Command {
-    Add(EntryKind, String),
+    Add(EntryDraft),
     Quit,
     ToggleSplit,
+    ToggleWeek,
     Complete,
     Cancel,
     Important,
+    Move(Option<DateExpr>),
+    Copy(Option<DateExpr>),
+    ToggleTimestamps,
+    ToggleTimeline,
+    Recur(RecurCommand),
+    Pane(PaneCommand),
+    Goto(DateExpr),
+    OpenCalendar,
+    Snooze(Option<u32>),
+    Dismiss,
+    ShowMessages,
+    ShowHelp,
+    Open,
+    Clip,
+    Editor,
+    EditDetails,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TransferMode {
+    Move,
+    Copy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaneCommand {
+    Add(DateExpr),
+    Set(DateExpr),
+    Close,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecurCommand {
+    List,
+    Add {
+        recurrence: Recurrence,
+        draft: EntryDraft,
+    },
+    Remove(String),
+}
+
+/// Work for the terminal loop that the app cannot do itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Effect {
+    Bell,
+    /// Runs `command` with `sh -c`, passing `argument` as `$1`.
+    RunCommand {
+        command: String,
+        argument: String,
+    },
+    /// Opens a link with the opener `command`, passing `link` as `$1`.
+    Open {
+        command: String,
+        link: String,
+    },
+    /// Puts text on the terminal's clipboard.
+    Copy(String),
+    /// Hands the terminal to the user's editor for the day file at `path`.
+    /// The day is reloaded with `reload_day` when the editor exits.
+    Edit {
+        path: PathBuf,
+        date: NaiveDate,
+    },
+    /// Hands the terminal to the user's editor for the detail note at `path`.
+    /// The preview notices the change on the next tick.
+    EditNote(PathBuf),
+}
+
+/// How a status message is styled in the status bar and message history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    /// The command could not be carried out as asked, but nothing failed.
+    Warning,
+    /// Something failed, such as reading or writing a file.
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    pub text: String,
+    pub severity: Severity,
+    /// How many times in a row the message was set.
+    pub repeats: usize,
+}
+
+impl Message {
+    fn new(text: impl Into<String>, severity: Severity) -> Self {
+        Self {
+            text: text.into(),
+            severity,
+            repeats: 1,
+        }
+    }
+}
+
+/// A popup drawn over the journal and command panes. It takes all key input
+/// until it is closed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Overlay {
+    Recurring(Vec<RecurringRule>),
+    Calendar(CalendarPicker),
+    /// The message history, `scroll` messages up from the latest.
+    Messages {
+        scroll: usize,
+    },
+    /// The leader menu, after the leader key and then `keys`.
+    Leader {
+        keys: String,
+    },
+    /// Keys and commands, `scroll` lines down from the top.
+    Help {
+        sections: Vec<HelpSection>,
+        scroll: usize,
+    },
+    /// The links in the highlighted entry, for `Enter` to open or, when
+    /// `copy` is set, copy.
+    Links {
+        links: Vec<String>,
+        selected: usize,
+        copy: bool,
+    },
+}
+
+/// Something on screen that responds to the mouse. Panes are numbered as in
+/// split view; a single journal is pane 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HitTarget {
+    Pane(usize),
+    Entry {
+        pane: usize,
+        index: usize,
+    },
+    /// A command search result, by its index among all matches.
+    CommandResult(usize),
+}
+
+/// Where each mouse target was drawn, recorded by the last draw.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HitMap {
+    targets: Vec<(Rect, HitTarget)>,
+}
+
+impl HitMap {
+    pub fn push(&mut self, area: Rect, target: HitTarget) {
+        self.targets.push((area, target));
+    }
+
+    /// The target at a cell. Targets pushed later were drawn on top, so they
+    /// win, as an entry does over the pane around it.
+    pub fn target_at(&self, column: u16, row: u16) -> Option<HitTarget> {
+        self.targets
+            .iter()
+            .rev()
+            .find(|(area, _)| area.contains(Position::new(column, row)))
+            .map(|(_, target)| *target)
+    }
+}
+
+/// Keys typed in the journal pane that wait for more, such as the `5` of `5j`
+/// or the first `d` of `dd`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct PendingKeys {
+    count: Option<usize>,
+    /// A doubled action and the key that started it.
+    operator: Option<(KeyAction, KeyCode)>,
+}
+
+/// The days as they were before a change, saved back by `u`.
+#[derive(Debug, Clone)]
+struct UndoStep {
+    action: &'static str,
+    journals: Vec<Journal>,
+    selected: Option<usize>,
+    /// A detail note the change created and what it was created with. Undo
+    /// removes it unless it has been written in since.
+    created_note: Option<(PathBuf, String)>,
+}
+
+/// A row of the leader menu: a command, or a group that opens another level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeaderMenuItem {
+    pub key: char,
+    pub label: &'static str,
+    pub is_group: bool,
+}
+
+/// A titled group of rows in the help popup. Each row is a key or command and
+/// what it does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelpSection {
+    pub title: String,
+    pub rows: Vec<(String, &'static str)>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -79,6 +343,11 @@ struct CommandOption {
     name: &'static str,
     token: &'static str,
     aliases: &'static [&'static str],
+    /// The keys that pick the command after the leader key, one per menu
+    /// level. Groups are listed in `LEADER_GROUPS`.
+    leader: &'static str,
+    /// What the command does, shown in the help popup.
+    summary: &'static str,
     action: CommandAction,
 }
 
@@ -88,10 +357,25 @@ pub struct CommandSearchResult {
     pub token: &'static str,
 }
 
+/// How a multi-pane view was opened. It decides how far every pane moves when
+/// navigation passes the first or last pane.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum SplitPane {
-    Older,
-    Newer,
+pub enum SplitLayout {
+    /// The `:split` preset: two consecutive days, shifting one day at a time.
+    Days,
+    /// Monday to Sunday, shifting a week at a time.
+    Week,
+    /// Panes added or changed with `:pane`, shifting one day at a time.
+    Custom,
+}
+
+impl SplitLayout {
+    fn step(self) -> usize {
+        match self {
+            SplitLayout::Days | SplitLayout::Custom => 1,
+            SplitLayout::Week => 7,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -102,9 +386,9 @@ pub struct JournalPane {
 
 #[derive(Debug, Clone)]
 pub struct SplitJournalView {
-    pub older: JournalPane,
-    pub newer: JournalPane,
-    pub active: SplitPane,
+    pub panes: Vec<JournalPane>,
+    pub active: usize,
+    pub layout: SplitLayout,
 }
 
 impl JournalPane {
@@ -116,26 +400,16 @@ impl JournalPane {
 }
 
 impl SplitJournalView {
-    pub fn pane(&self, pane: SplitPane) -> &JournalPane {
-        match pane {
-            SplitPane::Older => &self.older,
-            SplitPane::Newer => &self.newer,
-        }
-    }
-
-    fn pane_mut(&mut self, pane: SplitPane) -> &mut JournalPane {
-        match pane {
-            SplitPane::Older => &mut self.older,
-            SplitPane::Newer => &mut self.newer,
-        }
-    }
-
     pub fn active_pane(&self) -> &JournalPane {
-        self.pane(self.active)
+        &self.panes[self.active]
     }
 
     fn active_pane_mut(&mut self) -> &mut JournalPane {
-        self.pane_mut(self.active)
+        &mut self.panes[self.active]
+    }
+
+    fn into_active_pane(mut self) -> JournalPane {
+        self.panes.swap_remove(self.active)
     }
 }
 
@@ -147,49 +421,188 @@ enum MatchQuality {
 
 const COMMAND_SEARCH_RESULT_LIMIT: usize = 5;
 
+const DEFAULT_SNOOZE_MINUTES: u32 = 10;
+
+/// The largest count accepted before a journal-pane key, as in `5j`.
+const MAX_COUNT: usize = 999;
+
+/// The longest gap between two clicks on one target that makes a double-click.
+const DOUBLE_CLICK_MILLISECONDS: i64 = 500;
+
+/// How many changes `u` can undo.
+const UNDO_LIMIT: usize = 100;
+
+/// How many status messages `:messages` can scroll back through.
+const MESSAGE_HISTORY_LIMIT: usize = 200;
+
+/// How many messages `PageUp` and `PageDown` scroll in the message history.
+const MESSAGE_PAGE: usize = 10;
+
+/// Names for the nested levels of the leader menu, by the keys that open them.
+const LEADER_GROUPS: &[(&str, &str)] = &[("g", "go to"), ("v", "view"), ("r", "reminders")];
+
 const COMMAND_PANE_OPTIONS: &[CommandOption] = &[
     CommandOption {
         name: "note",
         token: ":n",
         aliases: &["n", "new note"],
+        leader: "n",
+        summary: "Add a note",
         action: CommandAction::Add(EntryKind::Note),
     },
     CommandOption {
         name: "event",
         token: ":e",
         aliases: &["e", "calendar"],
+        leader: "e",
+        summary: "Add an event",
         action: CommandAction::Add(EntryKind::Event),
     },
     CommandOption {
         name: "feeling",
         token: ":f",
         aliases: &["f", "mood"],
+        leader: "f",
+        summary: "Add a feeling",
         action: CommandAction::Add(EntryKind::Feeling),
     },
     CommandOption {
         name: "task",
         token: ":t",
         aliases: &["t", "todo"],
+        leader: "t",
+        summary: "Add a task",
         action: CommandAction::Add(EntryKind::Task),
     },
     CommandOption {
         name: "quit",
         token: ":q",
         aliases: &["q", "exit"],
+        leader: "q",
+        summary: "Quit",
         action: CommandAction::Quit,
     },
     CommandOption {
         name: "split",
         token: ":split",
         aliases: &["compare", "dual"],
+        leader: "vs",
+        summary: "Toggle the two-day view",
         action: CommandAction::Split,
     },
+    CommandOption {
+        name: "week",
+        token: ":week",
+        aliases: &["plan", "seven days"],
+        leader: "vw",
+        summary: "Toggle the week view",
+        action: CommandAction::Week,
+    },
+    CommandOption {
+        name: "pane",
+        token: ":pane",
+        aliases: &["add pane", "open day"],
+        leader: "vp",
+        summary: "Add a pane for another day",
+        action: CommandAction::Pane,
+    },
+    CommandOption {
+        name: "goto",
+        token: ":goto",
+        aliases: &["jump", "go to date"],
+        leader: "gd",
+        summary: "Go to a date",
+        action: CommandAction::Goto,
+    },
+    CommandOption {
+        name: "calendar",
+        token: ":cal",
+        aliases: &["cal", "date picker"],
+        leader: "gc",
+        summary: "Open the month calendar",
+        action: CommandAction::Calendar,
+    },
+    CommandOption {
+        name: "timestamps",
+        token: ":times",
+        aliases: &["times", "clock"],
+        leader: "vt",
+        summary: "Show or hide entry times",
+        action: CommandAction::Timestamps,
+    },
+    CommandOption {
+        name: "timeline",
+        token: ":timeline",
+        aliases: &["agenda", "schedule"],
+        leader: "vl",
+        summary: "Switch between the entries and the timeline",
+        action: CommandAction::Timeline,
+    },
+    CommandOption {
+        name: "recurring",
+        token: ":recur",
+        aliases: &["recur", "repeat"],
+        leader: "vr",
+        summary: "List or add recurring entries",
+        action: CommandAction::Recurring,
+    },
+    CommandOption {
+        name: "snooze",
+        token: ":snooze",
+        aliases: &["remind later"],
+        leader: "rs",
+        summary: "Snooze the shown reminders",
+        action: CommandAction::Snooze,
+    },
+    CommandOption {
+        name: "dismiss",
+        token: ":dismiss",
+        aliases: &["reminders done"],
+        leader: "rd",
+        summary: "Dismiss the shown reminders",
+        action: CommandAction::Dismiss,
+    },
+    CommandOption {
+        name: "messages",
+        token: ":messages",
+        aliases: &["history", "log"],
+        leader: "vm",
+        summary: "Show the message history",
+        action: CommandAction::Messages,
+    },
+    CommandOption {
+        name: "editor",
+        token: ":editor",
+        aliases: &["edit"],
+        leader: "E",
+        summary: "Edit the focused day in $VISUAL or $EDITOR",
+        action: CommandAction::Editor,
+    },
+    CommandOption {
+        name: "help",
+        token: ":help",
+        aliases: &["?", "keys"],
+        leader: "?",
+        summary: "Show keys and commands",
+        action: CommandAction::Help,
+    },
+];
+
+/// Entry actions in the order they are offered for a range of entries.
+const ENTRY_ACTION_OPTIONS: [&CommandOption; 5] = [
+    &COMPLETE_COMMAND_OPTION,
+    &CANCEL_COMMAND_OPTION,
+    &IMPORTANT_COMMAND_OPTION,
+    &MOVE_COMMAND_OPTION,
+    &COPY_COMMAND_OPTION,
 ];
 
 const COMPLETE_COMMAND_OPTION: CommandOption = CommandOption {
     name: "complete",
     token: ":x",
     aliases: &["x", "done"],
+    leader: "x",
+    summary: "Complete or reopen the task",
     action: CommandAction::Complete,
 };
 
@@ -197,6 +610,8 @@ const CANCEL_COMMAND_OPTION: CommandOption = CommandOption {
     name: "cancel",
     token: ":c",
     aliases: &["c", "cancelled"],
+    leader: "c",
+    summary: "Cancel or reopen the entry",
     action: CommandAction::Cancel,
 };
 
@@ -204,9 +619,56 @@ const IMPORTANT_COMMAND_OPTION: CommandOption = CommandOption {
     name: "important",
     token: ":i",
     aliases: &["i"],
+    leader: "i",
+    summary: "Mark or unmark the entry as important",
     action: CommandAction::Important,
 };
 
+const MOVE_COMMAND_OPTION: CommandOption = CommandOption {
+    name: "move",
+    token: ":move",
+    aliases: &["migrate"],
+    leader: "m",
+    summary: "Move the entry to another day",
+    action: CommandAction::Move,
+};
+
+const COPY_COMMAND_OPTION: CommandOption = CommandOption {
+    name: "copy",
+    token: ":copy",
+    aliases: &["duplicate"],
+    leader: "y",
+    summary: "Copy the entry to another day",
+    action: CommandAction::Copy,
+};
+
+const OPEN_COMMAND_OPTION: CommandOption = CommandOption {
+    name: "open",
+    token: ":open",
+    aliases: &["url", "link"],
+    leader: "o",
+    summary: "Open a URL or path from the entry",
+    action: CommandAction::Open,
+};
+
+const CLIP_COMMAND_OPTION: CommandOption = CommandOption {
+    name: "clip",
+    token: ":clip",
+    aliases: &["clipboard"],
+    leader: "l",
+    summary: "Copy a URL or path from the entry to the clipboard",
+    action: CommandAction::Clip,
+};
+
+const DETAILS_COMMAND_OPTION: CommandOption = CommandOption {
+    name: "details",
+    token: ":details",
+    aliases: &["body", "detail"],
+    leader: "d",
+    summary: "Edit the entry's detail note in $VISUAL or $EDITOR",
+    action: CommandAction::Details,
+};
+
 #[derive(Debug)]
 pub struct App {
     pub journal: Journal,
@@ -215,9 +677,38 @@ pub struct App {
     pub command_input: String,
     pub command_result_index: usize,
     pub selected: Option<usize>,
-    pub status: String,
     pub should_quit: bool,
+    pub show_timestamps: bool,
+    pub show_timeline: bool,
+    pub overlay: Option<Overlay>,
+    /// Reminders that have fired and are shown until snoozed or dismissed.
+    pub reminders: Vec<Reminder>,
+    pub clock: fn() -> NaiveDateTime,
     journal_root: PathBuf,
+    cache: JournalCache,
+    day_index: DayIndex,
+    /// The recurring rules, read once and applied to each day as it loads.
+    rules: RecurringRules,
+    reminder_settings: ReminderSettings,
+    /// The shell command that opens links, with the link as `$1`.
+    open_command: String,
+    /// The detail note of the highlighted entry, read by `tick`.
+    detail_note: Option<LoadedNote>,
+    keymap: Keymap,
+    pending: PendingKeys,
+    /// Entries yanked or deleted most recently, pasted by `p`.
+    register: Vec<JournalEntry>,
+    /// Whether the register was deleted rather than yanked. Pasting deleted
+    /// entries moves them, so they keep their detail notes.
+    register_cut: bool,
+    undo: Vec<UndoStep>,
+    /// Where visual mode started: the day and the entry index.
+    visual: Option<(NaiveDate, usize)>,
+    /// The last click, kept to tell a double-click.
+    last_click: Option<(NaiveDateTime, HitTarget)>,
+    scheduler: ReminderScheduler,
+    effects: Vec<Effect>,
+    messages: Vec<Message>,
     today: NaiveDate,
     split: Option<SplitJournalView>,
     command_context: CommandContext,
@@ -225,10 +716,29 @@ pub struct App {
 
 // App construction and top-level input routing.
 impl App {
+    /// Starts the app on `journal`, which should already hold its recurring
+    /// occurrences.
     pub fn new(journal: Journal) -> Self {
-        let selected = last_entry_index(&journal);
         let journal_root = journal_root(&journal);
         let today = journal.date;
+        let (settings, status) = match Settings::load(&journal_root) {
+            Ok(settings) => (settings, Message::new("Ready.", Severity::Info)),
+            Err(error) => (
+                Settings::default(),
+                Message::new(format!("Could not read settings: {error}"), Severity::Error),
+            ),
+        };
+        let (rules, status) = match RecurringRules::load(&journal_root) {
+            Ok(rules) => (rules, status),
+            Err(error) => (
+                RecurringRules::empty(&journal_root),
+                Message::new(
+                    format!("Could not read recurring rules: {error}"),
+                    Severity::Error,
+                ),
+            ),
+        };
+        let selected = last_entry_index(&journal);
 
         Self {
             journal,
@@ -237,8 +747,28 @@ impl App {
             command_input: String::new(),
             command_result_index: 0,
             selected,
-            status: String::from("Ready."),
             should_quit: false,
+            show_timestamps: false,
+            show_timeline: false,
+            overlay: None,
+            reminders: Vec::new(),
+            clock: local_now,
+            cache: JournalCache::new(&journal_root),
+            day_index: DayIndex::new(&journal_root),
+            rules,
+            reminder_settings: ReminderSettings::from_settings(&settings),
+            open_command: open_command(&settings),
+            detail_note: None,
+            keymap: Keymap::from_settings(&settings),
+            pending: PendingKeys::default(),
+            register: Vec::new(),
+            register_cut: false,
+            undo: Vec::new(),
+            visual: None,
+            last_click: None,
+            scheduler: ReminderScheduler::default(),
+            effects: Vec::new(),
+            messages: vec![status],
             journal_root,
             today,
             split: None,
@@ -247,12 +777,113 @@ impl App {
     }
 
     pub fn handle_key(&mut self, key: KeyEvent) -> io::Result<()> {
+        if let Some(Overlay::Leader { keys }) = &self.overlay {
+            let keys = keys.clone();
+            return self.handle_leader_key(keys, key);
+        }
+
+        if self.overlay.is_some() {
+            self.handle_overlay_key(key);
+            return Ok(());
+        }
+
         match self.focus {
             Focus::Command => self.handle_command_key(key),
             Focus::Journal => self.handle_journal_key(key),
         }
     }
 
+    fn handle_overlay_key(&mut self, key: KeyEvent) {
+        if let Some(Overlay::Calendar(picker)) = &mut self.overlay {
+            let root = &self.journal_root;
+            match key.code {
+                KeyCode::Left | KeyCode::Char('h') => picker.move_days(root, -1),
+                KeyCode::Right | KeyCode::Char('l') => picker.move_days(root, 1),
+                KeyCode::Up | KeyCode::Char('k') => picker.move_days(root, -7),
+                KeyCode::Down | KeyCode::Char('j') => picker.move_days(root, 7),
+                KeyCode::PageUp | KeyCode::Char('<') => picker.move_months(root, -1),
+                KeyCode::PageDown | KeyCode::Char('>') => picker.move_months(root, 1),
+                KeyCode::Char('t') => picker.select(root, self.today),
+                KeyCode::Enter => {
+                    let date = picker.selected;
+                    self.overlay = None;
+                    self.go_to_date(date);
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(Overlay::Messages { scroll }) = &mut self.overlay {
+            let last = self.messages.len().saturating_sub(1);
+            *scroll = match key.code {
+                KeyCode::Up | KeyCode::Char('k') => (*scroll + 1).min(last),
+                KeyCode::Down | KeyCode::Char('j') => scroll.saturating_sub(1),
+                KeyCode::PageUp => (*scroll + MESSAGE_PAGE).min(last),
+                KeyCode::PageDown => scroll.saturating_sub(MESSAGE_PAGE),
+                KeyCode::Home => last,
+                KeyCode::End => 0,
+                _ => *scroll,
+            };
+        }
+
+        if let Some(Overlay::Help { sections, scroll }) = &mut self.overlay {
+            let last = help_line_count(sections).saturating_sub(1);
+            *scroll = match key.code {
+                KeyCode::Up | KeyCode::Char('k') => scroll.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => (*scroll + 1).min(last),
+                KeyCode::PageUp => scroll.saturating_sub(MESSAGE_PAGE),
+                KeyCode::PageDown => (*scroll + MESSAGE_PAGE).min(last),
+                KeyCode::Home => 0,
+                KeyCode::End => last,
+                _ => *scroll,
+            };
+        }
+
+        if let Some(Overlay::Links {
+            links,
+            selected,
+            copy,
+        }) = &mut self.overlay
+        {
+            let last = links.len().saturating_sub(1);
+            let link = links[*selected].clone();
+            let copy = match key.code {
+                KeyCode::Up | KeyCode::Char('k') => {
+                    *selected = selected.saturating_sub(1);
+                    None
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    *selected = (*selected + 1).min(last);
+                    None
+                }
+                KeyCode::Enter => Some(*copy),
+                KeyCode::Char('o') => Some(false),
+                KeyCode::Char('y') => Some(true),
+                _ => None,
+            };
+            if let Some(copy) = copy {
+                self.overlay = None;
+                self.focus = Focus::Journal;
+                if copy {
+                    self.copy_link(link);
+                } else {
+                    self.open_link(link);
+                }
+                return;
+            }
+        }
+
+        let closes = match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => true,
+            KeyCode::Char('?') => matches!(self.overlay, Some(Overlay::Help { .. })),
+            _ => false,
+        };
+        if closes && self.overlay.is_some() {
+            self.overlay = None;
+            self.focus_journal();
+        }
+    }
+
     fn handle_command_key(&mut self, key: KeyEvent) -> io::Result<()> {
         match self.command_mode {
             CommandPaneMode::Normal => self.handle_normal_command_key(key),
@@ -296,7 +927,7 @@ impl App {
 
                 let matches = self.matching_command_options();
                 let Some(command) = matches.get(self.command_result_index).copied() else {
-                    self.status = String::from("No matching commands.");
+                    self.set_warning("No matching commands.");
                     return Ok(());
                 };
 
@@ -305,14 +936,14 @@ impl App {
             KeyCode::Backspace => {
                 self.command_input.pop();
                 self.normalize_command_result_index();
-                self.status = String::from("Search commands.");
+                self.set_status("Search commands.");
             }
             KeyCode::Up => self.select_previous_command_result(),
             KeyCode::Down => self.select_next_command_result(),
             KeyCode::Char(character) if is_text_input(key.modifiers) => {
                 self.command_input.push(character);
                 self.normalize_command_result_index();
-                self.status = String::from("Search commands.");
+                self.set_status("Search commands.");
             }
             _ => {}
         }
@@ -339,1721 +970,5217 @@ impl App {
         Ok(())
     }
 
+    /// Routes journal-pane keys through the keymap, so remapped keys behave
+    /// exactly like the defaults. Unbound digits build a count for the next
+    /// key, and doubled actions such as `dd` wait for their second press.
+    /// `Esc` drops pending keys and refocuses the journal.
     fn handle_journal_key(&mut self, key: KeyEvent) -> io::Result<()> {
-        match key.code {
-            KeyCode::Esc => self.focus_journal(),
-            KeyCode::Up => self.select_previous(),
-            KeyCode::Char('k') if is_unmodified_key(key.modifiers) => self.select_previous(),
-            KeyCode::Down => self.select_next(),
-            KeyCode::Char('j') if is_unmodified_key(key.modifiers) => self.select_next(),
-            KeyCode::Left => self.navigate_left()?,
-            KeyCode::Char('h') if is_unmodified_key(key.modifiers) => self.navigate_left()?,
-            KeyCode::Right => self.navigate_right()?,
-            KeyCode::Char('l') if is_unmodified_key(key.modifiers) => self.navigate_right()?,
-            KeyCode::Char(character) if opens_journal_command_search(character, key.modifiers) => {
-                self.open_command_search(CommandContext::JournalPane);
+        let pending = std::mem::take(&mut self.pending);
+        if key.code == KeyCode::Esc {
+            self.visual = None;
+            self.focus_journal();
+            return Ok(());
+        }
+
+        let action = self.keymap.action_for(key);
+        if let (None, KeyCode::Char(digit @ '0'..='9')) = (action, key.code) {
+            if is_text_input(key.modifiers)
+                && pending.operator.is_none()
+                && (digit != '0' || pending.count.is_some())
+            {
+                let digit = digit.to_digit(10).unwrap_or(0) as usize;
+                let count = pending.count.unwrap_or(0) * 10 + digit;
+                self.pending.count = Some(count.min(MAX_COUNT));
             }
-            _ => {}
+            return Ok(());
+        }
+
+        let Some(action) = action else {
+            return Ok(());
+        };
+        match pending.operator {
+            Some((operator, _)) if operator == action => {}
+            Some(_) => return Ok(()),
+            None if action.is_doubled()
+                && !(self.visual_range().is_some()
+                    && matches!(action, KeyAction::Delete | KeyAction::Yank)) =>
+            {
+                self.pending = PendingKeys {
+                    count: pending.count,
+                    operator: Some((action, key.code)),
+                };
+                return Ok(());
+            }
+            None => {}
+        }
+
+        self.run_key_action(action, pending.count)?;
+        if self.visual_range().is_none() {
+            self.visual = None;
+        }
+        Ok(())
+    }
+
+    fn run_key_action(&mut self, action: KeyAction, count: Option<usize>) -> io::Result<()> {
+        if self.reload_changed_days()? {
+            return Ok(());
+        }
+
+        let times = count.unwrap_or(1);
+        match action {
+            KeyAction::PreviousEntry => (0..times).for_each(|_| self.select_previous()),
+            KeyAction::NextEntry => (0..times).for_each(|_| self.select_next()),
+            KeyAction::FirstEntry => self.select_entry(count.map_or(0, |count| count - 1)),
+            KeyAction::LastEntry => self.select_entry(count.map_or(usize::MAX, |count| count - 1)),
+            KeyAction::PreviousSection => (0..times).for_each(|_| self.select_section(false)),
+            KeyAction::NextSection => (0..times).for_each(|_| self.select_section(true)),
+            KeyAction::PreviousDay if self.split.is_some() => {
+                self.navigate_split(-(times as i64));
+            }
+            KeyAction::NextDay if self.split.is_some() => self.navigate_split(times as i64),
+            KeyAction::PreviousDay if count.is_some() => self.jump_days(-(times as i64)),
+            KeyAction::NextDay if count.is_some() => self.jump_days(times as i64),
+            KeyAction::PreviousDay => self.switch_to_previous_day(),
+            KeyAction::NextDay => self.switch_to_next_day(),
+            KeyAction::WeekBack => self.jump_days(-7 * times as i64),
+            KeyAction::WeekForward => self.jump_days(7 * times as i64),
+            KeyAction::MonthBack => self.jump_months(-(times as i32)),
+            KeyAction::MonthForward => self.jump_months(times as i32),
+            KeyAction::Today => self.go_to_date(self.today),
+            KeyAction::Calendar => self.open_calendar(),
+            KeyAction::PreviousDayFile => (0..times).for_each(|_| self.jump_to_day_file(false)),
+            KeyAction::NextDayFile => (0..times).for_each(|_| self.jump_to_day_file(true)),
+            KeyAction::Delete => self.delete_entries(times)?,
+            KeyAction::Yank => self.yank_entries(times),
+            KeyAction::Paste => self.paste_entries(times)?,
+            KeyAction::Undo => {
+                for _ in 0..times {
+                    self.undo()?;
+                }
+            }
+            KeyAction::Visual => self.toggle_visual_mode(),
+            KeyAction::Search => self.open_command_search(CommandContext::JournalPane),
+            KeyAction::Leader => self.open_leader_menu(),
+            KeyAction::Help => self.open_help(),
         }
 
         Ok(())
     }
 }
 
-// Journal focus and date navigation.
+// Mouse input, mapped to targets through the hit map of the last draw.
 impl App {
-    fn navigate_left(&mut self) -> io::Result<()> {
-        if self.split.is_some() {
-            self.navigate_split_left()
-        } else {
-            self.switch_to_previous_day();
-            Ok(())
+    /// Clicking a pane focuses it, clicking an entry highlights it and
+    /// clicking a command result selects it. Double-clicking an entry opens
+    /// its actions in the command search, and double-clicking a result runs
+    /// it. The wheel moves the highlight in the list under the pointer. Popups
+    /// take no mouse input.
+    pub fn handle_mouse(&mut self, mouse: MouseEvent, hits: &HitMap) -> io::Result<()> {
+        if self.overlay.is_some() {
+            return Ok(());
+        }
+
+        let target = hits.target_at(mouse.column, mouse.row);
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let Some(target) = target else {
+                    return Ok(());
+                };
+                let now = self.now();
+                let double = self.last_click.is_some_and(|(at, last)| {
+                    last == target && now - at <= TimeDelta::milliseconds(DOUBLE_CLICK_MILLISECONDS)
+                });
+                self.last_click = (!double).then_some((now, target));
+                self.click(target, double)
+            }
+            MouseEventKind::ScrollUp => {
+                self.scroll(target, false);
+                Ok(())
+            }
+            MouseEventKind::ScrollDown => {
+                self.scroll(target, true);
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn click(&mut self, target: HitTarget, double: bool) -> io::Result<()> {
+        match target {
+            HitTarget::Pane(pane) => self.focus_journal_pane(pane),
+            HitTarget::Entry { pane, index } => {
+                self.focus_journal_pane(pane);
+                self.select_entry(index);
+                if double {
+                    self.open_command_search(CommandContext::JournalPane);
+                }
+            }
+            HitTarget::CommandResult(index) => {
+                self.command_result_index = index;
+                self.normalize_command_result_index();
+                if double {
+                    let matches = self.matching_command_options();
+                    if let Some(command) = matches.get(self.command_result_index).copied() {
+                        self.select_command(command)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn scroll(&mut self, target: Option<HitTarget>, down: bool) {
+        match target {
+            Some(HitTarget::CommandResult(_)) if down => self.select_next_command_result(),
+            Some(HitTarget::CommandResult(_)) => self.select_previous_command_result(),
+            Some(HitTarget::Pane(pane) | HitTarget::Entry { pane, .. }) => {
+                self.focus_journal_pane(pane);
+                self.scroll_journal(down);
+            }
+            None if self.focus == Focus::Journal => self.scroll_journal(down),
+            None => {}
         }
     }
 
-    fn navigate_right(&mut self) -> io::Result<()> {
-        if self.split.is_some() {
-            self.navigate_split_right()
+    fn scroll_journal(&mut self, down: bool) {
+        if down {
+            self.select_next();
         } else {
-            self.switch_to_next_day();
-            Ok(())
+            self.select_previous();
         }
     }
 
-    fn switch_to_previous_day(&mut self) {
-        let Some(date) = self.journal.date.checked_sub_days(Days::new(1)) else {
-            self.status = String::from("Cannot switch before the supported date range.");
-            return;
-        };
+    /// Moves focus to a journal pane, leaving the command pane as `Esc` does.
+    fn focus_journal_pane(&mut self, pane: usize) {
+        self.pending = PendingKeys::default();
+        if self.focus == Focus::Command {
+            self.reset_command_pane();
+            self.focus_journal();
+        }
 
-        self.switch_to_day(date);
+        let active = self.split.as_ref().map(|split| split.active);
+        if active.is_some_and(|active| active != pane) {
+            self.visual = None;
+            self.set_active_split_pane(pane);
+        }
     }
+}
 
-    fn switch_to_next_day(&mut self) {
-        let Some(date) = self.journal.date.checked_add_days(Days::new(1)) else {
-            self.status = String::from("Cannot switch after the supported date range.");
+// Opening and copying the links in an entry.
+impl App {
+    /// Opens or copies the link in the highlighted entry, or offers a picker
+    /// when it has several.
+    fn follow_links(&mut self, copy: bool) {
+        let Some(index) = self.highlighted_entry_index() else {
+            self.set_warning("No entry selected.");
             return;
         };
 
-        self.switch_to_day(date);
-    }
-
-    fn switch_to_day(&mut self, date: chrono::NaiveDate) {
-        match Journal::load_for_date(&self.journal_root, date) {
-            Ok(journal) => {
-                self.journal = journal;
-                self.selected = last_entry_index(&self.journal);
-                self.reset_command_pane();
-                self.focus = Focus::Journal;
-                self.status = format!("Loaded {}.", self.journal.date.format("%Y-%m-%d"));
-            }
-            Err(error) => {
-                self.status = format!("Could not load {}: {error}", date.format("%Y-%m-%d"));
+        let mut links = find_links(&self.active_journal().entries[index].text);
+        match links.len() {
+            0 => self.set_warning("No links in the entry."),
+            1 if copy => self.copy_link(links.remove(0)),
+            1 => self.open_link(links.remove(0)),
+            _ => {
+                self.overlay = Some(Overlay::Links {
+                    links,
+                    selected: 0,
+                    copy,
+                })
             }
         }
     }
 
-    fn open_command_search(&mut self, context: CommandContext) {
-        self.focus = Focus::Command;
-        self.command_input.clear();
-        self.command_mode = CommandPaneMode::Search;
-        self.command_result_index = 0;
-        self.command_context = context;
-        self.status = String::from("Search commands.");
+    fn open_link(&mut self, link: String) {
+        let home = std::env::var("HOME").ok();
+        self.effects.push(Effect::Open {
+            command: self.open_command.clone(),
+            link: expand_home(&link, home.as_deref()),
+        });
+        self.set_status(format!("Opening {link}."));
     }
 
-    fn focus_journal(&mut self) {
-        self.focus = Focus::Journal;
-        self.status = String::from("Journal pane focused.");
+    fn copy_link(&mut self, link: String) {
+        self.set_status(format!("Copied {link} to the clipboard."));
+        self.effects.push(Effect::Copy(link));
     }
 }
 
-// Command selection and execution.
+// Detail notes holding the long-form body of an entry.
 impl App {
-    fn execute_command_from_search_input(&mut self) -> io::Result<bool> {
-        if let Some((input, context)) =
-            command_from_search_input(&self.command_input, self.command_context)
-        {
-            self.reset_command_pane();
-            self.execute_command(&input, context)?;
-            return Ok(true);
-        }
-
-        Ok(false)
+    /// The detail note of the highlighted entry, once `tick` has read it.
+    pub fn detail_note(&self) -> Option<&LoadedNote> {
+        let index = self.highlighted_entry_index()?;
+        let name = self.active_journal().entries[index].note.as_deref()?;
+        self.detail_note.as_ref().filter(|note| note.name == name)
     }
 
-    fn select_command(&mut self, command: &CommandOption) -> io::Result<()> {
-        self.command_input.clear();
-        self.command_result_index = 0;
-
-        match command.action {
-            CommandAction::Add(_) => {
-                self.command_mode = CommandPaneMode::Entry(command.action);
-                self.status = format!("Selected {}.", command.name);
+    /// Reads the highlighted entry's detail note when it is a different note
+    /// from the one shown or its file changed.
+    fn refresh_detail_note(&mut self) {
+        let name = self
+            .highlighted_entry_index()
+            .and_then(|index| self.active_journal().entries[index].note.clone());
+        let root = &self.journal_root;
+        self.detail_note = match (name, self.detail_note.take()) {
+            (Some(name), Some(mut note)) if note.name == name => {
+                note.refresh(root);
+                Some(note)
             }
-            CommandAction::Quit => {
-                self.reset_command_pane();
-                self.execute_command(command.token, CommandContext::CommandPane)?;
-            }
-            CommandAction::Split => {
-                self.reset_command_pane();
-                self.execute_command(command.token, CommandContext::CommandPane)?;
-            }
-            CommandAction::Complete | CommandAction::Cancel | CommandAction::Important => {
-                self.reset_command_pane();
-                self.execute_command(command.token, CommandContext::JournalPane)?;
-            }
-        }
-
-        Ok(())
+            (Some(name), _) => Some(LoadedNote::load(root, &name)),
+            (None, _) => None,
+        };
     }
 
-    fn submit_selected_command_entry(&mut self) -> io::Result<()> {
-        let CommandPaneMode::Entry(action) = self.command_mode else {
+    /// Opens the highlighted entry's detail note in the editor. An entry
+    /// without one gets a new note, linked from its line as one undo step.
+    /// The link is saved before the note is written, so a failed save leaves
+    /// no note behind.
+    fn edit_details(&mut self) -> io::Result<()> {
+        let Some(index) = self.highlighted_entry_index() else {
+            self.set_warning("No entry selected.");
             return Ok(());
         };
-
-        if self.command_input.trim().is_empty() {
-            self.status = String::from("Entry text cannot be empty.");
+        let journal = self.active_journal();
+        let entry = &journal.entries[index];
+        if entry.kind == EntryKind::Raw {
+            self.set_warning("Plain lines cannot have detail notes.");
             return Ok(());
         }
 
-        let Some(token) = action.token() else {
-            return Ok(());
+        let name = match entry.note.clone() {
+            Some(name) => name,
+            None => {
+                let name = next_note_name(&self.journal_root, journal.date)?;
+                let contents = new_note_contents(&entry.text);
+                let path = note_path(&self.journal_root, &name);
+                let before = journal.clone();
+                let selected = self.active_selected();
+                self.with_active_journal(|journal, _| {
+                    journal.entries[index].note = Some(name.clone());
+                    journal.save()
+                })?;
+                self.push_undo_step(UndoStep {
+                    action: "details",
+                    journals: vec![before],
+                    selected,
+                    created_note: Some((path.clone(), contents.clone())),
+                });
+                fs::write(&path, contents)?;
+                self.set_status(format!("Created note {name}."));
+                name
+            }
         };
-
-        let input = format!("{token} {}", self.command_input.trim());
-        self.reset_command_pane();
-        self.execute_command(&input, CommandContext::CommandPane)
+        self.visual = None;
+        self.effects
+            .push(Effect::EditNote(note_path(&self.journal_root, &name)));
+        Ok(())
     }
+}
 
-    fn execute_command(&mut self, input: &str, context: CommandContext) -> io::Result<()> {
-        match parse_command(input) {
-            Ok(Command::Add(kind, text)) => {
-                if context != CommandContext::CommandPane {
-                    self.status = String::from("Entry commands are available in the command pane.");
-                    return Ok(());
-                }
+// Editing a day in an external editor.
+impl App {
+    fn open_editor(&mut self) -> io::Result<()> {
+        fs::create_dir_all(&self.journal_root)?;
+        // Recurring occurrences are saved first, so the editor shows them and
+        // deleting one there is kept when the day is read back.
+        if self
+            .active_journal()
+            .entries
+            .iter()
+            .any(|entry| entry.recurrence.is_some())
+        {
+            self.with_active_journal(|journal, _| journal.save())?;
+        }
+        let journal = self.active_journal();
+        self.effects.push(Effect::Edit {
+            path: journal.path().to_path_buf(),
+            date: journal.date,
+        });
+        Ok(())
+    }
 
-                let path = self.add_entry_to_active_journal(kind, text)?;
-                self.status = format!("Wrote {}.", path.display());
-                if kind == EntryKind::Note {
-                    self.focus = Focus::Journal;
-                }
-            }
-            Ok(Command::Quit) => {
-                self.should_quit = true;
-            }
-            Ok(Command::ToggleSplit) => {
-                if context != CommandContext::CommandPane {
-                    self.status = String::from("Split is available in the command pane.");
-                    return Ok(());
-                }
-                self.toggle_split_view();
+    /// Reads a day again after it was edited outside the app and shows it in
+    /// every pane it is in. Undo steps for the day are dropped, since they
+    /// would overwrite the edit.
+    pub fn reload_day(&mut self, date: NaiveDate) -> io::Result<()> {
+        let mut journal = self.read_day(date)?;
+        if let Some(shown) = self.visible_journal(date) {
+            // Occurrences the editor removed from the file are dismissed; ones
+            // that were only just added by the rules are not.
+            let read = journal
+                .entries
+                .iter()
+                .filter(|entry| entry.read_from_file())
+                .cloned()
+                .collect::<Vec<_>>();
+            let dismissed = dismissed_occurrences(&shown.entries, &read);
+            if !dismissed.is_empty() {
+                self.skip_occurrences(date, &dismissed)?;
+                journal = self.read_day(date)?;
             }
-            Ok(Command::Complete) => {
-                if context != CommandContext::JournalPane {
-                    self.status = String::from("Complete is available in the journal pane.");
-                    return Ok(());
-                }
-                self.complete_selected()?;
-                self.focus = Focus::Journal;
-            }
-            Ok(Command::Cancel) => {
-                if context != CommandContext::JournalPane {
-                    self.status = String::from("Cancel is available in the journal pane.");
-                    return Ok(());
-                }
-                self.cancel_selected()?;
-                self.focus = Focus::Journal;
-            }
-            Ok(Command::Important) => {
-                if context != CommandContext::JournalPane {
-                    self.status = String::from("Important is available in the journal pane.");
-                    return Ok(());
-                }
-                self.toggle_important_selected()?;
-                self.focus = Focus::Journal;
+        }
+        self.show_reloaded_day(journal);
+        self.set_status(format!("Reloaded {}.", date.format("%Y-%m-%d")));
+        Ok(())
+    }
+
+    /// Reads again the visible days whose files changed outside the app, so
+    /// no change is saved over them. Returns whether any day was read again;
+    /// the key or command about to run is then dropped, since it was aimed at
+    /// the old entries.
+    fn reload_changed_days(&mut self) -> io::Result<bool> {
+        let mut changed = Vec::new();
+        for journal in self.visible_journals() {
+            if journal.changed_on_disk()? {
+                changed.push(journal.date);
             }
-            Err(message) => {
-                self.status = message;
+        }
+
+        for &date in &changed {
+            let journal = self.read_day(date)?;
+            self.show_changed_day(journal);
+        }
+        Ok(!changed.is_empty())
+    }
+
+    /// Asks the prefetch thread to check the visible days, and shows the
+    /// newer copies it has read of days that changed outside the app.
+    fn refresh_visible_days(&mut self) {
+        for date in self.cache.take_refreshed() {
+            let Some(stamp) = self.visible_journal(date).map(Journal::stamp) else {
+                continue;
+            };
+            match self.load_day(date) {
+                Ok(journal) if journal.stamp() != stamp => self.show_changed_day(journal),
+                Ok(_) => {}
+                Err(error) => self.set_error(format!(
+                    "Could not reload {}: {error}",
+                    date.format("%Y-%m-%d")
+                )),
             }
         }
 
-        Ok(())
+        let dates = self
+            .visible_journals()
+            .into_iter()
+            .map(|journal| journal.date)
+            .collect();
+        self.cache.prefetch(dates);
+    }
+
+    fn show_changed_day(&mut self, journal: Journal) {
+        let date = journal.date;
+        self.show_reloaded_day(journal);
+        self.set_warning(format!(
+            "{} changed on disk and was reloaded.",
+            date.format("%Y-%m-%d")
+        ));
+    }
+
+    /// Shows a day read again from its file in every pane it is in. Undo steps
+    /// for the day are dropped, since they would overwrite the new contents.
+    fn show_reloaded_day(&mut self, journal: Journal) {
+        let date = journal.date;
+        self.show_restored_journal(journal);
+        self.undo
+            .retain(|step| step.journals.iter().all(|journal| journal.date != date));
+        self.visual = None;
+        if self.active_journal().date == date {
+            self.valid_selected_index();
+        }
     }
 }
 
-// Split journal view management.
+// Journal focus and date navigation.
 impl App {
-    fn toggle_split_view(&mut self) {
-        if let Some(split) = self.split.take() {
-            let pane = match split.active {
-                SplitPane::Older => split.older,
-                SplitPane::Newer => split.newer,
-            };
-            self.journal = pane.journal;
-            self.selected = pane.selected;
-            self.focus = Focus::Journal;
-            self.status = format!(
-                "Split view off. Loaded {}.",
-                self.journal.date.format("%Y-%m-%d")
-            );
+    fn switch_to_previous_day(&mut self) {
+        let Some(date) = self.journal.date.checked_sub_days(Days::new(1)) else {
+            self.set_warning("Cannot switch before the supported date range.");
             return;
-        }
+        };
 
-        let Some(older_date) = self.today.checked_sub_days(Days::new(1)) else {
-            self.status = String::from("Cannot split before the supported date range.");
+        self.switch_to_day(date);
+    }
+
+    fn switch_to_next_day(&mut self) {
+        let Some(date) = self.journal.date.checked_add_days(Days::new(1)) else {
+            self.set_warning("Cannot switch after the supported date range.");
             return;
         };
 
-        match self.load_split_window(older_date, SplitPane::Newer) {
-            Ok(split) => {
-                self.split = Some(split);
-                self.sync_active_journal_from_split();
-                self.focus = Focus::Journal;
-                if let Some(split) = &self.split {
-                    self.status = format!(
-                        "Split view on: {} and {}.",
-                        split.older.journal.date.format("%Y-%m-%d"),
-                        split.newer.journal.date.format("%Y-%m-%d")
-                    );
-                }
-            }
-            Err(error) => {
-                self.status = format!("Could not load split view: {error}");
-            }
-        }
+        self.switch_to_day(date);
     }
 
-    fn load_split_window(
-        &self,
-        older_date: NaiveDate,
-        active: SplitPane,
-    ) -> io::Result<SplitJournalView> {
-        let newer_date = older_date.checked_add_days(Days::new(1)).ok_or_else(|| {
-            io::Error::new(
-                io::ErrorKind::InvalidInput,
-                "Cannot split after the supported date range.",
-            )
-        })?;
-
-        Ok(SplitJournalView {
-            older: JournalPane::new(Journal::load_for_date(&self.journal_root, older_date)?),
-            newer: JournalPane::new(Journal::load_for_date(&self.journal_root, newer_date)?),
-            active,
-        })
+    fn jump_days(&mut self, days: i64) {
+        match DateExpr::Offset(days).resolve(self.journal.date) {
+            Some(date) => self.go_to_date(date),
+            None => self.set_warning("Cannot switch outside the supported date range."),
+        }
     }
 
-    fn navigate_split_left(&mut self) -> io::Result<()> {
-        let Some(split) = &self.split else {
-            return Ok(());
+    fn jump_months(&mut self, months: i32) {
+        let date = if months < 0 {
+            self.journal
+                .date
+                .checked_sub_months(Months::new(months.unsigned_abs()))
+        } else {
+            self.journal
+                .date
+                .checked_add_months(Months::new(months as u32))
         };
 
-        if split.active == SplitPane::Newer {
-            self.set_active_split_pane(SplitPane::Older);
-            return Ok(());
+        match date {
+            Some(date) => self.go_to_date(date),
+            None => self.set_warning("Cannot switch outside the supported date range."),
         }
+    }
 
-        let Some(new_older_date) = split.older.journal.date.checked_sub_days(Days::new(1)) else {
-            self.status = String::from("Cannot switch before the supported date range.");
-            return Ok(());
+    /// Skips days without a file, going to the nearest earlier or later day
+    /// that has one.
+    fn jump_to_day_file(&mut self, forward: bool) {
+        let date = self.journal.date;
+        let found = if forward {
+            self.day_index.next(date)
+        } else {
+            self.day_index.previous(date)
         };
 
-        let new_older = match Journal::load_for_date(&self.journal_root, new_older_date) {
-            Ok(journal) => JournalPane::new(journal),
+        match found {
+            Ok(Some(date)) => self.go_to_date(date),
+            Ok(None) if forward => self.set_warning("No later day files."),
+            Ok(None) => self.set_warning("No earlier day files."),
             Err(error) => {
-                self.status = format!(
-                    "Could not load {}: {error}",
-                    new_older_date.format("%Y-%m-%d")
-                );
-                return Ok(());
+                self.set_error(format!(
+                    "Could not read {}: {error}",
+                    self.journal_root.display()
+                ));
             }
-        };
-
-        if let Some(split) = &mut self.split {
-            let previous_older = std::mem::replace(&mut split.older, new_older);
-            split.newer = previous_older;
-            split.active = SplitPane::Older;
         }
-
-        self.sync_active_journal_from_split();
-        self.status = format!("Loaded {}.", self.journal.date.format("%Y-%m-%d"));
-        Ok(())
     }
 
-    fn navigate_split_right(&mut self) -> io::Result<()> {
+    /// Shows `date` in the focused pane. Other split panes move by the same
+    /// number of days, and the week view moves to the week of `date`.
+    fn go_to_date(&mut self, date: NaiveDate) {
         let Some(split) = &self.split else {
-            return Ok(());
+            self.switch_to_day(date);
+            return;
         };
 
-        if split.active == SplitPane::Older {
-            self.set_active_split_pane(SplitPane::Newer);
-            return Ok(());
+        let window = match split.layout {
+            SplitLayout::Week => {
+                week_of(date).map(|week| (week, date.weekday().num_days_from_monday() as usize))
+            }
+            SplitLayout::Days | SplitLayout::Custom => {
+                let offset = (date - split.active_pane().journal.date).num_days();
+                split
+                    .panes
+                    .iter()
+                    .map(|pane| DateExpr::Offset(offset).resolve(pane.journal.date))
+                    .collect::<Option<Vec<_>>>()
+                    .map(|dates| (dates, split.active))
+            }
+        };
+
+        match window {
+            Some((dates, active)) => self.show_split_dates(&dates, active),
+            None => self.set_warning("Cannot switch outside the supported date range."),
         }
+    }
 
-        let Some(new_newer_date) = split.newer.journal.date.checked_add_days(Days::new(1)) else {
-            self.status = String::from("Cannot switch after the supported date range.");
-            return Ok(());
-        };
+    fn open_calendar(&mut self) {
+        self.overlay = Some(Overlay::Calendar(CalendarPicker::open(
+            &self.journal_root,
+            self.journal.date,
+        )));
+        self.focus = Focus::Journal;
+        self.set_status("Pick a day.");
+    }
 
-        let new_newer = match Journal::load_for_date(&self.journal_root, new_newer_date) {
-            Ok(journal) => JournalPane::new(journal),
+    fn switch_to_day(&mut self, date: chrono::NaiveDate) {
+        match self.load_day(date) {
+            Ok(journal) => {
+                self.journal = journal;
+                self.selected = last_entry_index(&self.journal);
+                self.reset_command_pane();
+                self.focus = Focus::Journal;
+                self.prefetch_neighbours();
+                self.set_status(format!("Loaded {}.", self.journal.date.format("%Y-%m-%d")));
+            }
             Err(error) => {
-                self.status = format!(
+                self.set_error(format!(
                     "Could not load {}: {error}",
-                    new_newer_date.format("%Y-%m-%d")
-                );
-                return Ok(());
+                    date.format("%Y-%m-%d")
+                ));
             }
-        };
-
-        if let Some(split) = &mut self.split {
-            let previous_newer = std::mem::replace(&mut split.newer, new_newer);
-            split.older = previous_newer;
-            split.active = SplitPane::Newer;
         }
-
-        self.sync_active_journal_from_split();
-        self.status = format!("Loaded {}.", self.journal.date.format("%Y-%m-%d"));
-        Ok(())
     }
 
-    fn set_active_split_pane(&mut self, active: SplitPane) {
-        if let Some(split) = &mut self.split {
-            split.active = active;
-        }
+    fn open_command_search(&mut self, context: CommandContext) {
+        self.focus = Focus::Command;
+        self.command_input.clear();
+        self.command_mode = CommandPaneMode::Search;
+        self.command_result_index = 0;
+        self.command_context = context;
+        self.set_status("Search commands.");
+    }
 
-        self.sync_active_journal_from_split();
-        self.status = format!("Focused {}.", self.journal.date.format("%Y-%m-%d"));
+    fn focus_journal(&mut self) {
+        self.focus = Focus::Journal;
+        self.set_status("Journal pane focused.");
     }
 }
 
-// Journal entry mutation and selection.
+// Status messages and their history.
 impl App {
-    fn add_entry_to_active_journal(
-        &mut self,
-        kind: EntryKind,
-        text: String,
-    ) -> io::Result<PathBuf> {
-        if let Some(split) = &mut self.split {
-            let pane = split.active_pane_mut();
-            pane.journal.add_entry(kind, text);
-            pane.journal.save()?;
-            pane.selected = last_entry_index(&pane.journal);
-            let path = pane.journal.path().to_path_buf();
-            self.sync_active_journal_from_split();
-            return Ok(path);
-        }
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
 
-        self.journal.add_entry(kind, text);
-        self.journal.save()?;
-        self.selected = last_entry_index(&self.journal);
-        Ok(self.journal.path().to_path_buf())
+    /// The latest message and how it should be styled.
+    pub fn latest_message(&self) -> Option<&Message> {
+        self.messages.last()
     }
 
-    fn complete_selected(&mut self) -> io::Result<()> {
-        let Some(index) = self.highlighted_entry_index() else {
-            self.status = String::from("No entry selected.");
-            return Ok(());
-        };
+    pub fn set_error(&mut self, text: impl Into<String>) {
+        self.push_message(text.into(), Severity::Error);
+    }
 
-        if let Some(split) = &mut self.split {
-            let pane = split.active_pane_mut();
-            self.status = match pane.journal.entries[index].toggle_complete() {
-                Ok(message) => {
-                    pane.journal.save()?;
-                    message.to_string()
-                }
-                Err(message) => message.to_string(),
-            };
-            self.sync_active_journal_from_split();
-            return Ok(());
+    fn set_status(&mut self, text: impl Into<String>) {
+        self.push_message(text.into(), Severity::Info);
+    }
+
+    fn set_warning(&mut self, text: impl Into<String>) {
+        self.push_message(text.into(), Severity::Warning);
+    }
+
+    /// Shows the message from an entry action: its success message, or why it
+    /// could not be done.
+    fn set_action_status(&mut self, result: Result<&str, &str>) {
+        match result {
+            Ok(message) => self.set_status(message),
+            Err(message) => self.set_warning(message),
         }
+    }
 
-        match self.journal.entries[index].toggle_complete() {
-            Ok(message) => {
-                self.journal.save()?;
-                self.status = message.to_string();
-            }
-            Err(message) => {
-                self.status = message.to_string();
+    /// Adds a message to the history. A message repeated straight away is
+    /// counted rather than added again.
+    fn push_message(&mut self, text: String, severity: Severity) {
+        if let Some(last) = self.messages.last_mut() {
+            if last.text == text && last.severity == severity {
+                last.repeats += 1;
+                return;
             }
         }
 
-        Ok(())
+        self.messages.push(Message::new(text, severity));
+        if self.messages.len() > MESSAGE_HISTORY_LIMIT {
+            self.messages.remove(0);
+        }
     }
+}
 
-    fn cancel_selected(&mut self) -> io::Result<()> {
-        let Some(index) = self.highlighted_entry_index() else {
-            self.status = String::from("No entry selected.");
-            return Ok(());
-        };
-
-        if let Some(split) = &mut self.split {
-            let pane = split.active_pane_mut();
-            self.status = match pane.journal.entries[index].toggle_cancel() {
-                Ok(message) => {
-                    pane.journal.save()?;
-                    message.to_string()
-                }
-                Err(message) => message.to_string(),
-            };
-            self.sync_active_journal_from_split();
-            return Ok(());
+// Visual mode: selecting a range of entries for actions to apply to.
+impl App {
+    fn toggle_visual_mode(&mut self) {
+        if self.visual_range().is_some() {
+            self.visual = None;
+            self.set_status("Visual mode off.");
+            return;
         }
 
-        match self.journal.entries[index].toggle_cancel() {
-            Ok(message) => {
-                self.journal.save()?;
-                self.status = message.to_string();
-            }
-            Err(message) => {
-                self.status = message.to_string();
+        match self.highlighted_entry_index() {
+            Some(index) => {
+                self.visual = Some((self.active_journal().date, index));
+                self.set_status("Visual mode.");
             }
+            None => self.set_warning("No entry selected."),
         }
-
-        Ok(())
     }
 
-    fn toggle_important_selected(&mut self) -> io::Result<()> {
-        let Some(index) = self.highlighted_entry_index() else {
-            self.status = String::from("No entry selected.");
-            return Ok(());
-        };
-
-        if let Some(split) = &mut self.split {
-            let pane = split.active_pane_mut();
-            self.status = pane.journal.entries[index].toggle_important().to_string();
-            pane.journal.save()?;
-            self.sync_active_journal_from_split();
-            return Ok(());
+    /// The entries from where visual mode started to the highlighted one, in
+    /// order. It is `None` outside visual mode or once the focused day changes.
+    pub fn visual_range(&self) -> Option<RangeInclusive<usize>> {
+        let (date, anchor) = self.visual?;
+        if date != self.active_journal().date {
+            return None;
         }
 
-        self.status = self.journal.entries[index].toggle_important().to_string();
-        self.journal.save()?;
-
-        Ok(())
+        let last = last_entry_index(self.active_journal())?;
+        let index = self.highlighted_entry_index()?;
+        let anchor = anchor.min(last);
+        Some(anchor.min(index)..=anchor.max(index))
     }
 
-    fn highlighted_entry_index(&self) -> Option<usize> {
-        let index = self.active_selected()?;
-        (index < self.active_journal().entries.len()).then_some(index)
+    /// The entries an entry action applies to: the visual range, or else the
+    /// highlighted entry.
+    fn action_range(&self) -> Option<RangeInclusive<usize>> {
+        self.visual_range()
+            .or_else(|| self.highlighted_entry_index().map(|index| index..=index))
     }
 
-    fn valid_selected_index(&mut self) -> Option<usize> {
-        let entry_count = self.active_journal().entries.len();
-        if entry_count == 0 {
-            self.set_active_selected(None);
-            return None;
-        }
+    /// The actions for the highlighted entry or, in visual mode, the range
+    /// actions that apply to at least one entry in it, in a fixed order.
+    fn action_range_options(&self) -> Vec<&'static CommandOption> {
+        let Some(range) = self.visual_range() else {
+            return self
+                .highlighted_entry_index()
+                .map(|index| entry_action_options(&self.active_journal().entries[index]))
+                .unwrap_or_default();
+        };
 
-        let max = entry_count - 1;
-        let index = self.active_selected().unwrap_or(max).min(max);
-        self.set_active_selected(Some(index));
-        Some(index)
+        let applicable = self.active_journal().entries[range]
+            .iter()
+            .flat_map(entry_action_options)
+            .map(|option| option.action)
+            .collect::<Vec<_>>();
+        ENTRY_ACTION_OPTIONS
+            .iter()
+            .copied()
+            .filter(|option| applicable.contains(&option.action))
+            .collect()
     }
 
-    fn select_previous(&mut self) {
-        let Some(index) = self.valid_selected_index() else {
-            return;
+    /// Runs `edit` on the entries in the action range of the focused day.
+    /// `edit` returns how many entries it changed. When any changed, the day
+    /// is saved once and the change becomes one undo step.
+    fn edit_action_range<T>(
+        &mut self,
+        action: &'static str,
+        edit: impl FnOnce(&mut [JournalEntry]) -> (usize, T),
+    ) -> io::Result<Option<(usize, T)>> {
+        let Some(range) = self.action_range() else {
+            self.set_warning("No entry selected.");
+            return Ok(None);
         };
 
-        self.set_active_selected(Some(index.saturating_sub(1)));
+        let before = self.active_journal().clone();
+        let selected = self.active_selected();
+        let (changed, value) = self.with_active_journal(|journal, _| -> io::Result<_> {
+            let (changed, value) = edit(&mut journal.entries[range]);
+            if changed > 0 {
+                journal.save()?;
+            }
+            Ok((changed, value))
+        })?;
+
+        if changed > 0 {
+            self.push_undo(action, vec![before], selected);
+            self.visual = None;
+        }
+        Ok(Some((changed, value)))
     }
+}
 
-    fn select_next(&mut self) {
-        let Some(index) = self.valid_selected_index() else {
-            return;
+// Deleting, yanking, pasting and undoing entries.
+impl App {
+    /// Deletes the visual range, or `count` entries from the highlighted one
+    /// down. They can be pasted back with `p`.
+    fn delete_entries(&mut self, count: usize) -> io::Result<()> {
+        let Some(range) = self.counted_range(count) else {
+            self.set_warning("No entry selected.");
+            return Ok(());
         };
 
-        let max = self.active_journal().entries.len() - 1;
-        self.set_active_selected(Some((index + 1).min(max)));
+        let before = self.active_journal().clone();
+        let selected = self.active_selected();
+        let start = *range.start();
+        let removed = self.with_active_journal(|journal, selected| -> io::Result<_> {
+            let removed = journal.entries.drain(range).collect::<Vec<_>>();
+            journal.save()?;
+            *selected = last_entry_index(journal).map(|last| start.min(last));
+            Ok(removed)
+        })?;
+        let dismissed = dismissed_occurrences(&before.entries, &self.active_journal().entries);
+        let date = before.date;
+
+        self.visual = None;
+        self.push_undo("delete", vec![before], selected);
+        self.skip_occurrences(date, &dismissed)?;
+        self.set_status(format!("Deleted {}.", entry_count(removed.len())));
+        self.register = removed;
+        self.register_cut = true;
+        Ok(())
     }
 
-    fn active_journal(&self) -> &Journal {
-        self.split
-            .as_ref()
-            .map(|split| &split.active_pane().journal)
-            .unwrap_or(&self.journal)
-    }
+    fn yank_entries(&mut self, count: usize) {
+        let Some(range) = self.counted_range(count) else {
+            self.set_warning("No entry selected.");
+            return;
+        };
 
-    fn active_selected(&self) -> Option<usize> {
-        self.split
-            .as_ref()
-            .map(|split| split.active_pane().selected)
-            .unwrap_or(self.selected)
+        self.visual = None;
+        self.register = self.active_journal().entries[range].to_vec();
+        self.register_cut = false;
+        self.set_status(format!("Yanked {}.", entry_count(self.register.len())));
     }
 
-    fn set_active_selected(&mut self, selected: Option<usize>) {
-        if let Some(split) = &mut self.split {
-            split.active_pane_mut().selected = selected;
-            self.sync_active_journal_from_split();
-        } else {
-            self.selected = selected;
+    /// The visual range, or `count` entries from the highlighted one down.
+    fn counted_range(&self, count: usize) -> Option<RangeInclusive<usize>> {
+        if let Some(range) = self.visual_range() {
+            return Some(range);
         }
+
+        let index = self.highlighted_entry_index()?;
+        let last = self.active_journal().entries.len() - 1;
+        Some(index..=index.saturating_add(count - 1).min(last))
     }
 
-    fn sync_active_journal_from_split(&mut self) {
-        if let Some(split) = &self.split {
-            self.journal = split.active_pane().journal.clone();
-            self.selected = split.active_pane().selected;
+    /// Pastes `times` copies of the register below the highlighted entry.
+    /// Pasted entries are new entries on the focused day.
+    fn paste_entries(&mut self, times: usize) -> io::Result<()> {
+        if self.register.is_empty() {
+            self.set_warning("Nothing to paste.");
+            return Ok(());
         }
+
+        let now = self.now();
+        let before = self.active_journal().clone();
+        let selected = self.active_selected();
+        let at = self
+            .highlighted_entry_index()
+            .map_or(before.entries.len(), |index| index + 1);
+        let pasted = (0..times)
+            .flat_map(|_| &self.register)
+            .map(|entry| copied_entry(entry, before.date, now, self.register_cut))
+            .collect::<Vec<_>>();
+        let count = pasted.len();
+
+        self.with_active_journal(|journal, selected| {
+            journal.entries.splice(at..at, pasted);
+            *selected = Some(at + count - 1);
+            journal.save()
+        })?;
+
+        self.push_undo("paste", vec![before], selected);
+        self.set_status(format!("Pasted {}.", entry_count(count)));
+        Ok(())
     }
-}
 
-// UI-facing state for split panes and command search.
-impl App {
-    pub fn split_view(&self) -> Option<&SplitJournalView> {
-        self.split.as_ref()
+    fn push_undo(&mut self, action: &'static str, journals: Vec<Journal>, selected: Option<usize>) {
+        self.push_undo_step(UndoStep {
+            action,
+            journals,
+            selected,
+            created_note: None,
+        });
     }
 
-    fn normalize_command_result_index(&mut self) {
-        let count = self.matching_command_options().len();
-        if count == 0 {
-            self.command_result_index = 0;
-        } else {
-            self.command_result_index = self.command_result_index.min(count - 1);
+    fn push_undo_step(&mut self, step: UndoStep) {
+        self.undo.push(step);
+        if self.undo.len() > UNDO_LIMIT {
+            self.undo.remove(0);
         }
     }
 
-    fn select_previous_command_result(&mut self) {
-        self.normalize_command_result_index();
-        self.command_result_index = self.command_result_index.saturating_sub(1);
-    }
+    /// Writes back the days from before the last change and shows them in
+    /// any pane they are visible in.
+    fn undo(&mut self) -> io::Result<()> {
+        let Some(step) = self.undo.pop() else {
+            self.set_warning("Nothing to undo.");
+            return Ok(());
+        };
 
-    fn select_next_command_result(&mut self) {
-        self.normalize_command_result_index();
-        let count = self.matching_command_options().len();
-        if count > 0 {
-            self.command_result_index = (self.command_result_index + 1).min(count - 1);
+        let active_date = self.active_journal().date;
+        let restores_active = step
+            .journals
+            .iter()
+            .any(|journal| journal.date == active_date);
+        for mut journal in step.journals {
+            restore_day_file(&mut journal)?;
+            self.show_restored_journal(journal);
+        }
+        if restores_active {
+            self.set_active_selected(step.selected);
+            self.valid_selected_index();
         }
+        if let Some((path, contents)) = &step.created_note {
+            remove_unedited_note(path, contents)?;
+        }
+
+        self.set_status(format!("Undid {}.", step.action));
+        Ok(())
     }
 
-    fn reset_command_pane(&mut self) {
-        self.command_mode = CommandPaneMode::Normal;
-        self.command_input.clear();
-        self.command_result_index = 0;
-        self.command_context = CommandContext::CommandPane;
+    fn show_restored_journal(&mut self, journal: Journal) {
+        let Some(split) = &mut self.split else {
+            if self.journal.date == journal.date {
+                self.journal = journal;
+            }
+            return;
+        };
+
+        for pane in &mut split.panes {
+            if pane.journal.date == journal.date {
+                pane.journal = journal.clone();
+                pane.selected = pane
+                    .selected
+                    .zip(last_entry_index(&pane.journal))
+                    .map(|(selected, last)| selected.min(last));
+            }
+        }
+        self.sync_active_journal_from_split();
     }
 
-    pub fn command_title(&self) -> &'static str {
-        match self.command_mode {
-            CommandPaneMode::Normal => "Command",
-            CommandPaneMode::Search => "Search Commands",
-            CommandPaneMode::Entry(action) => action.entry_title(),
+    /// Keys typed so far for a count or a doubled action, such as `5d`.
+    pub fn pending_keys(&self) -> String {
+        let mut keys = self
+            .pending
+            .count
+            .map(|count| count.to_string())
+            .unwrap_or_default();
+        if let Some((_, code)) = self.pending.operator {
+            keys.push_str(&key_name(code));
         }
+        keys
     }
+}
 
-    pub fn visible_command_search_results(&self) -> Vec<(usize, CommandSearchResult)> {
-        let results = self.command_search_results();
-        if results.is_empty() {
-            return Vec::new();
+// The leader menu.
+impl App {
+    fn open_leader_menu(&mut self) {
+        self.overlay = Some(Overlay::Leader {
+            keys: String::new(),
+        });
+        self.set_status("Leader menu.");
+    }
+
+    /// Each key goes one level deeper until it names a command, which then
+    /// runs exactly as if it had been picked from journal-pane search.
+    fn handle_leader_key(&mut self, mut keys: String, key: KeyEvent) -> io::Result<()> {
+        match key.code {
+            KeyCode::Backspace if !keys.is_empty() => {
+                keys.pop();
+                self.overlay = Some(Overlay::Leader { keys });
+                return Ok(());
+            }
+            KeyCode::Char(character) if is_text_input(key.modifiers) => keys.push(character),
+            _ => {
+                self.overlay = None;
+                self.focus_journal();
+                return Ok(());
+            }
         }
 
-        let selected = self.command_result_index.min(results.len() - 1);
-        let start = selected
-            .saturating_add(1)
-            .saturating_sub(COMMAND_SEARCH_RESULT_LIMIT);
+        let options = self.leader_options();
+        if let Some(option) = options.iter().find(|option| option.leader == keys) {
+            self.overlay = None;
+            self.focus = Focus::Command;
+            self.command_context = CommandContext::JournalPane;
+            return self.select_command(option);
+        }
 
-        results
-            .into_iter()
-            .enumerate()
-            .skip(start)
-            .take(COMMAND_SEARCH_RESULT_LIMIT)
-            .collect()
+        if options
+            .iter()
+            .any(|option| option.leader.starts_with(&keys))
+        {
+            self.overlay = Some(Overlay::Leader { keys });
+        } else {
+            self.overlay = None;
+            self.set_warning(format!("No leader action for {keys}."));
+        }
+        Ok(())
     }
 
-    pub fn command_search_result_limit(&self) -> usize {
-        COMMAND_SEARCH_RESULT_LIMIT
+    /// The next level of the leader menu after `keys`, in registry order with
+    /// the highlighted entry's actions first.
+    pub fn leader_menu(&self, keys: &str) -> Vec<LeaderMenuItem> {
+        let mut items: Vec<LeaderMenuItem> = Vec::new();
+
+        for option in self.leader_options() {
+            let Some(rest) = option.leader.strip_prefix(keys) else {
+                continue;
+            };
+            let Some(key) = rest.chars().next() else {
+                continue;
+            };
+            if items.iter().any(|item| item.key == key) {
+                continue;
+            }
+
+            let item = if rest.len() > key.len_utf8() {
+                let group = &option.leader[..keys.len() + key.len_utf8()];
+                LeaderMenuItem {
+                    key,
+                    label: LEADER_GROUPS
+                        .iter()
+                        .find(|(keys, _)| *keys == group)
+                        .map_or("more", |(_, name)| name),
+                    is_group: true,
+                }
+            } else {
+                LeaderMenuItem {
+                    key,
+                    label: option.name,
+                    is_group: false,
+                }
+            };
+            items.push(item);
+        }
+
+        items
     }
 
-    pub fn command_search_input_is_exact_command(&self) -> bool {
-        command_from_search_input(&self.command_input, self.command_context).is_some()
+    /// The commands journal-pane search offers, so the leader menu and the
+    /// search never disagree.
+    fn leader_options(&self) -> Vec<&'static CommandOption> {
+        let mut options = self.action_range_options();
+        options.extend(COMMAND_PANE_OPTIONS.iter());
+        options
     }
+}
 
-    fn command_search_results(&self) -> Vec<CommandSearchResult> {
-        self.matching_command_options()
+// The help popup.
+impl App {
+    fn open_help(&mut self) {
+        self.overlay = Some(Overlay::Help {
+            sections: self.help_sections(),
+            scroll: 0,
+        });
+        self.focus = Focus::Journal;
+    }
+
+    /// The journal-pane keys as currently bound, the actions for the
+    /// highlighted entry, and every command in the command registry.
+    fn help_sections(&self) -> Vec<HelpSection> {
+        let keys = KeyAction::ALL
             .into_iter()
-            .map(|command| CommandSearchResult {
-                name: command.name,
-                token: command.token,
+            .map(|action| {
+                let keys = self.keymap.keys_for(action);
+                let keys = if keys.is_empty() {
+                    String::from("unbound")
+                } else {
+                    keys.into_iter()
+                        .map(|code| {
+                            if action.is_doubled() {
+                                key_name(code).repeat(2)
+                            } else {
+                                key_name(code)
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                };
+                (keys, action.description())
             })
-            .collect()
+            .chain([(
+                String::from("1-9"),
+                "Count for the next key, as in 5j or 3dd",
+            )])
+            .collect();
+        let mut sections = vec![HelpSection {
+            title: String::from("Journal pane"),
+            rows: keys,
+        }];
+
+        if let Some(range) = self.visual_range() {
+            sections.push(HelpSection {
+                title: format!("Selected {}", entry_count(range.count())),
+                rows: command_rows(self.action_range_options()),
+            });
+        } else if let Some(index) = self.highlighted_entry_index() {
+            let entry = &self.active_journal().entries[index];
+            sections.push(HelpSection {
+                title: format!("Highlighted {}", entry_description(entry)),
+                rows: command_rows(entry_action_options(entry)),
+            });
+        }
+
+        sections.push(HelpSection {
+            title: String::from("Commands"),
+            rows: command_rows(COMMAND_PANE_OPTIONS.iter()),
+        });
+        sections
     }
+}
 
-    fn matching_command_options(&self) -> Vec<&'static CommandOption> {
-        let options = self.available_command_options();
-        matching_command_options(&self.command_input, &options)
+// Time passing while the app is open.
+impl App {
+    /// Called on every tick of the event loop, whether or not a key was
+    /// pressed.
+    pub fn tick(&mut self) {
+        let today = self.now().date();
+        if today != self.today {
+            self.roll_over_to(today);
+        }
+        self.refresh_visible_days();
+        self.check_reminders();
+        self.refresh_detail_note();
     }
 
-    fn available_command_options(&self) -> Vec<&'static CommandOption> {
-        let mut options = self.entry_action_command_options();
-        options.extend(COMMAND_PANE_OPTIONS.iter());
-        options
+    /// Effects queued since the last call, in order.
+    pub fn take_effects(&mut self) -> Vec<Effect> {
+        std::mem::take(&mut self.effects)
     }
 
-    fn entry_action_command_options(&self) -> Vec<&'static CommandOption> {
-        if self.command_context != CommandContext::JournalPane {
-            return Vec::new();
+    /// Makes `today` the new date. When the focused pane was showing the old
+    /// today it moves to the new one, keeping any command being typed.
+    fn roll_over_to(&mut self, today: NaiveDate) {
+        let previous = self.today;
+        self.today = today;
+        if self.journal.date != previous {
+            self.set_status(format!("It is now {}.", today.format("%Y-%m-%d")));
+            return;
         }
 
-        let Some(index) = self.highlighted_entry_index() else {
-            return Vec::new();
-        };
+        let typing = (self.focus == Focus::Command).then(|| {
+            (
+                self.command_mode,
+                std::mem::take(&mut self.command_input),
+                self.command_result_index,
+                self.command_context,
+            )
+        });
+
+        self.go_to_date(today);
+        if self.journal.date == today {
+            self.set_status(format!(
+                "It is now {}. Moved to the new day.",
+                today.format("%Y-%m-%d")
+            ));
+        }
 
-        let entry = &self.active_journal().entries[index];
-        match entry.kind {
-            EntryKind::Task => {
-                let mut options = vec![&COMPLETE_COMMAND_OPTION];
-                if entry.state != crate::journal::EntryState::Completed {
-                    options.push(&CANCEL_COMMAND_OPTION);
-                }
-                options.push(&IMPORTANT_COMMAND_OPTION);
-                options
-            }
-            EntryKind::Event => vec![&CANCEL_COMMAND_OPTION, &IMPORTANT_COMMAND_OPTION],
-            EntryKind::Note | EntryKind::Feeling | EntryKind::Raw => {
-                vec![&IMPORTANT_COMMAND_OPTION]
-            }
+        if let Some((mode, input, index, context)) = typing {
+            self.focus = Focus::Command;
+            self.command_mode = mode;
+            self.command_input = input;
+            self.command_result_index = index;
+            self.command_context = context;
         }
     }
 }
 
-pub fn command_search_results(query: &str) -> Vec<CommandSearchResult> {
-    let options = COMMAND_PANE_OPTIONS.iter().collect::<Vec<_>>();
-    matching_command_options(query, &options)
-        .into_iter()
-        .map(|command| CommandSearchResult {
-            name: command.name,
-            token: command.token,
-        })
-        .collect()
-}
+// Reminders for timed events and open tasks.
+impl App {
+    /// Fires reminders for today and tomorrow, so events shortly after
+    /// midnight are not missed.
+    fn check_reminders(&mut self) {
+        let now = self.now();
+        let mut reminders = Vec::new();
+        for date in [Some(self.today), self.today.succ_opt()]
+            .into_iter()
+            .flatten()
+        {
+            match self.load_day(date) {
+                Ok(journal) => reminders.extend(reminders_for(&journal, &self.reminder_settings)),
+                Err(error) => {
+                    self.set_error(format!("Could not check reminders: {error}"));
+                    return;
+                }
+            }
+        }
 
-fn matching_command_options(
-    query: &str,
-    options: &[&'static CommandOption],
-) -> Vec<&'static CommandOption> {
-    let mut matches = options
-        .iter()
-        .enumerate()
-        .filter_map(|(index, &command)| {
-            command_match_quality(query, command).map(|quality| (quality, index, command))
-        })
-        .collect::<Vec<_>>();
+        // Reminders whose time has passed or whose entry changed leave the banner.
+        self.reminders
+            .retain(|reminder| reminder.is_due(now) && reminders.contains(reminder));
 
-    matches.sort_by_key(|(quality, index, _)| (*quality, *index));
-    matches.into_iter().map(|(_, _, command)| command).collect()
-}
+        let fired = self.scheduler.fire(&reminders, now);
+        if fired.is_empty() {
+            return;
+        }
 
-fn command_match_quality(query: &str, command: &CommandOption) -> Option<MatchQuality> {
-    let query = query.trim();
-    if query.is_empty() {
-        return Some(MatchQuality::Prefix);
+        if self.reminder_settings.bell {
+            self.effects.push(Effect::Bell);
+        }
+        for reminder in fired {
+            if let Some(command) = &self.reminder_settings.command {
+                self.effects.push(Effect::RunCommand {
+                    command: command.clone(),
+                    argument: reminder.to_string(),
+                });
+            }
+            self.set_status(format!("Reminder: {reminder}."));
+            self.reminders.push(reminder);
+        }
     }
 
-    let mut quality = None;
-    for candidate in std::iter::once(command.name)
-        .chain(std::iter::once(command.token))
-        .chain(command.aliases.iter().copied())
-    {
-        if starts_with_ignore_ascii_case(candidate, query) {
-            return Some(MatchQuality::Prefix);
+    fn snooze_reminders(&mut self, minutes: u32) {
+        if self.reminders.is_empty() {
+            self.set_warning("No reminders to snooze.");
+            return;
         }
 
-        if fuzzy_subsequence_match(query, candidate) {
-            quality = Some(MatchQuality::Subsequence);
+        let until = self.now() + chrono::TimeDelta::minutes(i64::from(minutes));
+        for reminder in std::mem::take(&mut self.reminders) {
+            self.scheduler.snooze(&reminder, until);
         }
+        self.set_status(format!("Snoozed reminders for {minutes} minutes."));
     }
 
-    quality
-}
+    fn dismiss_reminders(&mut self) {
+        if self.reminders.is_empty() {
+            self.set_warning("No reminders to dismiss.");
+            return;
+        }
 
-pub fn fuzzy_subsequence_match(query: &str, candidate: &str) -> bool {
-    let query = query.trim();
-    if query.is_empty() {
-        return true;
+        for reminder in std::mem::take(&mut self.reminders) {
+            self.scheduler.dismiss(&reminder);
+        }
+        self.set_status("Dismissed reminders.");
     }
+}
 
-    let mut candidate_chars = candidate
-        .chars()
-        .map(|character| character.to_ascii_lowercase());
-
-    'query: for query_char in query
-        .chars()
-        .map(|character| character.to_ascii_lowercase())
-    {
-        for candidate_char in candidate_chars.by_ref() {
-            if candidate_char == query_char {
-                continue 'query;
-            }
+// Command selection and execution.
+impl App {
+    fn execute_command_from_search_input(&mut self) -> io::Result<bool> {
+        if let Some((input, context)) =
+            command_from_search_input(&self.command_input, self.command_context)
+        {
+            self.reset_command_pane();
+            self.execute_command(&input, context)?;
+            return Ok(true);
         }
 
-        return false;
+        Ok(false)
     }
 
-    true
-}
+    fn select_command(&mut self, command: &CommandOption) -> io::Result<()> {
+        self.command_input.clear();
+        self.command_result_index = 0;
 
-fn starts_with_ignore_ascii_case(candidate: &str, query: &str) -> bool {
-    candidate
-        .to_ascii_lowercase()
-        .starts_with(&query.to_ascii_lowercase())
-}
+        match command.action {
+            CommandAction::Add(_) | CommandAction::Pane | CommandAction::Goto => {
+                self.command_mode = CommandPaneMode::Entry(command.action);
+                self.set_status(format!("Selected {}.", command.name));
+            }
+            CommandAction::Quit => {
+                self.reset_command_pane();
+                self.execute_command(command.token, CommandContext::CommandPane)?;
+            }
+            CommandAction::Split
+            | CommandAction::Week
+            | CommandAction::Calendar
+            | CommandAction::Timestamps
+            | CommandAction::Timeline
+            | CommandAction::Recurring
+            | CommandAction::Snooze
+            | CommandAction::Dismiss
+            | CommandAction::Messages
+            | CommandAction::Editor
+            | CommandAction::Help => {
+                self.reset_command_pane();
+                self.execute_command(command.token, CommandContext::CommandPane)?;
+            }
+            CommandAction::Complete
+            | CommandAction::Cancel
+            | CommandAction::Important
+            | CommandAction::Move
+            | CommandAction::Copy
+            | CommandAction::Open
+            | CommandAction::Clip
+            | CommandAction::Details => {
+                self.reset_command_pane();
+                self.execute_command(command.token, CommandContext::JournalPane)?;
+            }
+        }
 
-fn command_from_search_input(
-    query: &str,
-    context: CommandContext,
-) -> Option<(String, CommandContext)> {
-    let query = query.trim();
-    if query.is_empty() {
-        return None;
+        Ok(())
     }
 
-    let input = format!(":{query}");
-    match parse_command(&input).ok()? {
-        Command::Add(_, _) | Command::Quit | Command::ToggleSplit => {
-            Some((input, CommandContext::CommandPane))
+    fn submit_selected_command_entry(&mut self) -> io::Result<()> {
+        let CommandPaneMode::Entry(action) = self.command_mode else {
+            return Ok(());
+        };
+
+        if self.command_input.trim().is_empty() {
+            self.set_warning("Entry text cannot be empty.");
+            return Ok(());
         }
-        Command::Complete | Command::Cancel | Command::Important
-            if context == CommandContext::JournalPane =>
-        {
-            Some((input, CommandContext::JournalPane))
+
+        let Some(token) = action.token() else {
+            return Ok(());
+        };
+
+        let input = format!("{token} {}", self.command_input.trim());
+        self.reset_command_pane();
+        self.execute_command(&input, CommandContext::CommandPane)
+    }
+
+    fn execute_command(&mut self, input: &str, context: CommandContext) -> io::Result<()> {
+        if self.reload_changed_days()? {
+            return Ok(());
+        }
+
+        match parse_command(input) {
+            Ok(Command::Add(draft)) => {
+                if context != CommandContext::CommandPane {
+                    self.set_warning("Entry commands are available in the command pane.");
+                    return Ok(());
+                }
+
+                if draft
+                    .date
+                    .is_some_and(|date| date.resolve(self.today).is_none())
+                {
+                    self.set_warning("Date is outside the supported range.");
+                    return Ok(());
+                }
+
+                let kind = draft.kind;
+                let path = self.add_entry_to_active_journal(draft)?;
+                self.set_status(format!("Wrote {}.", path.display()));
+                if kind == EntryKind::Note {
+                    self.focus = Focus::Journal;
+                }
+            }
+            Ok(Command::Quit) => {
+                self.should_quit = true;
+            }
+            Ok(Command::ToggleSplit) => {
+                if context != CommandContext::CommandPane {
+                    self.set_warning("Split is available in the command pane.");
+                    return Ok(());
+                }
+                self.toggle_split_view();
+            }
+            Ok(Command::ToggleWeek) => {
+                if context != CommandContext::CommandPane {
+                    self.set_warning("Week view is available in the command pane.");
+                    return Ok(());
+                }
+                self.toggle_week_view();
+            }
+            Ok(Command::ToggleTimestamps) => {
+                self.show_timestamps = !self.show_timestamps;
+                self.focus = Focus::Journal;
+                self.set_status(if self.show_timestamps {
+                    "Showing timestamps."
+                } else {
+                    "Hiding timestamps."
+                });
+            }
+            Ok(Command::ToggleTimeline) => {
+                self.show_timeline = !self.show_timeline;
+                self.focus = Focus::Journal;
+                self.set_status(if self.show_timeline {
+                    "Showing timeline."
+                } else {
+                    "Showing entries."
+                });
+            }
+            Ok(Command::Complete) => {
+                if context != CommandContext::JournalPane {
+                    self.set_warning("Complete is available in the journal pane.");
+                    return Ok(());
+                }
+                self.complete_selected()?;
+                self.focus = Focus::Journal;
+            }
+            Ok(Command::Cancel) => {
+                if context != CommandContext::JournalPane {
+                    self.set_warning("Cancel is available in the journal pane.");
+                    return Ok(());
+                }
+                self.cancel_selected()?;
+                self.focus = Focus::Journal;
+            }
+            Ok(Command::Important) => {
+                if context != CommandContext::JournalPane {
+                    self.set_warning("Important is available in the journal pane.");
+                    return Ok(());
+                }
+                self.toggle_important_selected()?;
+                self.focus = Focus::Journal;
+            }
+            Ok(Command::Move(date)) => {
+                if context != CommandContext::JournalPane {
+                    self.set_warning("Move is available in the journal pane.");
+                    return Ok(());
+                }
+                self.transfer_selected(TransferMode::Move, date)?;
+                self.focus = Focus::Journal;
+            }
+            Ok(Command::Copy(date)) => {
+                if context != CommandContext::JournalPane {
+                    self.set_warning("Copy is available in the journal pane.");
+                    return Ok(());
+                }
+                self.transfer_selected(TransferMode::Copy, date)?;
+                self.focus = Focus::Journal;
+            }
+            Ok(Command::Pane(command)) => self.execute_pane_command(command),
+            Ok(Command::Goto(date)) => {
+                match date.resolve(self.today) {
+                    Some(date) => self.go_to_date(date),
+                    None => self.set_warning("Date is outside the supported range."),
+                }
+                self.focus = Focus::Journal;
+            }
+            Ok(Command::OpenCalendar) => self.open_calendar(),
+            Ok(Command::Snooze(minutes)) => {
+                self.snooze_reminders(minutes.unwrap_or(DEFAULT_SNOOZE_MINUTES));
+                self.focus = Focus::Journal;
+            }
+            Ok(Command::Dismiss) => {
+                self.dismiss_reminders();
+                self.focus = Focus::Journal;
+            }
+            Ok(Command::ShowMessages) => {
+                self.overlay = Some(Overlay::Messages { scroll: 0 });
+                self.focus = Focus::Journal;
+            }
+            Ok(Command::ShowHelp) => self.open_help(),
+            Ok(Command::Editor) => {
+                self.focus = Focus::Journal;
+                self.open_editor()?;
+            }
+            Ok(Command::EditDetails) => {
+                if context != CommandContext::JournalPane {
+                    self.set_warning("Details are available in the journal pane.");
+                    return Ok(());
+                }
+                self.focus = Focus::Journal;
+                self.edit_details()?;
+            }
+            Ok(Command::Open) => {
+                if context != CommandContext::JournalPane {
+                    self.set_warning("Open is available in the journal pane.");
+                    return Ok(());
+                }
+                self.focus = Focus::Journal;
+                self.follow_links(false);
+            }
+            Ok(Command::Clip) => {
+                if context != CommandContext::JournalPane {
+                    self.set_warning("Clip is available in the journal pane.");
+                    return Ok(());
+                }
+                self.focus = Focus::Journal;
+                self.follow_links(true);
+            }
+            Ok(Command::Recur(command)) => {
+                self.execute_recur_command(command)?;
+                self.focus = Focus::Journal;
+            }
+            Err(message) => {
+                self.set_warning(message);
+            }
         }
-        Command::Complete | Command::Cancel | Command::Important => None,
+
+        Ok(())
     }
 }
 
-pub fn parse_command(input: &str) -> Result<Command, String> {
-    let trimmed = input.trim();
-    if trimmed.is_empty() {
-        return Err(String::from("Enter a command."));
+// Recurring rule management.
+impl App {
+    fn execute_recur_command(&mut self, command: RecurCommand) -> io::Result<()> {
+        let mut rules = RecurringRules::load(&self.journal_root)?;
+
+        match command {
+            RecurCommand::List => {
+                let rules = rules.rules().cloned().collect::<Vec<_>>();
+                self.set_status(format!("{} recurring rules.", rules.len()));
+                self.overlay = Some(Overlay::Recurring(rules));
+            }
+            RecurCommand::Add { recurrence, draft } => {
+                let Some(start) = draft
+                    .date
+                    .map_or(Some(self.today), |date| date.resolve(self.today))
+                else {
+                    self.set_warning("Date is outside the supported range.");
+                    return Ok(());
+                };
+
+                let mut entry = JournalEntry::new(draft.kind, draft.text, start);
+                entry.time = draft.time;
+                let id = rules.add(recurrence, start, entry).id;
+                rules.save()?;
+                self.rules = rules;
+                self.reload_visible_days()?;
+                self.set_status(format!("Added recurring rule {id}."));
+            }
+            RecurCommand::Remove(id) => {
+                if rules.remove(&id).is_some() {
+                    rules.save()?;
+                    self.rules = rules;
+                    self.reload_visible_days()?;
+                    self.set_status(format!("Removed recurring rule {id}."));
+                } else {
+                    self.set_warning(format!("No recurring rule {id}."));
+                }
+            }
+        }
+
+        Ok(())
     }
 
-    let (command, rest) = split_command(trimmed);
-    match command {
-        ":n" => entry_command(EntryKind::Note, rest),
-        ":e" => entry_command(EntryKind::Event, rest),
-        ":f" => entry_command(EntryKind::Feeling, rest),
-        ":t" => entry_command(EntryKind::Task, rest),
-        ":q" => Ok(Command::Quit),
-        ":split" => Ok(Command::ToggleSplit),
-        ":x" => Ok(Command::Complete),
-        ":c" => Ok(Command::Cancel),
-        ":i" | ":important" => Ok(Command::Important),
-        _ => Err(format!("Unknown command: {command}")),
+    /// Reads the visible days again with the current rules, so a new rule's
+    /// occurrences appear and a removed rule's unsaved ones disappear. Every
+    /// other change is already in the files.
+    fn reload_visible_days(&mut self) -> io::Result<()> {
+        let Some(split) = &mut self.split else {
+            self.journal = self.read_day(self.journal.date)?;
+            self.selected = clamped_selection(self.selected, &self.journal);
+            return Ok(());
+        };
+
+        for pane in &mut split.panes {
+            pane.journal = Journal::load_for_date_with_rules(
+                &self.journal_root,
+                pane.journal.date,
+                &self.rules,
+            )?;
+            pane.selected = clamped_selection(pane.selected, &pane.journal);
+        }
+        self.sync_active_journal_from_split();
+        Ok(())
+    }
+
+    /// Keeps the occurrences of `ids` off `date` from now on, because they
+    /// were deleted or moved away. The rules are read again before the
+    /// change, so edits made to `recurring.md` meanwhile are not lost.
+    fn skip_occurrences(&mut self, date: NaiveDate, ids: &[String]) -> io::Result<()> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut rules = RecurringRules::load(&self.journal_root)?;
+        let mut changed = false;
+        for id in ids {
+            changed |= rules.skip(id, date);
+        }
+        if changed {
+            rules.save()?;
+        }
+        self.rules = rules;
+        Ok(())
+    }
+
+    /// Loads a day through the cache, with its recurring occurrences. A day
+    /// that is cached and unchanged costs one file stat. A day that is not
+    /// cached is read on the spot: single steps find theirs prefetched, but
+    /// jumps such as `:goto`, counted `h` and `l`, the calendar, `:pane`,
+    /// moving the week view and the first reminder check of a day wait for
+    /// the read.
+    fn load_day(&self, date: NaiveDate) -> io::Result<Journal> {
+        self.cache.load(date, &self.rules)
+    }
+
+    /// Reads a day from its file, with its recurring occurrences.
+    fn read_day(&self, date: NaiveDate) -> io::Result<Journal> {
+        Journal::load_for_date_with_rules(&self.journal_root, date, &self.rules)
     }
 }
 
-fn split_command(input: &str) -> (&str, &str) {
-    match input.find(char::is_whitespace) {
-        Some(index) => (&input[..index], input[index..].trim()),
-        None => (input, ""),
+// Split journal view management.
+impl App {
+    fn toggle_split_view(&mut self) {
+        if self.split_layout() == Some(SplitLayout::Days) {
+            self.close_split_view("Split view off.");
+            return;
+        }
+
+        let Some(older_date) = self.today.checked_sub_days(Days::new(1)) else {
+            self.set_warning("Cannot split before the supported date range.");
+            return;
+        };
+
+        if self.open_split_view(SplitLayout::Days, &[older_date, self.today], self.today) {
+            if let Some(split) = &self.split {
+                self.set_status(format!(
+                    "Split view on: {} and {}.",
+                    split.panes[0].journal.date.format("%Y-%m-%d"),
+                    split.panes[1].journal.date.format("%Y-%m-%d")
+                ));
+            }
+        }
+    }
+
+    fn toggle_week_view(&mut self) {
+        if self.split_layout() == Some(SplitLayout::Week) {
+            self.close_split_view("Week view off.");
+            return;
+        }
+
+        let date = self.journal.date;
+        let Some(week) = week_of(date) else {
+            self.set_warning("Cannot show a week outside the supported date range.");
+            return;
+        };
+
+        if self.open_split_view(SplitLayout::Week, &week, date) {
+            self.set_status(format!(
+                "Week view on: {} to {}.",
+                week[0].format("%Y-%m-%d"),
+                week[6].format("%Y-%m-%d")
+            ));
+        }
+    }
+
+    fn split_layout(&self) -> Option<SplitLayout> {
+        self.split.as_ref().map(|split| split.layout)
+    }
+
+    /// Replaces the current view with a pane for each date, focused on
+    /// `focused`. Returns whether the view could be loaded.
+    fn open_split_view(
+        &mut self,
+        layout: SplitLayout,
+        dates: &[NaiveDate],
+        focused: NaiveDate,
+    ) -> bool {
+        let panes = match self.load_split_window(dates, &[]) {
+            Ok(panes) => panes,
+            Err(error) => {
+                self.set_error(format!("Could not load split view: {error}"));
+                return false;
+            }
+        };
+
+        let active = panes
+            .iter()
+            .position(|pane| pane.journal.date == focused)
+            .unwrap_or(panes.len() - 1);
+        self.split = Some(SplitJournalView {
+            panes,
+            active,
+            layout,
+        });
+        self.sync_active_journal_from_split();
+        self.focus = Focus::Journal;
+        self.prefetch_neighbours();
+        true
+    }
+
+    fn close_split_view(&mut self, message: &str) {
+        if let Some(split) = self.split.take() {
+            let pane = split.into_active_pane();
+            self.journal = pane.journal;
+            self.selected = pane.selected;
+        }
+
+        self.focus = Focus::Journal;
+        self.set_status(format!(
+            "{message} Loaded {}.",
+            self.journal.date.format("%Y-%m-%d")
+        ));
+    }
+
+    /// Loads a pane for each date, reusing `loaded` panes for the same day so
+    /// their selection is kept.
+    fn load_split_window(
+        &self,
+        dates: &[NaiveDate],
+        loaded: &[JournalPane],
+    ) -> io::Result<Vec<JournalPane>> {
+        dates
+            .iter()
+            .map(
+                |&date| match loaded.iter().find(|pane| pane.journal.date == date) {
+                    Some(pane) => Ok(pane.clone()),
+                    None => Ok(JournalPane::new(self.load_day(date)?)),
+                },
+            )
+            .collect()
+    }
+
+    /// Moves the focus `steps` panes to the left, when negative, or right, as
+    /// that many presses of `h` or `l` would. Steps past the first or last pane
+    /// move every pane by the layout's step for each step and focus the pane at
+    /// that edge, so consecutive days continue past the edge and custom panes
+    /// keep their spacing. The panes are loaded once, however far they move.
+    fn navigate_split(&mut self, steps: i64) {
+        let Some(split) = &self.split else {
+            return;
+        };
+
+        let last = split.panes.len() as i64 - 1;
+        let target = split.active as i64 + steps;
+        if (0..=last).contains(&target) {
+            self.set_active_split_pane(target as usize);
+            return;
+        }
+
+        let window = if split.layout == SplitLayout::Week {
+            DateExpr::Offset(steps)
+                .resolve(split.active_pane().journal.date)
+                .and_then(|date| {
+                    week_of(date).map(|week| (week, date.weekday().num_days_from_monday() as usize))
+                })
+        } else {
+            let (passed, active) = if target < 0 {
+                (target, 0)
+            } else {
+                (target - last, last as usize)
+            };
+            split
+                .panes
+                .iter()
+                .map(|pane| DateExpr::Offset(passed).resolve(pane.journal.date))
+                .collect::<Option<Vec<_>>>()
+                .map(|dates| (dates, active))
+        };
+
+        match window {
+            Some((dates, active)) => self.show_split_dates(&dates, active),
+            None if steps < 0 => self.set_warning("Cannot switch before the supported date range."),
+            None => self.set_warning("Cannot switch after the supported date range."),
+        }
+    }
+
+    /// Loads `dates` into the split panes, reusing panes that already show one
+    /// of them, and focuses the pane at `active`.
+    fn show_split_dates(&mut self, dates: &[NaiveDate], active: usize) {
+        let Some(split) = &self.split else {
+            return;
+        };
+
+        let panes = match self.load_split_window(dates, &split.panes) {
+            Ok(panes) => panes,
+            Err(error) => {
+                self.set_error(format!("Could not load split view: {error}"));
+                return;
+            }
+        };
+
+        if let Some(split) = &mut self.split {
+            split.panes = panes;
+            split.active = active;
+        }
+
+        self.sync_active_journal_from_split();
+        self.prefetch_neighbours();
+        self.set_status(format!("Loaded {}.", self.journal.date.format("%Y-%m-%d")));
+    }
+
+    fn set_active_split_pane(&mut self, active: usize) {
+        if let Some(split) = &mut self.split {
+            split.active = active;
+        }
+
+        self.sync_active_journal_from_split();
+        self.set_status(format!("Focused {}.", self.journal.date.format("%Y-%m-%d")));
+    }
+
+    fn execute_pane_command(&mut self, command: PaneCommand) {
+        let date = match command {
+            PaneCommand::Add(date) | PaneCommand::Set(date) => match date.resolve(self.today) {
+                Some(date) => Some(date),
+                None => {
+                    self.set_warning("Date is outside the supported range.");
+                    return;
+                }
+            },
+            PaneCommand::Close => None,
+        };
+
+        match (command, date) {
+            (PaneCommand::Add(_), Some(date)) => self.add_split_pane(date),
+            (PaneCommand::Set(_), Some(date)) => self.set_split_pane_date(date),
+            _ => self.close_split_pane(),
+        }
+        self.focus = Focus::Journal;
+    }
+
+    /// Adds a pane for `date` after the focused one and focuses it. A single
+    /// day view becomes a split with the current day first.
+    fn add_split_pane(&mut self, date: NaiveDate) {
+        if self.focus_visible_date(date) {
+            return;
+        }
+
+        let pane = match self.load_day(date) {
+            Ok(journal) => JournalPane::new(journal),
+            Err(error) => {
+                self.set_error(format!(
+                    "Could not load {}: {error}",
+                    date.format("%Y-%m-%d")
+                ));
+                return;
+            }
+        };
+
+        let split = self.split.get_or_insert_with(|| SplitJournalView {
+            panes: vec![JournalPane {
+                journal: self.journal.clone(),
+                selected: self.selected,
+            }],
+            active: 0,
+            layout: SplitLayout::Custom,
+        });
+        split.active += 1;
+        split.panes.insert(split.active, pane);
+        split.layout = SplitLayout::Custom;
+
+        self.sync_active_journal_from_split();
+        self.set_status(format!("Added pane for {}.", date.format("%Y-%m-%d")));
+    }
+
+    /// Shows `date` in the focused pane without moving the other panes.
+    fn set_split_pane_date(&mut self, date: NaiveDate) {
+        if self.split.is_none() {
+            self.switch_to_day(date);
+            return;
+        }
+        if self.focus_visible_date(date) {
+            return;
+        }
+
+        let pane = match self.load_day(date) {
+            Ok(journal) => JournalPane::new(journal),
+            Err(error) => {
+                self.set_error(format!(
+                    "Could not load {}: {error}",
+                    date.format("%Y-%m-%d")
+                ));
+                return;
+            }
+        };
+
+        if let Some(split) = &mut self.split {
+            *split.active_pane_mut() = pane;
+            split.layout = SplitLayout::Custom;
+        }
+
+        self.sync_active_journal_from_split();
+        self.set_status(format!("Loaded {}.", date.format("%Y-%m-%d")));
+    }
+
+    /// Removes the focused pane. Closing the second-to-last pane leaves the
+    /// remaining day as a single view.
+    fn close_split_pane(&mut self) {
+        let Some(split) = &mut self.split else {
+            self.set_warning("No pane to close.");
+            return;
+        };
+
+        let closed = split.panes.remove(split.active).journal.date;
+        split.active = split.active.min(split.panes.len() - 1);
+        split.layout = SplitLayout::Custom;
+        if split.panes.len() == 1 {
+            self.close_split_view(&format!("Closed {}.", closed.format("%Y-%m-%d")));
+            return;
+        }
+
+        self.sync_active_journal_from_split();
+        self.set_status(format!(
+            "Closed {}. Focused {}.",
+            closed.format("%Y-%m-%d"),
+            self.journal.date.format("%Y-%m-%d")
+        ));
+    }
+
+    /// Focuses the pane that already shows `date`, so one day is never loaded
+    /// into two panes that would overwrite each other's changes.
+    fn focus_visible_date(&mut self, date: NaiveDate) -> bool {
+        let visible = match &self.split {
+            Some(split) => split
+                .panes
+                .iter()
+                .position(|pane| pane.journal.date == date),
+            None => None,
+        };
+        let Some(index) = visible else {
+            return false;
+        };
+
+        self.set_active_split_pane(index);
+        self.set_warning(format!("{} is already shown.", date.format("%Y-%m-%d")));
+        true
+    }
+}
+
+// Journal entry mutation and selection.
+impl App {
+    fn add_entry_to_active_journal(&mut self, draft: EntryDraft) -> io::Result<PathBuf> {
+        let now = self.now();
+        let date = draft
+            .date
+            .and_then(|date| date.resolve(self.today))
+            .unwrap_or(self.journal.date);
+
+        if date != self.journal.date {
+            return self.add_entry_to_other_day(date, draft, now);
+        }
+
+        if let Some(split) = &mut self.split {
+            let pane = split.active_pane_mut();
+            add_draft(&mut pane.journal, draft, now);
+            pane.journal.save()?;
+            pane.selected = last_entry_index(&pane.journal);
+            let path = pane.journal.path().to_path_buf();
+            self.sync_active_journal_from_split();
+            return Ok(path);
+        }
+
+        add_draft(&mut self.journal, draft, now);
+        self.journal.save()?;
+        self.selected = last_entry_index(&self.journal);
+        Ok(self.journal.path().to_path_buf())
+    }
+
+    /// Adds an entry to a day other than the focused one. A visible split pane
+    /// for that day is updated in place; otherwise the day is loaded, written
+    /// and dropped.
+    fn add_entry_to_other_day(
+        &mut self,
+        date: NaiveDate,
+        draft: EntryDraft,
+        now: NaiveDateTime,
+    ) -> io::Result<PathBuf> {
+        if let Some(split) = &mut self.split {
+            for pane in &mut split.panes {
+                if pane.journal.date == date {
+                    add_draft(&mut pane.journal, draft, now);
+                    pane.journal.save()?;
+                    pane.selected = last_entry_index(&pane.journal);
+                    return Ok(pane.journal.path().to_path_buf());
+                }
+            }
+        }
+
+        let mut journal = self.read_day(date)?;
+        add_draft(&mut journal, draft, now);
+        journal.save()?;
+        Ok(journal.path().to_path_buf())
+    }
+
+    fn complete_selected(&mut self) -> io::Result<()> {
+        self.toggle_selected_state(
+            "complete",
+            JournalEntry::toggle_complete,
+            EntryState::Completed,
+        )
+    }
+
+    fn cancel_selected(&mut self) -> io::Result<()> {
+        self.toggle_selected_state("cancel", JournalEntry::toggle_cancel, EntryState::Cancelled)
+    }
+
+    /// Applies a state toggle to the action range and records when each entry
+    /// changed. Entries that the toggle would move into `target` are toggled;
+    /// only when there are none are the others toggled back, so completing a
+    /// range never reopens the tasks in it that were already done.
+    fn toggle_selected_state(
+        &mut self,
+        action: &'static str,
+        toggle: fn(&mut JournalEntry) -> Result<&'static str, &'static str>,
+        target: EntryState,
+    ) -> io::Result<()> {
+        let now = self.now();
+        let reaches_target = |entry: &JournalEntry| {
+            let mut entry = entry.clone();
+            toggle(&mut entry).is_ok() && entry.state == target
+        };
+
+        let edited = self.edit_action_range(action, |entries| {
+            let forward = entries.iter().any(reaches_target);
+            let mut changed = 0;
+            let mut result = Err("No entry selected.");
+            for entry in entries.iter_mut() {
+                if forward && !reaches_target(entry) {
+                    continue;
+                }
+                let outcome = toggle(entry);
+                if outcome.is_ok() {
+                    entry.record_state_change(now);
+                    changed += 1;
+                }
+                if result.is_err() {
+                    result = outcome;
+                }
+            }
+            (changed, (forward, result))
+        })?;
+
+        match edited {
+            Some((changed, (forward, _))) if changed > 1 => {
+                let verb = match (forward, target) {
+                    (false, _) => "Reopened",
+                    (true, EntryState::Cancelled) => "Cancelled",
+                    (true, _) => "Completed",
+                };
+                self.set_status(format!("{verb} {}.", entry_count(changed)));
+            }
+            Some((_, (_, result))) => self.set_action_status(result),
+            None => {}
+        }
+
+        Ok(())
+    }
+
+    /// Marks every entry in the action range important, or unmarks them all
+    /// when they already are.
+    fn toggle_important_selected(&mut self) -> io::Result<()> {
+        let edited = self.edit_action_range("important", |entries| {
+            let mark = entries.iter().any(|entry| !entry.important);
+            let mut message = "";
+            for entry in entries.iter_mut().filter(|entry| entry.important != mark) {
+                message = entry.toggle_important();
+            }
+            (entries.len(), (mark, message))
+        })?;
+
+        match edited {
+            Some((1, (_, message))) => self.set_status(message),
+            Some((changed, (true, _))) => {
+                self.set_status(format!("Marked {} important.", entry_count(changed)));
+            }
+            Some((changed, (false, _))) => {
+                self.set_status(format!("Unmarked {}.", entry_count(changed)));
+            }
+            None => {}
+        }
+
+        Ok(())
+    }
+
+    /// Moves or copies the entries in the action range to `target`, or to the
+    /// next pane when no date is given. Moving an open task migrates it and
+    /// leaves the original in place; other moved entries are removed. Lines
+    /// the app does not parse and tasks that were already migrated are
+    /// skipped. Each day is saved once, and focus follows the entries when
+    /// their new day is visible.
+    fn transfer_selected(
+        &mut self,
+        mode: TransferMode,
+        target: Option<DateExpr>,
+    ) -> io::Result<()> {
+        let Some(range) = self.action_range() else {
+            self.set_warning("No entry selected.");
+            return Ok(());
+        };
+
+        let entries = &self.active_journal().entries;
+        let indexes = range
+            .clone()
+            .filter(|&index| {
+                let entry = &entries[index];
+                entry.kind != EntryKind::Raw
+                    && !(mode == TransferMode::Move && entry.state == EntryState::Migrated)
+            })
+            .collect::<Vec<_>>();
+        if indexes.is_empty() {
+            if entries[range]
+                .iter()
+                .all(|entry| entry.kind == EntryKind::Raw)
+            {
+                self.set_warning("Only journal entries can be moved or copied.");
+            } else {
+                self.set_warning("Task was already migrated.");
+            }
+            return Ok(());
+        }
+
+        let target = match target {
+            Some(date) => date.resolve(self.today),
+            None => self.next_pane_date(),
+        };
+        let Some(target) = target else {
+            self.set_warning("Open another pane or give a date, such as :move tomorrow.");
+            return Ok(());
+        };
+        if target == self.active_journal().date {
+            self.set_warning(format!(
+                "Entry is already on {}.",
+                target.format("%Y-%m-%d")
+            ));
+            return Ok(());
+        }
+
+        let now = self.now();
+        let copies = indexes
+            .iter()
+            .map(|&index| {
+                let entry = &self.active_journal().entries[index];
+                copied_entry(entry, target, now, mode == TransferMode::Move)
+            })
+            .collect::<Vec<_>>();
+        let source_before = self.active_journal().clone();
+        let selected = self.active_selected();
+
+        // Write the new day first so a failed save never loses the entries.
+        let target_pane = self.split.as_ref().and_then(|split| {
+            split
+                .panes
+                .iter()
+                .position(|pane| pane.journal.date == target)
+        });
+        let target_before = match (&mut self.split, target_pane) {
+            (Some(split), Some(target_pane)) => {
+                let pane = &mut split.panes[target_pane];
+                let before = pane.journal.clone();
+                pane.journal.entries.extend(copies);
+                pane.journal.save()?;
+                pane.selected = last_entry_index(&pane.journal);
+                before
+            }
+            _ => {
+                let mut journal = self.read_day(target)?;
+                let before = journal.clone();
+                journal.entries.extend(copies);
+                journal.save()?;
+                before
+            }
+        };
+
+        let (action, undo_action, journals) = match mode {
+            TransferMode::Copy => ("Copied", "copy", vec![target_before]),
+            TransferMode::Move => {
+                let action = self.with_active_journal(|journal, selected| {
+                    let mut action = "Migrated";
+                    for &index in indexes.iter().rev() {
+                        let entry = &mut journal.entries[index];
+                        if entry.migrate().is_ok() {
+                            entry.record_state_change(now);
+                        } else {
+                            journal.entries.remove(index);
+                            action = "Moved";
+                        }
+                    }
+                    *selected = last_entry_index(journal).map(|last| indexes[0].min(last));
+                    journal.save().map(|()| action)
+                })?;
+                (action, "move", vec![source_before, target_before])
+            }
+        };
+        let dismissed = match mode {
+            TransferMode::Copy => Vec::new(),
+            TransferMode::Move => {
+                dismissed_occurrences(&journals[0].entries, &self.active_journal().entries)
+            }
+        };
+        let source = self.active_journal().date;
+
+        self.visual = None;
+        self.push_undo(undo_action, journals, selected);
+        self.skip_occurrences(source, &dismissed)?;
+        if let Some(target_pane) = target_pane {
+            self.set_active_split_pane(target_pane);
+        }
+        let date = target.format("%Y-%m-%d");
+        if indexes.len() == 1 {
+            self.set_status(format!("{action} to {date}."));
+        } else {
+            self.set_status(format!(
+                "{action} {} to {date}.",
+                entry_count(indexes.len())
+            ));
+        }
+        Ok(())
+    }
+
+    /// The day of the pane after the focused one, wrapping to the first.
+    fn next_pane_date(&self) -> Option<NaiveDate> {
+        let split = self.split.as_ref()?;
+        if split.panes.len() < 2 {
+            return None;
+        }
+
+        Some(
+            split.panes[(split.active + 1) % split.panes.len()]
+                .journal
+                .date,
+        )
+    }
+
+    fn with_active_journal<T>(
+        &mut self,
+        update: impl FnOnce(&mut Journal, &mut Option<usize>) -> T,
+    ) -> T {
+        let Some(split) = &mut self.split else {
+            return update(&mut self.journal, &mut self.selected);
+        };
+
+        let pane = split.active_pane_mut();
+        let result = update(&mut pane.journal, &mut pane.selected);
+        self.sync_active_journal_from_split();
+        result
+    }
+
+    fn highlighted_entry_index(&self) -> Option<usize> {
+        let index = self.active_selected()?;
+        (index < self.active_journal().entries.len()).then_some(index)
+    }
+
+    fn valid_selected_index(&mut self) -> Option<usize> {
+        let entry_count = self.active_journal().entries.len();
+        if entry_count == 0 {
+            self.set_active_selected(None);
+            return None;
+        }
+
+        let max = entry_count - 1;
+        let index = self.active_selected().unwrap_or(max).min(max);
+        self.set_active_selected(Some(index));
+        Some(index)
+    }
+
+    fn select_previous(&mut self) {
+        let Some(index) = self.valid_selected_index() else {
+            return;
+        };
+
+        self.set_active_selected(Some(index.saturating_sub(1)));
+    }
+
+    fn select_next(&mut self) {
+        let Some(index) = self.valid_selected_index() else {
+            return;
+        };
+
+        let max = self.active_journal().entries.len() - 1;
+        self.set_active_selected(Some((index + 1).min(max)));
+    }
+
+    /// Highlights the entry at `index`, or the last one when it is past the
+    /// end.
+    fn select_entry(&mut self, index: usize) {
+        if self.valid_selected_index().is_none() {
+            return;
+        }
+
+        let max = self.active_journal().entries.len() - 1;
+        self.set_active_selected(Some(index.min(max)));
+    }
+
+    /// Goes to the start of the next section, or back to the start of this
+    /// section and then the ones before it.
+    fn select_section(&mut self, forward: bool) {
+        let Some(index) = self.valid_selected_index() else {
+            return;
+        };
+
+        let starts = section_starts(self.active_journal());
+        let target = if forward {
+            starts
+                .into_iter()
+                .find(|&start| start > index)
+                .unwrap_or(self.active_journal().entries.len() - 1)
+        } else {
+            starts
+                .into_iter()
+                .rev()
+                .find(|&start| start < index)
+                .unwrap_or(0)
+        };
+        self.set_active_selected(Some(target));
+    }
+
+    fn active_journal(&self) -> &Journal {
+        self.split
+            .as_ref()
+            .map(|split| &split.active_pane().journal)
+            .unwrap_or(&self.journal)
+    }
+
+    fn active_selected(&self) -> Option<usize> {
+        self.split
+            .as_ref()
+            .map(|split| split.active_pane().selected)
+            .unwrap_or(self.selected)
+    }
+
+    fn set_active_selected(&mut self, selected: Option<usize>) {
+        if let Some(split) = &mut self.split {
+            split.active_pane_mut().selected = selected;
+            self.sync_active_journal_from_split();
+        } else {
+            self.selected = selected;
+        }
+    }
+
+    /// Checks the visible days for changes and loads the days that the next
+    /// step left or right would show, so moving there does not wait for the
+    /// disk.
+    fn prefetch_neighbours(&self) {
+        let (dates, step) = match &self.split {
+            Some(split) => (
+                split
+                    .panes
+                    .iter()
+                    .map(|pane| pane.journal.date)
+                    .collect::<Vec<_>>(),
+                split.layout.step() as i64,
+            ),
+            None => (vec![self.journal.date], 1),
+        };
+
+        // The visible days go first, so a change to one of them is picked up
+        // before the neighbours are read.
+        let neighbours = dates
+            .iter()
+            .flat_map(|&date| [-step, step].map(|offset| DateExpr::Offset(offset).resolve(date)))
+            .flatten()
+            .filter(|date| !dates.contains(date))
+            .collect::<Vec<_>>();
+        self.cache.prefetch([dates, neighbours].concat());
+    }
+
+    fn visible_journals(&self) -> Vec<&Journal> {
+        match &self.split {
+            Some(split) => split.panes.iter().map(|pane| &pane.journal).collect(),
+            None => vec![&self.journal],
+        }
+    }
+
+    fn visible_journal(&self, date: NaiveDate) -> Option<&Journal> {
+        self.visible_journals()
+            .into_iter()
+            .find(|journal| journal.date == date)
+    }
+
+    fn sync_active_journal_from_split(&mut self) {
+        if let Some(split) = &self.split {
+            self.journal = split.active_pane().journal.clone();
+            self.selected = split.active_pane().selected;
+        }
+    }
+}
+
+// UI-facing state for split panes and command search.
+impl App {
+    pub fn now(&self) -> NaiveDateTime {
+        (self.clock)()
+    }
+
+    pub fn split_view(&self) -> Option<&SplitJournalView> {
+        self.split.as_ref()
+    }
+
+    pub fn today(&self) -> NaiveDate {
+        self.today
+    }
+
+    fn normalize_command_result_index(&mut self) {
+        let count = self.matching_command_options().len();
+        if count == 0 {
+            self.command_result_index = 0;
+        } else {
+            self.command_result_index = self.command_result_index.min(count - 1);
+        }
+    }
+
+    fn select_previous_command_result(&mut self) {
+        self.normalize_command_result_index();
+        self.command_result_index = self.command_result_index.saturating_sub(1);
+    }
+
+    fn select_next_command_result(&mut self) {
+        self.normalize_command_result_index();
+        let count = self.matching_command_options().len();
+        if count > 0 {
+            self.command_result_index = (self.command_result_index + 1).min(count - 1);
+        }
+    }
+
+    fn reset_command_pane(&mut self) {
+        self.command_mode = CommandPaneMode::Normal;
+        self.command_input.clear();
+        self.command_result_index = 0;
+        self.command_context = CommandContext::CommandPane;
+    }
+
+    pub fn command_title(&self) -> &'static str {
+        match self.command_mode {
+            CommandPaneMode::Normal => "Command",
+            CommandPaneMode::Search => "Search Commands",
+            CommandPaneMode::Entry(action) => action.entry_title(),
+        }
+    }
+
+    pub fn visible_command_search_results(&self) -> Vec<(usize, CommandSearchResult)> {
+        let results = self.command_search_results();
+        if results.is_empty() {
+            return Vec::new();
+        }
+
+        let selected = self.command_result_index.min(results.len() - 1);
+        let start = selected
+            .saturating_add(1)
+            .saturating_sub(COMMAND_SEARCH_RESULT_LIMIT);
+
+        results
+            .into_iter()
+            .enumerate()
+            .skip(start)
+            .take(COMMAND_SEARCH_RESULT_LIMIT)
+            .collect()
+    }
+
+    pub fn command_search_result_limit(&self) -> usize {
+        COMMAND_SEARCH_RESULT_LIMIT
+    }
+
+    pub fn command_search_input_is_exact_command(&self) -> bool {
+        command_from_search_input(&self.command_input, self.command_context).is_some()
+    }
+
+    fn command_search_results(&self) -> Vec<CommandSearchResult> {
+        self.matching_command_options()
+            .into_iter()
+            .map(|command| CommandSearchResult {
+                name: command.name,
+                token: command.token,
+            })
+            .collect()
+    }
+
+    fn matching_command_options(&self) -> Vec<&'static CommandOption> {
+        let options = self.available_command_options();
+        matching_command_options(&self.command_input, &options)
+    }
+
+    fn available_command_options(&self) -> Vec<&'static CommandOption> {
+        let mut options = self.entry_action_command_options();
+        options.extend(COMMAND_PANE_OPTIONS.iter());
+        options
+    }
+
+    fn entry_action_command_options(&self) -> Vec<&'static CommandOption> {
+        if self.command_context != CommandContext::JournalPane {
+            return Vec::new();
+        }
+
+        self.action_range_options()
+    }
+}
+
+/// The entry actions that apply to an entry of this kind and state.
+fn entry_action_options(entry: &JournalEntry) -> Vec<&'static CommandOption> {
+    let mut options = match entry.kind {
+        EntryKind::Task if entry.state == EntryState::Migrated => {
+            vec![&IMPORTANT_COMMAND_OPTION, &COPY_COMMAND_OPTION]
+        }
+        EntryKind::Task => {
+            let mut options = vec![&COMPLETE_COMMAND_OPTION];
+            if entry.state != EntryState::Completed {
+                options.push(&CANCEL_COMMAND_OPTION);
+            }
+            options.extend([
+                &IMPORTANT_COMMAND_OPTION,
+                &MOVE_COMMAND_OPTION,
+                &COPY_COMMAND_OPTION,
+            ]);
+            options
+        }
+        EntryKind::Event => vec![
+            &CANCEL_COMMAND_OPTION,
+            &IMPORTANT_COMMAND_OPTION,
+            &MOVE_COMMAND_OPTION,
+            &COPY_COMMAND_OPTION,
+        ],
+        EntryKind::Note | EntryKind::Feeling => vec![
+            &IMPORTANT_COMMAND_OPTION,
+            &MOVE_COMMAND_OPTION,
+            &COPY_COMMAND_OPTION,
+        ],
+        EntryKind::Raw => vec![&IMPORTANT_COMMAND_OPTION],
+    };
+    if entry.kind != EntryKind::Raw {
+        options.push(&DETAILS_COMMAND_OPTION);
+    }
+    if !find_links(&entry.text).is_empty() {
+        options.extend([&OPEN_COMMAND_OPTION, &CLIP_COMMAND_OPTION]);
+    }
+    options
+}
+
+fn command_rows<'a>(
+    options: impl IntoIterator<Item = &'a CommandOption>,
+) -> Vec<(String, &'static str)> {
+    options
+        .into_iter()
+        .map(|option| (option.token.to_string(), option.summary))
+        .collect()
+}
+
+/// How many lines the help popup takes: a title per section, its rows and a
+/// blank line between sections.
+pub fn help_line_count(sections: &[HelpSection]) -> usize {
+    sections
+        .iter()
+        .map(|section| section.rows.len() + 1)
+        .sum::<usize>()
+        + sections.len().saturating_sub(1)
+}
+
+/// Names an entry for the help popup, such as `open task` or `event`.
+fn entry_description(entry: &JournalEntry) -> String {
+    let kind = match entry.kind {
+        EntryKind::Note => "note",
+        EntryKind::Event => "event",
+        EntryKind::Feeling => "feeling",
+        EntryKind::Task => "task",
+        EntryKind::Raw => "line",
+    };
+    let state = match (entry.kind, entry.state) {
+        (EntryKind::Task, EntryState::Open) => "open ",
+        (_, EntryState::Open) => "",
+        (_, EntryState::Completed) => "completed ",
+        (_, EntryState::Cancelled) => "cancelled ",
+        (_, EntryState::Migrated) => "migrated ",
+    };
+    format!("{state}{kind}")
+}
+
+fn matching_command_options(
+    query: &str,
+    options: &[&'static CommandOption],
+) -> Vec<&'static CommandOption> {
+    let mut matches = options
+        .iter()
+        .enumerate()
+        .filter_map(|(index, &command)| {
+            command_match_quality(query, command).map(|quality| (quality, index, command))
+        })
+        .collect::<Vec<_>>();
+
+    matches.sort_by_key(|(quality, index, _)| (*quality, *index));
+    matches.into_iter().map(|(_, _, command)| command).collect()
+}
+
+fn command_match_quality(query: &str, command: &CommandOption) -> Option<MatchQuality> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Some(MatchQuality::Prefix);
+    }
+
+    let mut quality = None;
+    for candidate in std::iter::once(command.name)
+        .chain(std::iter::once(command.token))
+        .chain(command.aliases.iter().copied())
+    {
+        if starts_with_ignore_ascii_case(candidate, query) {
+            return Some(MatchQuality::Prefix);
+        }
+
+        if fuzzy_subsequence_match(query, candidate) {
+            quality = Some(MatchQuality::Subsequence);
+        }
+    }
+
+    quality
+}
+
+pub fn fuzzy_subsequence_match(query: &str, candidate: &str) -> bool {
+    let query = query.trim();
+    if query.is_empty() {
+        return true;
+    }
+
+    let mut candidate_chars = candidate
+        .chars()
+        .map(|character| character.to_ascii_lowercase());
+
+    'query: for query_char in query
+        .chars()
+        .map(|character| character.to_ascii_lowercase())
+    {
+        for candidate_char in candidate_chars.by_ref() {
+            if candidate_char == query_char {
+                continue 'query;
+            }
+        }
+
+        return false;
+    }
+
+    true
+}
+
+fn starts_with_ignore_ascii_case(candidate: &str, query: &str) -> bool {
+    candidate
+        .to_ascii_lowercase()
+        .starts_with(&query.to_ascii_lowercase())
+}
+
+fn command_from_search_input(
+    query: &str,
+    context: CommandContext,
+) -> Option<(String, CommandContext)> {
+    let query = query.trim();
+    if query.is_empty() {
+        return None;
+    }
+
+    let input = format!(":{query}");
+    match parse_command(&input).ok()? {
+        Command::Add(_)
+        | Command::Quit
+        | Command::ToggleSplit
+        | Command::ToggleWeek
+        | Command::ToggleTimestamps
+        | Command::ToggleTimeline
+        | Command::Recur(_)
+        | Command::Pane(_)
+        | Command::Goto(_)
+        | Command::OpenCalendar
+        | Command::Snooze(_)
+        | Command::Dismiss
+        | Command::ShowMessages
+        | Command::ShowHelp
+        | Command::Editor => Some((input, CommandContext::CommandPane)),
+        Command::Complete
+        | Command::Cancel
+        | Command::Important
+        | Command::Move(_)
+        | Command::Copy(_)
+        | Command::Open
+        | Command::Clip
+        | Command::EditDetails
+            if context == CommandContext::JournalPane =>
+        {
+            Some((input, CommandContext::JournalPane))
+        }
+        Command::Complete
+        | Command::Cancel
+        | Command::Important
+        | Command::Move(_)
+        | Command::Copy(_)
+        | Command::Open
+        | Command::Clip
+        | Command::EditDetails => None,
+    }
+}
+
+pub fn parse_command(input: &str) -> Result<Command, String> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(String::from("Enter a command."));
+    }
+
+    let (command, rest) = split_command(trimmed);
+    match command {
+        ":n" => entry_command(EntryKind::Note, rest),
+        ":e" => entry_command(EntryKind::Event, rest),
+        ":f" => entry_command(EntryKind::Feeling, rest),
+        ":t" => entry_command(EntryKind::Task, rest),
+        ":q" => Ok(Command::Quit),
+        ":split" => Ok(Command::ToggleSplit),
+        ":week" => Ok(Command::ToggleWeek),
+        ":times" | ":timestamps" => Ok(Command::ToggleTimestamps),
+        ":timeline" => Ok(Command::ToggleTimeline),
+        ":x" => Ok(Command::Complete),
+        ":c" => Ok(Command::Cancel),
+        ":i" | ":important" => Ok(Command::Important),
+        ":move" => Ok(Command::Move(optional_date(rest)?)),
+        ":copy" => Ok(Command::Copy(optional_date(rest)?)),
+        ":recur" => recur_command(rest),
+        ":pane" => pane_command(rest),
+        ":goto" | ":go" => match optional_date(rest)? {
+            Some(date) => Ok(Command::Goto(date)),
+            None => Err(String::from("Enter a date.")),
+        },
+        ":cal" | ":calendar" => Ok(Command::OpenCalendar),
+        ":snooze" if rest.is_empty() => Ok(Command::Snooze(None)),
+        ":snooze" => match rest.trim_end_matches('m').parse::<u32>() {
+            Ok(minutes) if minutes > 0 => Ok(Command::Snooze(Some(minutes))),
+            _ => Err(format!("Unknown snooze minutes: {rest}")),
+        },
+        ":dismiss" => Ok(Command::Dismiss),
+        ":messages" | ":msgs" => Ok(Command::ShowMessages),
+        ":help" | ":h" => Ok(Command::ShowHelp),
+        ":open" => Ok(Command::Open),
+        ":clip" => Ok(Command::Clip),
+        ":editor" | ":edit" => Ok(Command::Editor),
+        ":details" | ":body" => Ok(Command::EditDetails),
+        _ => Err(format!("Unknown command: {command}")),
+    }
+}
+
+fn optional_date(input: &str) -> Result<Option<DateExpr>, String> {
+    if input.is_empty() {
+        return Ok(None);
+    }
+
+    match parse_date_prefix(input) {
+        Some((date, "")) => Ok(Some(date)),
+        _ => Err(format!("Unknown date: {input}")),
+    }
+}
+
+/// Parses `:pane <date>`, `:pane set <date>` and `:pane close`.
+fn pane_command(rest: &str) -> Result<Command, String> {
+    let (first, date) = split_command(rest);
+    let (command, date): (fn(DateExpr) -> PaneCommand, &str) = match first {
+        "close" | "rm" if date.is_empty() => return Ok(Command::Pane(PaneCommand::Close)),
+        "set" => (PaneCommand::Set, date),
+        "" => {
+            return Err(String::from(
+                "Use :pane <date>, :pane set <date> or :pane close.",
+            ))
+        }
+        _ => (PaneCommand::Add, rest),
+    };
+
+    match optional_date(date)? {
+        Some(date) => Ok(Command::Pane(command(date))),
+        None => Err(String::from("Enter a date.")),
+    }
+}
+
+/// Parses `:recur`, `:recur rm <id>` and `:recur <rule> :<n|e|f|t> <text>`.
+fn recur_command(rest: &str) -> Result<Command, String> {
+    if rest.is_empty() {
+        return Ok(Command::Recur(RecurCommand::List));
+    }
+
+    let (first, id) = split_command(rest);
+    if matches!(first, "rm" | "remove") && !id.is_empty() {
+        return Ok(Command::Recur(RecurCommand::Remove(id.to_string())));
+    }
+
+    let usage = || String::from("Use :recur <rule> :<n|e|f|t> <text>.");
+    let index = rest.find(':').ok_or_else(usage)?;
+    let rule = rest[..index].trim();
+    let recurrence =
+        Recurrence::parse(rule).ok_or_else(|| format!("Unknown recurrence: {rule}"))?;
+    let Command::Add(draft) = parse_command(&rest[index..])? else {
+        return Err(usage());
+    };
+
+    Ok(Command::Recur(RecurCommand::Add { recurrence, draft }))
+}
+
+fn split_command(input: &str) -> (&str, &str) {
+    match input.find(char::is_whitespace) {
+        Some(index) => (&input[..index], input[index..].trim()),
+        None => (input, ""),
+    }
+}
+
+fn entry_command(kind: EntryKind, text: &str) -> Result<Command, String> {
+    let mut draft = EntryDraft::new(kind, text);
+
+    // A leading backslash keeps text such as `\tomorrow is a holiday` literal.
+    if let Some(literal) = text.strip_prefix('\\') {
+        draft.text = literal.to_string();
+    } else {
+        let mut text = text;
+        // Only tasks and events are scheduled for a day. Notes and feelings
+        // often start with a date word, as in `:n friday was great`.
+        if matches!(kind, EntryKind::Task | EntryKind::Event) {
+            if let Some((date, rest)) = parse_date_prefix(text) {
+                draft.date = Some(date);
+                text = rest;
+            }
+        }
+        if kind == EntryKind::Event {
+            if let Some((time, rest)) = parse_time_range_prefix(text) {
+                draft.time = Some(time);
+                text = rest;
+            }
+        }
+        draft.text = text.to_string();
+    }
+
+    if draft.text.is_empty() {
+        return Err(String::from("Entry text cannot be empty."));
+    }
+
+    Ok(Command::Add(draft))
+}
+
+/// A new entry for `date` with the same content as `entry`. A migrated task
+/// is copied as an open one. The detail note link is kept only when
+/// `keep_note` is set, for entries that move rather than get a twin.
+fn copied_entry(
+    entry: &JournalEntry,
+    date: NaiveDate,
+    now: NaiveDateTime,
+    keep_note: bool,
+) -> JournalEntry {
+    if entry.kind == EntryKind::Raw {
+        return JournalEntry::raw(entry.text.clone(), date);
+    }
+
+    let mut copy = JournalEntry::new(entry.kind, entry.text.clone(), date);
+    copy.time = entry.time;
+    copy.important = entry.important;
+    copy.state = match entry.state {
+        EntryState::Migrated => EntryState::Open,
+        state => state,
+    };
+    if keep_note {
+        copy.note = entry.note.clone();
+    }
+    copy.stamp_created(now);
+    copy
+}
+
+/// Saves a day as it was before a change. A day that had no entries has its
+/// file removed, so undoing the first paste into a day leaves no empty file.
+fn restore_day_file(journal: &mut Journal) -> io::Result<()> {
+    if journal.entries.is_empty() {
+        journal.remove_file()
+    } else {
+        journal.save()
+    }
+}
+
+/// Indexes where a section starts: the first entry, a heading, or an entry of
+/// a different kind from the one above it.
+fn section_starts(journal: &Journal) -> Vec<usize> {
+    let entries = &journal.entries;
+    (0..entries.len())
+        .filter(|&index| {
+            index == 0
+                || entries[index].kind != entries[index - 1].kind
+                || (entries[index].kind == EntryKind::Raw && entries[index].text.starts_with('#'))
+        })
+        .collect()
+}
+
+fn entry_count(count: usize) -> String {
+    if count == 1 {
+        String::from("1 entry")
+    } else {
+        format!("{count} entries")
+    }
+}
+
+fn add_draft(journal: &mut Journal, draft: EntryDraft, now: NaiveDateTime) {
+    let entry = journal.add_entry(draft.kind, draft.text);
+    entry.time = draft.time;
+    entry.stamp_created(now);
+}
+
+fn is_text_input(modifiers: KeyModifiers) -> bool {
+    !modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+}
+
+fn local_now() -> NaiveDateTime {
+    Local::now().naive_local()
+}
+
+fn consecutive_days(first: NaiveDate, count: usize) -> Option<Vec<NaiveDate>> {
+    (0..count)
+        .map(|offset| first.checked_add_days(Days::new(offset as u64)))
+        .collect()
+}
+
+/// Monday to Sunday of the week containing `date`.
+fn week_of(date: NaiveDate) -> Option<Vec<NaiveDate>> {
+    let days_from_monday = u64::from(date.weekday().num_days_from_monday());
+    date.checked_sub_days(Days::new(days_from_monday))
+        .and_then(|monday| consecutive_days(monday, 7))
+}
+
+/// The recurring rule ids whose occurrence `before` held and `after` does not.
+fn dismissed_occurrences(before: &[JournalEntry], after: &[JournalEntry]) -> Vec<String> {
+    before
+        .iter()
+        .filter_map(|entry| entry.recurrence.clone())
+        .filter(|id| {
+            !after
+                .iter()
+                .any(|entry| entry.recurrence.as_ref() == Some(id))
+        })
+        .collect()
+}
+
+/// Keeps a selection inside `journal`, or selects its last entry when there
+/// was none.
+fn clamped_selection(selected: Option<usize>, journal: &Journal) -> Option<usize> {
+    let last = last_entry_index(journal)?;
+    Some(selected.map_or(last, |selected| selected.min(last)))
+}
+
+fn last_entry_index(journal: &Journal) -> Option<usize> {
+    journal.entries.len().checked_sub(1)
+}
+
+fn journal_root(journal: &Journal) -> PathBuf {
+    journal
+        .path()
+        .parent()
+        .unwrap_or_else(|| Path::new(""))
+        .to_path_buf()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        env, fs, io,
+        path::PathBuf,
+        thread,
+        time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+    };
+
+    use chrono::{Datelike, NaiveDate, NaiveTime};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 5, 21).unwrap()
+    }
+
+    fn test_root() -> PathBuf {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+
+        env::temp_dir().join(format!("bullet-journal-tui-app-test-{unique}"))
+    }
+
+    /// The command pane options matching `query`, as search shows them.
+    fn command_search_results(query: &str) -> Vec<CommandSearchResult> {
+        let options = COMMAND_PANE_OPTIONS.iter().collect::<Vec<_>>();
+        matching_command_options(query, &options)
+            .into_iter()
+            .map(|command| CommandSearchResult {
+                name: command.name,
+                token: command.token,
+            })
+            .collect()
+    }
+
+    fn test_now() -> NaiveDateTime {
+        date().and_hms_opt(9, 30, 0).unwrap()
+    }
+
+    fn test_app() -> io::Result<(App, PathBuf)> {
+        let root = test_root();
+        let journal = Journal::load_for_date(&root, date())?;
+        let mut app = App::new(journal);
+        app.clock = test_now;
+        Ok((app, root))
+    }
+
+    fn status(app: &App) -> &str {
+        app.latest_message()
+            .map_or("", |message| message.text.as_str())
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn type_text(app: &mut App, text: &str) -> io::Result<()> {
+        for character in text.chars() {
+            app.handle_key(key(KeyCode::Char(character)))?;
+        }
+
+        Ok(())
+    }
+
+    fn search_result_names(app: &App) -> Vec<&'static str> {
+        app.visible_command_search_results()
+            .into_iter()
+            .map(|(_, result)| result.name)
+            .collect()
+    }
+
+    fn run_journal_search(app: &mut App, input: &str) -> io::Result<()> {
+        app.handle_key(key(KeyCode::Char(':')))?;
+        type_text(app, input)?;
+        app.handle_key(key(KeyCode::Enter))
+    }
+
+    fn toggle_split(app: &mut App) -> io::Result<()> {
+        run_journal_search(app, "split")
+    }
+
+    #[test]
+    fn parses_entry_commands() {
+        assert_eq!(
+            parse_command(":n a note").unwrap(),
+            Command::Add(EntryDraft::new(EntryKind::Note, "a note"))
+        );
+        assert_eq!(
+            parse_command(":e Meeting at 10pm").unwrap(),
+            Command::Add(EntryDraft::new(EntryKind::Event, "Meeting at 10pm"))
+        );
+        assert_eq!(
+            parse_command(":f accomplished").unwrap(),
+            Command::Add(EntryDraft::new(EntryKind::Feeling, "accomplished"))
+        );
+        assert_eq!(
+            parse_command(":t ship MVP").unwrap(),
+            Command::Add(EntryDraft::new(EntryKind::Task, "ship MVP"))
+        );
+    }
+
+    #[test]
+    fn parses_event_time_prefixes() {
+        let at = |hour, minute| NaiveTime::from_hms_opt(hour, minute, 0).unwrap();
+
+        let Command::Add(draft) = parse_command(":e 14:00-15:00 design review").unwrap() else {
+            panic!("expected an entry command");
+        };
+        assert_eq!(draft.text, "design review");
+        assert_eq!(
+            draft.time,
+            Some(TimeRange {
+                start: at(14, 0),
+                end: Some(at(15, 0)),
+            })
+        );
+
+        let Command::Add(draft) = parse_command(":e 10am offsite").unwrap() else {
+            panic!("expected an entry command");
+        };
+        assert_eq!(draft.text, "offsite");
+        assert_eq!(draft.time.map(|time| time.start), Some(at(10, 0)));
+
+        assert_eq!(
+            parse_command(":t 10am call").unwrap(),
+            Command::Add(EntryDraft::new(EntryKind::Task, "10am call"))
+        );
+    }
+
+    #[test]
+    fn parses_entry_date_prefixes() {
+        let Command::Add(draft) = parse_command(":t tomorrow call dentist").unwrap() else {
+            panic!("expected an entry command");
+        };
+        assert_eq!(draft.date, Some(DateExpr::Offset(1)));
+        assert_eq!(draft.text, "call dentist");
+
+        let Command::Add(draft) = parse_command(":e next fri 10am offsite").unwrap() else {
+            panic!("expected an entry command");
+        };
+        assert_eq!(
+            draft.date,
+            Some(DateExpr::NextWeekday(chrono::Weekday::Fri))
+        );
+        assert_eq!(
+            draft.time.map(|time| time.start),
+            NaiveTime::from_hms_opt(10, 0, 0)
+        );
+        assert_eq!(draft.text, "offsite");
+
+        assert_eq!(
+            parse_command(":n \\tomorrow is a holiday").unwrap(),
+            Command::Add(EntryDraft::new(EntryKind::Note, "tomorrow is a holiday"))
+        );
+        assert!(parse_command(":t tomorrow").is_err());
+
+        assert_eq!(
+            parse_command(":n friday was great").unwrap(),
+            Command::Add(EntryDraft::new(EntryKind::Note, "friday was great"))
+        );
+        assert_eq!(
+            parse_command(":f today I learned a lot").unwrap(),
+            Command::Add(EntryDraft::new(EntryKind::Feeling, "today I learned a lot"))
+        );
+    }
+
+    #[test]
+    fn parses_recur_commands() {
+        assert_eq!(
+            parse_command(":recur").unwrap(),
+            Command::Recur(RecurCommand::List)
+        );
+        assert_eq!(
+            parse_command(":recur rm r2").unwrap(),
+            Command::Recur(RecurCommand::Remove(String::from("r2")))
+        );
+
+        let mut draft = EntryDraft::new(EntryKind::Event, "1:1");
+        draft.time = parse_time_range_prefix("14:00").map(|(time, _)| time);
+        assert_eq!(
+            parse_command(":recur weekly mon :e 14:00 1:1").unwrap(),
+            Command::Recur(RecurCommand::Add {
+                recurrence: Recurrence::Weekly(chrono::Weekday::Mon),
+                draft,
+            })
+        );
+
+        assert!(parse_command(":recur weekly :t standup").is_err());
+        assert!(parse_command(":recur daily standup").is_err());
+        assert!(parse_command(":recur daily :q").is_err());
+    }
+
+    #[test]
+    fn parses_move_and_copy_commands() {
+        assert_eq!(parse_command(":move").unwrap(), Command::Move(None));
+        assert_eq!(
+            parse_command(":move tomorrow").unwrap(),
+            Command::Move(Some(DateExpr::Offset(1)))
+        );
+        assert_eq!(
+            parse_command(":copy 2026-06-01").unwrap(),
+            Command::Copy(Some(DateExpr::Date(
+                NaiveDate::from_ymd_opt(2026, 6, 1).unwrap()
+            )))
+        );
+        assert!(parse_command(":copy somewhere").is_err());
+    }
+
+    #[test]
+    fn parses_pane_commands() {
+        assert_eq!(
+            parse_command(":pane -7d").unwrap(),
+            Command::Pane(PaneCommand::Add(DateExpr::Offset(-7)))
+        );
+        assert_eq!(
+            parse_command(":pane set next week").unwrap(),
+            Command::Pane(PaneCommand::Set(DateExpr::NextWeek))
+        );
+        assert_eq!(
+            parse_command(":pane close").unwrap(),
+            Command::Pane(PaneCommand::Close)
+        );
+        assert!(parse_command(":pane").is_err());
+        assert!(parse_command(":pane someday").is_err());
+        assert!(parse_command(":pane today please").is_err());
+    }
+
+    #[test]
+    fn parses_navigation_and_action_commands() {
+        assert_eq!(parse_command(":q").unwrap(), Command::Quit);
+        assert_eq!(parse_command(":split").unwrap(), Command::ToggleSplit);
+        assert_eq!(parse_command(":x").unwrap(), Command::Complete);
+        assert_eq!(parse_command(":c").unwrap(), Command::Cancel);
+        assert_eq!(parse_command(":i").unwrap(), Command::Important);
+        assert_eq!(parse_command(":important").unwrap(), Command::Important);
+    }
+
+    #[test]
+    fn rejects_empty_entries_and_unknown_commands() {
+        assert!(parse_command(":n").is_err());
+        assert!(parse_command(":cw").is_err());
+        assert!(parse_command(":wat").is_err());
+        assert!(parse_command("").is_err());
+    }
+
+    #[test]
+    fn starts_with_journal_focused() -> io::Result<()> {
+        let (app, root) = test_app()?;
+
+        assert_eq!(app.focus, Focus::Journal);
+        assert_eq!(app.command_mode, CommandPaneMode::Normal);
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn space_opens_the_leader_menu_from_focused_journal() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
+
+        app.handle_key(key(KeyCode::Char(' ')))?;
+        assert_eq!(
+            app.overlay,
+            Some(Overlay::Leader {
+                keys: String::new()
+            })
+        );
+        let menu = app.leader_menu("");
+        assert_eq!(
+            menu[..4].iter().map(|item| item.key).collect::<String>(),
+            "neft"
+        );
+        assert!(menu.contains(&LeaderMenuItem {
+            key: 'g',
+            label: "go to",
+            is_group: true,
+        }));
+
+        app.handle_key(key(KeyCode::Char('t')))?;
+        assert!(app.overlay.is_none());
+        assert_eq!(app.focus, Focus::Command);
+        assert_eq!(
+            app.command_mode,
+            CommandPaneMode::Entry(CommandAction::Add(EntryKind::Task))
+        );
+        type_text(&mut app, "ship feature")?;
+        app.handle_key(key(KeyCode::Enter))?;
+        assert_eq!(app.journal.entries[0].text, "ship feature");
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn leader_menu_offers_what_journal_search_offers() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
+        app.journal.add_entry(EntryKind::Task, "ship feature");
+        app.selected = Some(0);
+
+        app.handle_key(key(KeyCode::Char(':')))?;
+        let mut searched = app
+            .matching_command_options()
+            .into_iter()
+            .map(|option| option.name)
+            .collect::<Vec<_>>();
+        app.handle_key(key(KeyCode::Esc))?;
+        let mut offered = app
+            .leader_options()
+            .into_iter()
+            .map(|option| option.name)
+            .collect::<Vec<_>>();
+        searched.sort_unstable();
+        offered.sort_unstable();
+        assert_eq!(offered, searched);
+
+        let menu = app.leader_menu("");
+        assert_eq!(
+            menu[..2],
+            [
+                LeaderMenuItem {
+                    key: 'x',
+                    label: "complete",
+                    is_group: false,
+                },
+                LeaderMenuItem {
+                    key: 'c',
+                    label: "cancel",
+                    is_group: false,
+                },
+            ]
+        );
+        type_text(&mut app, " x")?;
+        assert_eq!(app.journal.entries[0].state, EntryState::Completed);
+        assert_eq!(app.focus, Focus::Journal);
+
+        type_text(&mut app, " c")?;
+        assert!(app.overlay.is_none());
+        assert_eq!(status(&app), "No leader action for c.");
+
+        type_text(&mut app, " g")?;
+        assert_eq!(
+            app.overlay,
+            Some(Overlay::Leader {
+                keys: String::from("g")
+            })
+        );
+        let names = app
+            .leader_menu("g")
+            .into_iter()
+            .map(|item| item.label)
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["goto", "calendar"]);
+        app.handle_key(key(KeyCode::Backspace))?;
+        app.handle_key(key(KeyCode::Char('g')))?;
+        app.handle_key(key(KeyCode::Char('c')))?;
+        assert!(matches!(app.overlay, Some(Overlay::Calendar(_))));
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn space_remains_literal_input_in_command_text_modes() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
+
+        app.focus = Focus::Command;
+        app.handle_key(key(KeyCode::Char(' ')))?;
+        assert_eq!(app.focus, Focus::Command);
+        assert_eq!(app.command_mode, CommandPaneMode::Normal);
+        assert_eq!(app.command_input, " ");
+
+        app.open_command_search(CommandContext::CommandPane);
+        app.handle_key(key(KeyCode::Char(' ')))?;
+        assert_eq!(app.command_mode, CommandPaneMode::Search);
+        assert_eq!(app.command_input, " ");
+
+        app.command_mode = CommandPaneMode::Entry(CommandAction::Add(EntryKind::Note));
+        app.command_input = String::from("draft");
+        app.handle_key(key(KeyCode::Char(' ')))?;
+        assert_eq!(
+            app.command_mode,
+            CommandPaneMode::Entry(CommandAction::Add(EntryKind::Note))
+        );
+        assert_eq!(app.command_input, "draft ");
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn switches_between_journal_days_with_left_and_right_arrows() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
+        fs::create_dir_all(&root)?;
+        fs::write(
+            root.join("2026-05-20.md"),
+            "- yesterday note\n· yesterday task\n",
+        )?;
+
+        app.handle_key(key(KeyCode::Left))?;
+
+        assert_eq!(
+            app.journal.date,
+            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
+        );
+        assert_eq!(app.journal.entries.len(), 2);
+        assert_eq!(app.journal.entries[0].text, "yesterday note");
+        assert_eq!(app.journal.entries[1].text, "yesterday task");
+        assert_eq!(app.selected, Some(1));
+        assert_eq!(app.focus, Focus::Journal);
+        assert_eq!(status(&app), "Loaded 2026-05-20.");
+
+        app.handle_key(key(KeyCode::Right))?;
+
+        assert_eq!(app.journal.date, date());
+        assert!(app.journal.entries.is_empty());
+        assert_eq!(app.selected, None);
+        assert!(!root.join("2026-05-21.md").exists());
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn navigates_single_journal_with_vim_keys() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
+        fs::create_dir_all(&root)?;
+        fs::write(root.join("2026-05-20.md"), "- yesterday note\n")?;
+        app.journal.add_entry(EntryKind::Note, "today one");
+        app.journal.add_entry(EntryKind::Note, "today two");
+        app.journal.add_entry(EntryKind::Note, "today three");
+        app.selected = Some(1);
+
+        app.handle_key(key(KeyCode::Char('k')))?;
+        assert_eq!(app.selected, Some(0));
+
+        app.handle_key(key(KeyCode::Char('j')))?;
+        assert_eq!(app.selected, Some(1));
+
+        app.handle_key(key(KeyCode::Char('h')))?;
+
+        assert_eq!(
+            app.journal.date,
+            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
+        );
+        assert_eq!(app.journal.entries[0].text, "yesterday note");
+        assert_eq!(app.selected, Some(0));
+
+        app.handle_key(key(KeyCode::Char('l')))?;
+
+        assert_eq!(app.journal.date, date());
+        assert!(app.journal.entries.is_empty());
+        assert_eq!(app.selected, None);
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn switching_to_empty_day_does_not_create_file() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
+
+        app.handle_key(key(KeyCode::Right))?;
+
+        assert_eq!(
+            app.journal.date,
+            NaiveDate::from_ymd_opt(2026, 5, 22).unwrap()
+        );
+        assert!(app.journal.entries.is_empty());
+        assert_eq!(app.selected, None);
+        assert!(!root.join("2026-05-22.md").exists());
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn writes_new_entries_to_displayed_day() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
+
+        app.handle_key(key(KeyCode::Right))?;
+        app.handle_key(key(KeyCode::Char(':')))?;
+        type_text(&mut app, "n future note")?;
+        app.handle_key(key(KeyCode::Enter))?;
+
+        assert_eq!(
+            app.journal.date,
+            NaiveDate::from_ymd_opt(2026, 5, 22).unwrap()
+        );
+        assert_eq!(app.journal.entries.len(), 1);
+        assert_eq!(
+            app.journal.entries[0].created_on,
+            NaiveDate::from_ymd_opt(2026, 5, 22).unwrap()
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("2026-05-22.md"))?,
+            "  - future note <!-- created 2026-05-21T09:30 -->\n"
+        );
+        assert!(!root.join("2026-05-21.md").exists());
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn toggles_split_view_on_for_yesterday_and_today() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        fs::write(root.join("2026-05-20.md"), "- yesterday note\n")?;
+        fs::write(root.join("2026-05-21.md"), "- today note\n")?;
+
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+        app.clock = test_now;
+        toggle_split(&mut app)?;
+
+        let split = app.split.as_ref().expect("split view should be active");
+        assert_eq!(
+            split.panes[0].journal.date,
+            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
+        );
+        assert_eq!(split.panes[1].journal.date, date());
+        assert_eq!(split.active, 1);
+        assert_eq!(app.journal.date, date());
+        assert_eq!(app.selected, Some(0));
+        assert_eq!(split.panes[0].journal.entries[0].text, "yesterday note");
+        assert_eq!(split.panes[1].journal.entries[0].text, "today note");
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn split_view_arrows_focus_and_shift_the_two_day_window() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
+
+        toggle_split(&mut app)?;
+        app.handle_key(key(KeyCode::Left))?;
+
+        let split = app.split.as_ref().expect("split view should be active");
+        assert_eq!(split.active, 0);
+        assert_eq!(
+            app.journal.date,
+            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
+        );
+
+        app.handle_key(key(KeyCode::Left))?;
+
+        let split = app.split.as_ref().expect("split view should be active");
+        assert_eq!(
+            split.panes[0].journal.date,
+            NaiveDate::from_ymd_opt(2026, 5, 19).unwrap()
+        );
+        assert_eq!(
+            split.panes[1].journal.date,
+            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
+        );
+        assert_eq!(split.active, 0);
+        assert_eq!(
+            app.journal.date,
+            NaiveDate::from_ymd_opt(2026, 5, 19).unwrap()
+        );
+
+        app.handle_key(key(KeyCode::Right))?;
+
+        let split = app.split.as_ref().expect("split view should be active");
+        assert_eq!(split.active, 1);
+        assert_eq!(
+            app.journal.date,
+            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
+        );
+
+        app.handle_key(key(KeyCode::Right))?;
+
+        let split = app.split.as_ref().expect("split view should be active");
+        assert_eq!(
+            split.panes[0].journal.date,
+            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
+        );
+        assert_eq!(split.panes[1].journal.date, date());
+        assert_eq!(split.active, 1);
+        assert_eq!(app.journal.date, date());
+
+        type_text(&mut app, "5l")?;
+        let split = app.split.as_ref().expect("split view should be active");
+        assert_eq!(split.panes[0].journal.date, date() + Days::new(4));
+        assert_eq!(split.panes[1].journal.date, date() + Days::new(5));
+        assert_eq!(split.active, 1);
+
+        type_text(&mut app, "3h")?;
+        let split = app.split.as_ref().expect("split view should be active");
+        assert_eq!(split.panes[0].journal.date, date() + Days::new(2));
+        assert_eq!(split.panes[1].journal.date, date() + Days::new(3));
+        assert_eq!(split.active, 0);
+        assert_eq!(app.journal.date, date() + Days::new(2));
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn split_view_navigates_with_vim_keys() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        fs::write(
+            root.join("2026-05-20.md"),
+            "- yesterday one\n- yesterday two\n",
+        )?;
+        fs::write(
+            root.join("2026-05-21.md"),
+            "- today one\n- today two\n- today three\n",
+        )?;
+
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+        app.clock = test_now;
+        toggle_split(&mut app)?;
+        app.handle_key(key(KeyCode::Char('k')))?;
+        assert_eq!(app.selected, Some(1));
+        assert_eq!(
+            app.split.as_ref().expect("split view").panes[1].selected,
+            Some(1)
+        );
+
+        app.handle_key(key(KeyCode::Char('h')))?;
+
+        let split = app.split.as_ref().expect("split view should be active");
+        assert_eq!(split.active, 0);
+        assert_eq!(
+            app.journal.date,
+            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
+        );
+        assert_eq!(app.selected, Some(1));
+
+        app.handle_key(key(KeyCode::Char('k')))?;
+        assert_eq!(app.selected, Some(0));
+
+        app.handle_key(key(KeyCode::Char('j')))?;
+        assert_eq!(app.selected, Some(1));
+
+        app.handle_key(key(KeyCode::Char('h')))?;
+
+        let split = app.split.as_ref().expect("split view should be active");
+        assert_eq!(
+            split.panes[0].journal.date,
+            NaiveDate::from_ymd_opt(2026, 5, 19).unwrap()
+        );
+        assert_eq!(
+            split.panes[1].journal.date,
+            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
+        );
+        assert_eq!(split.active, 0);
+
+        app.handle_key(key(KeyCode::Char('l')))?;
+
+        let split = app.split.as_ref().expect("split view should be active");
+        assert_eq!(split.active, 1);
+        assert_eq!(
+            app.journal.date,
+            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
+        );
+
+        app.handle_key(key(KeyCode::Char('l')))?;
+
+        let split = app.split.as_ref().expect("split view should be active");
+        assert_eq!(
+            split.panes[0].journal.date,
+            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
+        );
+        assert_eq!(split.panes[1].journal.date, date());
+        assert_eq!(split.active, 1);
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn split_view_keeps_independent_selection_per_pane() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        fs::write(
+            root.join("2026-05-20.md"),
+            "- yesterday one\n- yesterday two\n",
+        )?;
+        fs::write(
+            root.join("2026-05-21.md"),
+            "- today one\n- today two\n- today three\n",
+        )?;
+
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+        app.clock = test_now;
+        toggle_split(&mut app)?;
+        assert_eq!(app.selected, Some(2));
+
+        app.handle_key(key(KeyCode::Up))?;
+        assert_eq!(app.selected, Some(1));
+        assert_eq!(
+            app.split.as_ref().expect("split view").panes[1].selected,
+            Some(1)
+        );
+
+        app.handle_key(key(KeyCode::Left))?;
+        assert_eq!(app.selected, Some(1));
+        app.handle_key(key(KeyCode::Up))?;
+        assert_eq!(app.selected, Some(0));
+        assert_eq!(
+            app.split.as_ref().expect("split view").panes[0].selected,
+            Some(0)
+        );
+
+        app.handle_key(key(KeyCode::Right))?;
+
+        let split = app.split.as_ref().expect("split view should be active");
+        assert_eq!(split.active, 1);
+        assert_eq!(app.selected, Some(1));
+        assert_eq!(split.panes[0].selected, Some(0));
+        assert_eq!(split.panes[1].selected, Some(1));
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn split_view_writes_new_entries_to_focused_pane() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
+
+        toggle_split(&mut app)?;
+        app.handle_key(key(KeyCode::Left))?;
+        run_journal_search(&mut app, "n older add")?;
+
+        let split = app.split.as_ref().expect("split view should be active");
+        assert_eq!(split.active, 0);
+        assert_eq!(
+            split.panes[0].journal.date,
+            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
+        );
+        assert_eq!(split.panes[0].journal.entries[0].text, "older add");
+        assert!(split.panes[1].journal.entries.is_empty());
+        assert_eq!(app.journal.date, split.panes[0].journal.date);
+        assert_eq!(app.selected, Some(0));
+        assert_eq!(
+            fs::read_to_string(root.join("2026-05-20.md"))?,
+            "  - older add <!-- created 2026-05-21T09:30 -->\n"
+        );
+        assert!(!root.join("2026-05-21.md").exists());
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn split_view_applies_entry_actions_to_focused_pane() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        fs::write(root.join("2026-05-20.md"), "· old task\n")?;
+        fs::write(root.join("2026-05-21.md"), "· today task\n")?;
+
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+        app.clock = test_now;
+        toggle_split(&mut app)?;
+        app.handle_key(key(KeyCode::Left))?;
+        run_journal_search(&mut app, "x")?;
+
+        let split = app.split.as_ref().expect("split view should be active");
+        assert_eq!(split.active, 0);
+        assert_eq!(
+            split.panes[0].journal.entries[0].state,
+            crate::journal::EntryState::Completed
+        );
+        assert_eq!(
+            split.panes[1].journal.entries[0].state,
+            crate::journal::EntryState::Open
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("2026-05-20.md"))?,
+            "  X old task <!-- completed 2026-05-21T09:30 -->\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("2026-05-21.md"))?,
+            "· today task\n"
+        );
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn split_view_toggles_importance_on_focused_pane() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        fs::write(root.join("2026-05-20.md"), "- old note\n")?;
+        fs::write(root.join("2026-05-21.md"), "- today note\n")?;
+
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+        app.clock = test_now;
+        toggle_split(&mut app)?;
+        app.handle_key(key(KeyCode::Left))?;
+        run_journal_search(&mut app, "i")?;
+
+        let split = app.split.as_ref().expect("split view should be active");
+        assert_eq!(split.active, 0);
+        assert!(split.panes[0].journal.entries[0].important);
+        assert!(!split.panes[1].journal.entries[0].important);
+        assert_eq!(
+            fs::read_to_string(root.join("2026-05-20.md"))?,
+            "* - old note\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("2026-05-21.md"))?,
+            "- today note\n"
+        );
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn toggling_split_view_off_keeps_the_active_day() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
+
+        toggle_split(&mut app)?;
+        app.handle_key(key(KeyCode::Left))?;
+        toggle_split(&mut app)?;
+
+        assert!(app.split.is_none());
+        assert_eq!(
+            app.journal.date,
+            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
+        );
+
+        app.handle_key(key(KeyCode::Left))?;
+
+        assert_eq!(
+            app.journal.date,
+            NaiveDate::from_ymd_opt(2026, 5, 19).unwrap()
+        );
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn split_view_does_not_create_files_for_empty_displayed_days() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
+
+        toggle_split(&mut app)?;
+
+        assert!(app.split.is_some());
+        assert!(!root.join("2026-05-20.md").exists());
+        assert!(!root.join("2026-05-21.md").exists());
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn matches_fuzzy_subsequences_case_insensitively() {
+        assert!(fuzzy_subsequence_match("nt", "note"));
+        assert!(fuzzy_subsequence_match("TD", "todo"));
+        assert!(!fuzzy_subsequence_match("task", "note"));
+    }
+
+    #[test]
+    fn searches_only_command_pane_commands() {
+        let task_results = command_search_results("t");
+        assert_eq!(task_results[0].name, "task");
+
+        assert!(command_search_results("cw").is_empty());
+
+        let all_names = command_search_results("")
+            .into_iter()
+            .map(|result| result.name)
+            .collect::<Vec<_>>();
+        assert!(all_names.contains(&"split"));
+        assert!(!all_names.contains(&"switch to journal"));
+        assert!(!all_names.contains(&"yesterday"));
+        assert!(!all_names.contains(&"tomorrow"));
+        assert!(!all_names.contains(&"complete"));
+        assert!(!all_names.contains(&"cancel"));
+        assert!(!all_names.contains(&"important"));
+    }
+
+    #[test]
+    fn selects_entry_command_from_fuzzy_search_and_submits_text() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
+
+        app.handle_key(key(KeyCode::Char(':')))?;
+        assert_eq!(app.command_mode, CommandPaneMode::Search);
+        assert_eq!(app.focus, Focus::Command);
+
+        type_text(&mut app, "nt")?;
+        app.handle_key(key(KeyCode::Enter))?;
+
+        assert_eq!(
+            app.command_mode,
+            CommandPaneMode::Entry(CommandAction::Add(EntryKind::Note))
+        );
+        assert_eq!(app.command_title(), "Enter a note");
+
+        type_text(&mut app, "a fuzzy note")?;
+        app.handle_key(key(KeyCode::Enter))?;
+
+        assert_eq!(app.command_mode, CommandPaneMode::Normal);
+        assert_eq!(app.focus, Focus::Journal);
+        assert_eq!(app.journal.entries.len(), 1);
+        assert_eq!(app.journal.entries[0].kind, EntryKind::Note);
+        assert_eq!(app.journal.entries[0].text, "a fuzzy note");
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn navigates_search_results_with_arrow_keys() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
+
+        app.handle_key(key(KeyCode::Char(':')))?;
+        app.handle_key(key(KeyCode::Down))?;
+        app.handle_key(key(KeyCode::Enter))?;
+
+        assert_eq!(
+            app.command_mode,
+            CommandPaneMode::Entry(CommandAction::Add(EntryKind::Event))
+        );
+        assert_eq!(app.command_title(), "Enter an event");
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn escape_hides_command_search_and_focuses_journal() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
+
+        app.handle_key(key(KeyCode::Char(':')))?;
+        type_text(&mut app, "nt")?;
+        app.handle_key(key(KeyCode::Esc))?;
+
+        assert_eq!(app.focus, Focus::Journal);
+        assert_eq!(app.command_mode, CommandPaneMode::Normal);
+        assert!(app.command_input.is_empty());
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn escape_hides_selected_command_entry_and_focuses_journal() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
+
+        app.handle_key(key(KeyCode::Char(':')))?;
+        type_text(&mut app, "nt")?;
+        app.handle_key(key(KeyCode::Enter))?;
+        type_text(&mut app, "draft")?;
+        app.handle_key(key(KeyCode::Esc))?;
+
+        assert_eq!(app.focus, Focus::Journal);
+        assert_eq!(app.command_mode, CommandPaneMode::Normal);
+        assert!(app.command_input.is_empty());
+        assert!(app.journal.entries.is_empty());
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn executes_no_text_command_immediately_from_search() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
+
+        app.handle_key(key(KeyCode::Char(':')))?;
+        type_text(&mut app, "q")?;
+        app.handle_key(key(KeyCode::Enter))?;
+
+        assert_eq!(app.command_mode, CommandPaneMode::Normal);
+        assert!(app.should_quit);
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
     }
-}
 
-fn entry_command(kind: EntryKind, text: &str) -> Result<Command, String> {
-    if text.is_empty() {
-        return Err(String::from("Entry text cannot be empty."));
-    }
+    #[test]
+    fn keeps_exact_command_submission_available_from_search() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
 
-    Ok(Command::Add(kind, text.to_string()))
-}
+        app.handle_key(key(KeyCode::Char(':')))?;
+        type_text(&mut app, "n exact note")?;
+        app.handle_key(key(KeyCode::Enter))?;
 
-fn is_text_input(modifiers: KeyModifiers) -> bool {
-    !modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
-}
+        assert_eq!(app.command_mode, CommandPaneMode::Normal);
+        assert_eq!(app.focus, Focus::Journal);
+        assert_eq!(app.journal.entries.len(), 1);
+        assert_eq!(app.journal.entries[0].kind, EntryKind::Note);
+        assert_eq!(app.journal.entries[0].text, "exact note");
 
-fn opens_journal_command_search(character: char, modifiers: KeyModifiers) -> bool {
-    matches!(character, ':' | ' ') && is_text_input(modifiers)
-}
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
 
-fn is_unmodified_key(modifiers: KeyModifiers) -> bool {
-    modifiers == KeyModifiers::NONE
-}
+    #[test]
+    fn identifies_exact_shortcut_input_during_search() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
 
-fn last_entry_index(journal: &Journal) -> Option<usize> {
-    journal.entries.len().checked_sub(1)
-}
+        app.handle_key(key(KeyCode::Char(':')))?;
+        type_text(&mut app, "n exact note")?;
 
-fn journal_root(journal: &Journal) -> PathBuf {
-    journal
-        .path()
-        .parent()
-        .unwrap_or_else(|| Path::new(""))
-        .to_path_buf()
-}
+        assert!(app.visible_command_search_results().is_empty());
+        assert!(app.command_search_input_is_exact_command());
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::{
-        env, fs, io,
-        path::PathBuf,
-        time::{SystemTime, UNIX_EPOCH},
-    };
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
 
-    use chrono::NaiveDate;
-    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    #[test]
+    fn clears_no_match_status_when_search_input_changes() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
 
-    fn date() -> NaiveDate {
-        NaiveDate::from_ymd_opt(2026, 5, 21).unwrap()
-    }
+        app.handle_key(key(KeyCode::Char(':')))?;
+        type_text(&mut app, "zzz")?;
+        app.handle_key(key(KeyCode::Enter))?;
+        assert_eq!(status(&app), "No matching commands.");
 
-    fn test_root() -> PathBuf {
-        let unique = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_nanos();
+        for _ in 0.."zzz".len() {
+            app.handle_key(key(KeyCode::Backspace))?;
+        }
+        type_text(&mut app, "n exact note")?;
 
-        env::temp_dir().join(format!("bullet-journal-tui-app-test-{unique}"))
-    }
+        assert_eq!(status(&app), "Search commands.");
+        assert!(app.command_search_input_is_exact_command());
 
-    fn test_app() -> io::Result<(App, PathBuf)> {
-        let root = test_root();
-        let journal = Journal::load_for_date(&root, date())?;
-        Ok((App::new(journal), root))
+        let _ = fs::remove_dir_all(root);
+        Ok(())
     }
 
-    fn key(code: KeyCode) -> KeyEvent {
-        KeyEvent::new(code, KeyModifiers::NONE)
-    }
+    #[test]
+    fn reports_empty_search_results_without_exiting_search() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
 
-    fn type_text(app: &mut App, text: &str) -> io::Result<()> {
-        for character in text.chars() {
-            app.handle_key(key(KeyCode::Char(character)))?;
-        }
+        app.handle_key(key(KeyCode::Char(':')))?;
+        type_text(&mut app, "zzz")?;
+        app.handle_key(key(KeyCode::Enter))?;
+
+        assert_eq!(app.command_mode, CommandPaneMode::Search);
+        assert_eq!(status(&app), "No matching commands.");
 
+        let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
-    fn search_result_names(app: &App) -> Vec<&'static str> {
-        app.visible_command_search_results()
-            .into_iter()
-            .map(|(_, result)| result.name)
-            .collect()
-    }
+    #[test]
+    fn command_text_inputs_keep_vim_keys_as_text() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
 
-    fn run_journal_search(app: &mut App, input: &str) -> io::Result<()> {
         app.handle_key(key(KeyCode::Char(':')))?;
-        type_text(app, input)?;
-        app.handle_key(key(KeyCode::Enter))
-    }
+        type_text(&mut app, "hjkl")?;
+        assert_eq!(app.focus, Focus::Command);
+        assert_eq!(app.command_mode, CommandPaneMode::Search);
+        assert_eq!(app.command_input, "hjkl");
 
-    fn toggle_split(app: &mut App) -> io::Result<()> {
-        run_journal_search(app, "split")
+        app.handle_key(key(KeyCode::Esc))?;
+        app.handle_key(key(KeyCode::Char(':')))?;
+        type_text(&mut app, "n")?;
+        app.handle_key(key(KeyCode::Enter))?;
+        type_text(&mut app, "hjkl")?;
+
+        assert_eq!(
+            app.command_mode,
+            CommandPaneMode::Entry(CommandAction::Add(EntryKind::Note))
+        );
+        assert_eq!(app.command_input, "hjkl");
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
     }
 
     #[test]
-    fn parses_entry_commands() {
+    fn exposes_and_executes_task_actions_for_highlighted_task() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
+        app.journal.add_entry(EntryKind::Note, "keep note");
+        app.journal.add_entry(EntryKind::Task, "ship feature");
+        app.selected = Some(1);
+
+        app.handle_key(key(KeyCode::Char(':')))?;
+
+        let names = search_result_names(&app);
+        assert_eq!(names[0], "complete");
+        assert_eq!(names[1], "cancel");
+        assert!(names.contains(&"important"));
+
+        type_text(&mut app, "complete")?;
+        app.handle_key(key(KeyCode::Enter))?;
+
+        assert_eq!(app.focus, Focus::Journal);
         assert_eq!(
-            parse_command(":n a note").unwrap(),
-            Command::Add(EntryKind::Note, String::from("a note"))
+            app.journal.entries[1].state,
+            crate::journal::EntryState::Completed
         );
         assert_eq!(
-            parse_command(":e Meeting at 10pm").unwrap(),
-            Command::Add(EntryKind::Event, String::from("Meeting at 10pm"))
+            app.journal.entries[0].state,
+            crate::journal::EntryState::Open
         );
         assert_eq!(
-            parse_command(":f accomplished").unwrap(),
-            Command::Add(EntryKind::Feeling, String::from("accomplished"))
+            fs::read_to_string(root.join("2026-05-21.md"))?,
+            "  - keep note\n  X ship feature <!-- completed 2026-05-21T09:30 -->\n"
         );
+
+        run_journal_search(&mut app, "c")?;
+
+        assert_eq!(status(&app), "Completed tasks cannot be cancelled.");
         assert_eq!(
-            parse_command(":t ship MVP").unwrap(),
-            Command::Add(EntryKind::Task, String::from("ship MVP"))
+            app.journal.entries[1].state,
+            crate::journal::EntryState::Completed
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("2026-05-21.md"))?,
+            "  - keep note\n  X ship feature <!-- completed 2026-05-21T09:30 -->\n"
         );
-    }
 
-    #[test]
-    fn parses_navigation_and_action_commands() {
-        assert_eq!(parse_command(":q").unwrap(), Command::Quit);
-        assert_eq!(parse_command(":split").unwrap(), Command::ToggleSplit);
-        assert_eq!(parse_command(":x").unwrap(), Command::Complete);
-        assert_eq!(parse_command(":c").unwrap(), Command::Cancel);
-        assert_eq!(parse_command(":i").unwrap(), Command::Important);
-        assert_eq!(parse_command(":important").unwrap(), Command::Important);
+        let _ = fs::remove_dir_all(root);
+        Ok(())
     }
 
     #[test]
-    fn rejects_empty_entries_and_unknown_commands() {
-        assert!(parse_command(":n").is_err());
-        assert!(parse_command(":cw").is_err());
-        assert!(parse_command(":wat").is_err());
-        assert!(parse_command("").is_err());
-    }
+    fn exposes_and_executes_event_actions_for_highlighted_event() -> io::Result<()> {
+        let (mut app, root) = test_app()?;
+        app.journal.add_entry(EntryKind::Note, "keep note");
+        app.journal.add_entry(EntryKind::Event, "planning");
+        app.journal.add_entry(EntryKind::Task, "ship feature");
+        app.selected = Some(1);
 
-    #[test]
-    fn starts_with_journal_focused() -> io::Result<()> {
-        let (app, root) = test_app()?;
+        app.handle_key(key(KeyCode::Char(':')))?;
+
+        let names = search_result_names(&app);
+        assert_eq!(names[0], "cancel");
+        assert!(names.contains(&"important"));
+        assert!(!names.contains(&"complete"));
+
+        type_text(&mut app, "cancel")?;
+        app.handle_key(key(KeyCode::Enter))?;
 
         assert_eq!(app.focus, Focus::Journal);
-        assert_eq!(app.command_mode, CommandPaneMode::Normal);
+        assert_eq!(
+            app.journal.entries[1].state,
+            crate::journal::EntryState::Cancelled
+        );
+        assert_eq!(
+            app.journal.entries[0].state,
+            crate::journal::EntryState::Open
+        );
+        assert_eq!(
+            app.journal.entries[2].state,
+            crate::journal::EntryState::Open
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("2026-05-21.md"))?,
+            "  - keep note\n  ◦ ~~planning~~ <!-- cancelled 2026-05-21T09:30 -->\n  · ship feature\n"
+        );
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn space_opens_command_search_from_focused_journal() -> io::Result<()> {
+    fn toggles_importance_for_highlighted_entry_and_alias() -> io::Result<()> {
         let (mut app, root) = test_app()?;
+        app.journal.add_entry(EntryKind::Note, "plain note");
+        app.selected = Some(0);
 
-        app.handle_key(key(KeyCode::Char(' ')))?;
+        run_journal_search(&mut app, "i")?;
 
-        assert_eq!(app.focus, Focus::Command);
-        assert_eq!(app.command_mode, CommandPaneMode::Search);
-        assert_eq!(app.command_context, CommandContext::JournalPane);
-        assert!(app.command_input.is_empty());
+        assert_eq!(status(&app), "Entry marked important.");
+        assert!(app.journal.entries[0].important);
+        assert_eq!(
+            fs::read_to_string(root.join("2026-05-21.md"))?,
+            "* - plain note\n"
+        );
+
+        run_journal_search(&mut app, "important")?;
+
+        assert_eq!(status(&app), "Entry unmarked important.");
+        assert!(!app.journal.entries[0].important);
+        assert_eq!(
+            fs::read_to_string(root.join("2026-05-21.md"))?,
+            "  - plain note\n"
+        );
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn space_opens_journal_command_search_with_highlighted_entry_actions() -> io::Result<()> {
+    fn exposes_only_importance_for_notes_and_feelings_and_keeps_invalid_actions_unchanged(
+    ) -> io::Result<()> {
         let (mut app, root) = test_app()?;
+        app.journal.add_entry(EntryKind::Note, "plain note");
+        app.journal.add_entry(EntryKind::Feeling, "focused");
         app.journal.add_entry(EntryKind::Task, "ship feature");
+        app.journal.save()?;
+        let before = fs::read_to_string(root.join("2026-05-21.md"))?;
+
         app.selected = Some(0);
+        app.handle_key(key(KeyCode::Char(':')))?;
+        let note_names = search_result_names(&app);
+        assert!(note_names.contains(&"important"));
+        assert!(!note_names.contains(&"complete"));
+        assert!(!note_names.contains(&"cancel"));
+        type_text(&mut app, "x")?;
+        app.handle_key(key(KeyCode::Enter))?;
 
-        app.handle_key(key(KeyCode::Char(' ')))?;
+        app.selected = Some(1);
+        app.handle_key(key(KeyCode::Char(':')))?;
+        let feeling_names = search_result_names(&app);
+        assert!(feeling_names.contains(&"important"));
+        assert!(!feeling_names.contains(&"complete"));
+        assert!(!feeling_names.contains(&"cancel"));
+        type_text(&mut app, "c")?;
+        app.handle_key(key(KeyCode::Enter))?;
 
-        let names = search_result_names(&app);
-        assert_eq!(names[0], "complete");
-        assert_eq!(names[1], "cancel");
-        assert!(names.contains(&"important"));
+        assert_eq!(
+            app.journal.entries[0].state,
+            crate::journal::EntryState::Open
+        );
+        assert_eq!(
+            app.journal.entries[1].state,
+            crate::journal::EntryState::Open
+        );
+        assert_eq!(
+            app.journal.entries[2].state,
+            crate::journal::EntryState::Open
+        );
+        assert_eq!(fs::read_to_string(root.join("2026-05-21.md"))?, before);
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn space_remains_literal_input_in_command_text_modes() -> io::Result<()> {
+    fn does_not_apply_actions_without_a_highlighted_entry() -> io::Result<()> {
         let (mut app, root) = test_app()?;
+        app.journal.add_entry(EntryKind::Task, "leave open");
+        app.journal.save()?;
+        app.selected = None;
 
-        app.focus = Focus::Command;
-        app.handle_key(key(KeyCode::Char(' ')))?;
-        assert_eq!(app.focus, Focus::Command);
-        assert_eq!(app.command_mode, CommandPaneMode::Normal);
-        assert_eq!(app.command_input, " ");
+        run_journal_search(&mut app, "x")?;
 
-        app.open_command_search(CommandContext::CommandPane);
-        app.handle_key(key(KeyCode::Char(' ')))?;
-        assert_eq!(app.command_mode, CommandPaneMode::Search);
-        assert_eq!(app.command_input, " ");
+        assert_eq!(status(&app), "No entry selected.");
+        assert_eq!(app.selected, None);
+        assert_eq!(
+            app.journal.entries[0].state,
+            crate::journal::EntryState::Open
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("2026-05-21.md"))?,
+            "  · leave open\n"
+        );
 
-        app.command_mode = CommandPaneMode::Entry(CommandAction::Add(EntryKind::Note));
-        app.command_input = String::from("draft");
-        app.handle_key(key(KeyCode::Char(' ')))?;
+        run_journal_search(&mut app, "i")?;
+
+        assert_eq!(status(&app), "No entry selected.");
+        assert_eq!(app.selected, None);
+        assert!(!app.journal.entries[0].important);
         assert_eq!(
-            app.command_mode,
-            CommandPaneMode::Entry(CommandAction::Add(EntryKind::Note))
+            fs::read_to_string(root.join("2026-05-21.md"))?,
+            "  · leave open\n"
         );
-        assert_eq!(app.command_input, "draft ");
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn switches_between_journal_days_with_left_and_right_arrows() -> io::Result<()> {
+    fn records_creation_and_state_change_times() -> io::Result<()> {
         let (mut app, root) = test_app()?;
-        fs::create_dir_all(&root)?;
-        fs::write(
-            root.join("2026-05-20.md"),
-            "- yesterday note\n· yesterday task\n",
-        )?;
 
-        app.handle_key(key(KeyCode::Left))?;
+        run_journal_search(&mut app, "t ship feature")?;
+        app.handle_key(key(KeyCode::Esc))?;
+        assert_eq!(app.journal.entries[0].created_at, Some(test_now()));
+
+        run_journal_search(&mut app, "x")?;
+        run_journal_search(&mut app, "x")?;
 
+        let changes = &app.journal.entries[0].state_changes;
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].state, crate::journal::EntryState::Completed);
+        assert_eq!(changes[1].state, crate::journal::EntryState::Open);
         assert_eq!(
-            app.journal.date,
-            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
+            fs::read_to_string(root.join("2026-05-21.md"))?,
+            "  · ship feature <!-- created 2026-05-21T09:30 completed 2026-05-21T09:30 reopened 2026-05-21T09:30 -->\n"
         );
-        assert_eq!(app.journal.entries.len(), 2);
-        assert_eq!(app.journal.entries[0].text, "yesterday note");
-        assert_eq!(app.journal.entries[1].text, "yesterday task");
-        assert_eq!(app.selected, Some(1));
-        assert_eq!(app.focus, Focus::Journal);
-        assert_eq!(app.status, "Loaded 2026-05-20.");
-
-        app.handle_key(key(KeyCode::Right))?;
-
-        assert_eq!(app.journal.date, date());
-        assert!(app.journal.entries.is_empty());
-        assert_eq!(app.selected, None);
-        assert!(!root.join("2026-05-21.md").exists());
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn navigates_single_journal_with_vim_keys() -> io::Result<()> {
+    fn toggles_timestamp_display() -> io::Result<()> {
         let (mut app, root) = test_app()?;
-        fs::create_dir_all(&root)?;
-        fs::write(root.join("2026-05-20.md"), "- yesterday note\n")?;
-        app.journal.add_entry(EntryKind::Note, "today one");
-        app.journal.add_entry(EntryKind::Note, "today two");
-        app.journal.add_entry(EntryKind::Note, "today three");
-        app.selected = Some(1);
-
-        app.handle_key(key(KeyCode::Char('k')))?;
-        assert_eq!(app.selected, Some(0));
-
-        app.handle_key(key(KeyCode::Char('j')))?;
-        assert_eq!(app.selected, Some(1));
-
-        app.handle_key(key(KeyCode::Char('h')))?;
-
-        assert_eq!(
-            app.journal.date,
-            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
-        );
-        assert_eq!(app.journal.entries[0].text, "yesterday note");
-        assert_eq!(app.selected, Some(0));
 
-        app.handle_key(key(KeyCode::Char('l')))?;
+        run_journal_search(&mut app, "times")?;
+        assert!(app.show_timestamps);
+        assert_eq!(status(&app), "Showing timestamps.");
 
-        assert_eq!(app.journal.date, date());
-        assert!(app.journal.entries.is_empty());
-        assert_eq!(app.selected, None);
+        run_journal_search(&mut app, "timestamps")?;
+        assert!(!app.show_timestamps);
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn switching_to_empty_day_does_not_create_file() -> io::Result<()> {
+    fn saves_timed_events_and_toggles_timeline() -> io::Result<()> {
         let (mut app, root) = test_app()?;
 
-        app.handle_key(key(KeyCode::Right))?;
-
+        app.execute_command(":e 9:30-10am standup", CommandContext::CommandPane)?;
         assert_eq!(
-            app.journal.date,
-            NaiveDate::from_ymd_opt(2026, 5, 22).unwrap()
+            fs::read_to_string(app.journal.path())?,
+            "  ◦ 09:30-10:00 standup <!-- created 2026-05-21T09:30 -->\n"
         );
-        assert!(app.journal.entries.is_empty());
-        assert_eq!(app.selected, None);
-        assert!(!root.join("2026-05-22.md").exists());
+
+        app.handle_key(key(KeyCode::Esc))?;
+        run_journal_search(&mut app, "timeline")?;
+        assert!(app.show_timeline);
+        assert_eq!(status(&app), "Showing timeline.");
+
+        run_journal_search(&mut app, "agenda")?;
+        assert!(!app.show_timeline);
+        assert_eq!(status(&app), "Showing entries.");
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn writes_new_entries_to_displayed_day() -> io::Result<()> {
+    fn writes_dated_entries_to_the_resolved_day() -> io::Result<()> {
         let (mut app, root) = test_app()?;
 
-        app.handle_key(key(KeyCode::Right))?;
-        app.handle_key(key(KeyCode::Char(':')))?;
-        type_text(&mut app, "n future note")?;
-        app.handle_key(key(KeyCode::Enter))?;
-
-        assert_eq!(
-            app.journal.date,
-            NaiveDate::from_ymd_opt(2026, 5, 22).unwrap()
-        );
-        assert_eq!(app.journal.entries.len(), 1);
-        assert_eq!(
-            app.journal.entries[0].created_on,
-            NaiveDate::from_ymd_opt(2026, 5, 22).unwrap()
-        );
+        app.execute_command(":t tomorrow call dentist", CommandContext::CommandPane)?;
+        assert!(app.journal.entries.is_empty());
         assert_eq!(
             fs::read_to_string(root.join("2026-05-22.md"))?,
-            "  - future note\n"
+            "  · call dentist <!-- created 2026-05-21T09:30 -->\n"
         );
         assert!(!root.join("2026-05-21.md").exists());
 
+        app.execute_command(":t today water plants", CommandContext::CommandPane)?;
+        assert_eq!(app.journal.entries[0].text, "water plants");
+
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn toggles_split_view_on_for_yesterday_and_today() -> io::Result<()> {
+    fn writes_dated_entries_to_a_visible_split_pane() -> io::Result<()> {
         let (mut app, root) = test_app()?;
-        fs::create_dir_all(&root)?;
-        fs::write(root.join("2026-05-20.md"), "- yesterday note\n")?;
-        fs::write(root.join("2026-05-21.md"), "- today note\n")?;
-
         toggle_split(&mut app)?;
 
+        app.execute_command(":t yesterday late thought", CommandContext::CommandPane)?;
         let split = app.split.as_ref().expect("split view should be active");
-        assert_eq!(
-            split.older.journal.date,
-            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
-        );
-        assert_eq!(split.newer.journal.date, date());
-        assert_eq!(split.active, SplitPane::Newer);
-        assert_eq!(app.journal.date, date());
-        assert_eq!(app.selected, Some(0));
-        assert_eq!(split.older.journal.entries[0].text, "yesterday note");
-        assert_eq!(split.newer.journal.entries[0].text, "today note");
+        assert_eq!(split.active, 1);
+        assert_eq!(split.panes[0].journal.entries[0].text, "late thought");
+        assert_eq!(split.panes[0].selected, Some(0));
+        assert!(split.panes[1].journal.entries.is_empty());
+        assert!(fs::read_to_string(root.join("2026-05-20.md"))?.contains("late thought"));
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn split_view_arrows_focus_and_shift_the_two_day_window() -> io::Result<()> {
+    fn manages_recurring_rules() -> io::Result<()> {
         let (mut app, root) = test_app()?;
 
-        toggle_split(&mut app)?;
-        app.handle_key(key(KeyCode::Left))?;
-
-        let split = app.split.as_ref().expect("split view should be active");
-        assert_eq!(split.active, SplitPane::Older);
+        app.execute_command(":recur daily :t standup", CommandContext::CommandPane)?;
+        assert_eq!(status(&app), "Added recurring rule r1.");
         assert_eq!(
-            app.journal.date,
-            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
+            fs::read_to_string(root.join("recurring.md"))?,
+            "- r1 daily from 2026-05-21: · standup\n<!-- last id r1 -->\n"
         );
+        assert_eq!(app.journal.entries[0].text, "standup");
+        assert_eq!(app.journal.entries[0].recurrence.as_deref(), Some("r1"));
+        assert_eq!(app.selected, Some(0));
+        assert!(!app.journal.path().exists());
 
-        app.handle_key(key(KeyCode::Left))?;
-
-        let split = app.split.as_ref().expect("split view should be active");
-        assert_eq!(
-            split.older.journal.date,
-            NaiveDate::from_ymd_opt(2026, 5, 19).unwrap()
-        );
-        assert_eq!(
-            split.newer.journal.date,
-            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
-        );
-        assert_eq!(split.active, SplitPane::Older);
-        assert_eq!(
-            app.journal.date,
-            NaiveDate::from_ymd_opt(2026, 5, 19).unwrap()
-        );
+        run_journal_search(&mut app, "recurring")?;
+        let Some(Overlay::Recurring(rules)) = &app.overlay else {
+            panic!("expected the recurring overlay");
+        };
+        assert_eq!(rules.len(), 1);
 
-        app.handle_key(key(KeyCode::Right))?;
+        app.handle_key(key(KeyCode::Char('j')))?;
+        assert!(app.overlay.is_some());
+        app.handle_key(key(KeyCode::Esc))?;
+        assert!(app.overlay.is_none());
+        assert_eq!(app.focus, Focus::Journal);
 
-        let split = app.split.as_ref().expect("split view should be active");
-        assert_eq!(split.active, SplitPane::Newer);
+        app.execute_command(":recur rm r1", CommandContext::CommandPane)?;
+        assert_eq!(status(&app), "Removed recurring rule r1.");
         assert_eq!(
-            app.journal.date,
-            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
+            fs::read_to_string(root.join("recurring.md"))?,
+            "<!-- last id r1 -->\n"
         );
+        assert!(app.journal.entries.is_empty());
+        assert_eq!(app.selected, None);
 
         app.handle_key(key(KeyCode::Right))?;
+        assert!(app.journal.entries.is_empty());
 
-        let split = app.split.as_ref().expect("split view should be active");
-        assert_eq!(
-            split.older.journal.date,
-            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
-        );
-        assert_eq!(split.newer.journal.date, date());
-        assert_eq!(split.active, SplitPane::Newer);
-        assert_eq!(app.journal.date, date());
+        app.execute_command(":recur daily :t review", CommandContext::CommandPane)?;
+        assert_eq!(status(&app), "Added recurring rule r2.");
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn split_view_navigates_with_vim_keys() -> io::Result<()> {
-        let (mut app, root) = test_app()?;
+    fn deleted_and_moved_occurrences_stay_off_their_day() -> io::Result<()> {
+        let root = test_root();
         fs::create_dir_all(&root)?;
         fs::write(
-            root.join("2026-05-20.md"),
-            "- yesterday one\n- yesterday two\n",
-        )?;
-        fs::write(
-            root.join("2026-05-21.md"),
-            "- today one\n- today two\n- today three\n",
+            root.join("recurring.md"),
+            "- r1 daily from 2026-05-01: · standup\n- r2 daily from 2026-05-01: - water plants\n",
         )?;
+        let rules = RecurringRules::load(&root)?;
+        let mut app = App::new(Journal::load_for_date_with_rules(&root, date(), &rules)?);
+        app.clock = test_now;
+        assert_eq!(app.journal.entries.len(), 2);
 
-        toggle_split(&mut app)?;
-        app.handle_key(key(KeyCode::Char('k')))?;
-        assert_eq!(app.selected, Some(1));
-        assert_eq!(
-            app.split.as_ref().expect("split view").newer.selected,
-            Some(1)
-        );
-
-        app.handle_key(key(KeyCode::Char('h')))?;
-
-        let split = app.split.as_ref().expect("split view should be active");
-        assert_eq!(split.active, SplitPane::Older);
+        type_text(&mut app, "gg")?;
+        type_text(&mut app, "dd")?;
         assert_eq!(
-            app.journal.date,
-            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
+            fs::read_to_string(root.join("recurring.md"))?,
+            "- r1 daily from 2026-05-01 except 2026-05-21: · standup\n- r2 daily from 2026-05-01: - water plants\n"
         );
-        assert_eq!(app.selected, Some(1));
-
-        app.handle_key(key(KeyCode::Char('k')))?;
-        assert_eq!(app.selected, Some(0));
-
-        app.handle_key(key(KeyCode::Char('j')))?;
-        assert_eq!(app.selected, Some(1));
-
-        app.handle_key(key(KeyCode::Char('h')))?;
 
-        let split = app.split.as_ref().expect("split view should be active");
-        assert_eq!(
-            split.older.journal.date,
-            NaiveDate::from_ymd_opt(2026, 5, 19).unwrap()
-        );
+        run_journal_search(&mut app, "move tomorrow")?;
+        assert_eq!(status(&app), "Moved to 2026-05-22.");
         assert_eq!(
-            split.newer.journal.date,
-            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
+            fs::read_to_string(root.join("recurring.md"))?,
+            "- r1 daily from 2026-05-01 except 2026-05-21: · standup\n- r2 daily from 2026-05-01 except 2026-05-21: - water plants\n"
         );
-        assert_eq!(split.active, SplitPane::Older);
-
-        app.handle_key(key(KeyCode::Char('l')))?;
 
-        let split = app.split.as_ref().expect("split view should be active");
-        assert_eq!(split.active, SplitPane::Newer);
-        assert_eq!(
-            app.journal.date,
-            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
-        );
+        app.handle_key(key(KeyCode::Right))?;
+        app.handle_key(key(KeyCode::Left))?;
+        assert!(app.journal.entries.is_empty());
 
-        app.handle_key(key(KeyCode::Char('l')))?;
+        app.handle_key(key(KeyCode::Right))?;
+        let texts = app
+            .journal
+            .entries
+            .iter()
+            .map(|entry| entry.text.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(texts, ["standup", "water plants", "water plants"]);
 
-        let split = app.split.as_ref().expect("split view should be active");
-        assert_eq!(
-            split.older.journal.date,
-            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
-        );
-        assert_eq!(split.newer.journal.date, date());
-        assert_eq!(split.active, SplitPane::Newer);
+        run_journal_search(&mut app, "editor")?;
+        let path = root.join("2026-05-22.md");
+        let saved = fs::read_to_string(&path)?;
+        assert!(saved.contains("standup <!-- recur r1 -->"));
+        fs::write(
+            &path,
+            saved
+                .lines()
+                .filter(|line| !line.contains("standup"))
+                .map(|line| format!("{line}\n"))
+                .collect::<String>(),
+        )?;
+        app.reload_day(day(22))?;
+        assert_eq!(app.journal.entries.len(), 2);
+        assert!(fs::read_to_string(root.join("recurring.md"))?
+            .contains("r1 daily from 2026-05-01 except 2026-05-21 2026-05-22:"));
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn split_view_keeps_independent_selection_per_pane() -> io::Result<()> {
+    fn week_view_moves_between_days_and_weeks() -> io::Result<()> {
         let (mut app, root) = test_app()?;
         fs::create_dir_all(&root)?;
-        fs::write(
-            root.join("2026-05-20.md"),
-            "- yesterday one\n- yesterday two\n",
-        )?;
-        fs::write(
-            root.join("2026-05-21.md"),
-            "- today one\n- today two\n- today three\n",
-        )?;
+        fs::write(root.join("2026-05-19.md"), "· tuesday task\n")?;
 
-        toggle_split(&mut app)?;
-        assert_eq!(app.selected, Some(2));
+        run_journal_search(&mut app, "week")?;
+        let split = app.split.as_ref().expect("week view should be active");
+        assert_eq!(split.layout, SplitLayout::Week);
+        assert_eq!(split.panes.len(), 7);
+        assert_eq!(split.panes[0].journal.date, day(18));
+        assert_eq!(split.active, 3);
+        assert_eq!(status(&app), "Week view on: 2026-05-18 to 2026-05-24.");
+
+        app.handle_key(key(KeyCode::Char('h')))?;
+        app.handle_key(key(KeyCode::Char('h')))?;
+        assert_eq!(app.journal.date, day(19));
+        run_journal_search(&mut app, "x")?;
+        assert_eq!(
+            fs::read_to_string(root.join("2026-05-19.md"))?,
+            "  X tuesday task <!-- completed 2026-05-21T09:30 -->\n"
+        );
+
+        for _ in 0..6 {
+            app.handle_key(key(KeyCode::Right))?;
+        }
+        let split = app.split.as_ref().expect("week view should be active");
+        assert_eq!(split.panes[0].journal.date, day(25));
+        assert_eq!(split.active, 0);
+        assert_eq!(app.journal.date, day(25));
 
-        app.handle_key(key(KeyCode::Up))?;
-        assert_eq!(app.selected, Some(1));
+        app.handle_key(key(KeyCode::Left))?;
+        let split = app.split.as_ref().expect("week view should be active");
+        assert_eq!(split.panes[0].journal.date, day(18));
+        assert_eq!(split.active, 6);
         assert_eq!(
-            app.split.as_ref().expect("split view").newer.selected,
-            Some(1)
+            split.panes[1].journal.entries[0].state,
+            crate::journal::EntryState::Completed
         );
 
-        app.handle_key(key(KeyCode::Left))?;
-        assert_eq!(app.selected, Some(1));
-        app.handle_key(key(KeyCode::Up))?;
-        assert_eq!(app.selected, Some(0));
+        type_text(&mut app, "9l")?;
+        let split = app.split.as_ref().expect("week view should be active");
         assert_eq!(
-            app.split.as_ref().expect("split view").older.selected,
-            Some(0)
+            split.panes[0].journal.date,
+            NaiveDate::from_ymd_opt(2026, 6, 1).unwrap()
         );
+        assert_eq!(split.active, 1);
+        type_text(&mut app, "9h")?;
+        let split = app.split.as_ref().expect("week view should be active");
+        assert_eq!(split.panes[0].journal.date, day(18));
+        assert_eq!(split.active, 6);
 
-        app.handle_key(key(KeyCode::Right))?;
+        let files = fs::read_dir(&root)?.count();
+        assert_eq!(files, 1);
 
-        let split = app.split.as_ref().expect("split view should be active");
-        assert_eq!(split.active, SplitPane::Newer);
-        assert_eq!(app.selected, Some(1));
-        assert_eq!(split.older.selected, Some(0));
-        assert_eq!(split.newer.selected, Some(1));
+        run_journal_search(&mut app, "week")?;
+        assert!(app.split.is_none());
+        assert_eq!(app.journal.date, day(24));
+        assert_eq!(status(&app), "Week view off. Loaded 2026-05-24.");
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn split_view_writes_new_entries_to_focused_pane() -> io::Result<()> {
+    fn split_command_replaces_week_view() -> io::Result<()> {
         let (mut app, root) = test_app()?;
 
+        run_journal_search(&mut app, "week")?;
         toggle_split(&mut app)?;
-        app.handle_key(key(KeyCode::Left))?;
-        run_journal_search(&mut app, "n yesterday add")?;
-
         let split = app.split.as_ref().expect("split view should be active");
-        assert_eq!(split.active, SplitPane::Older);
-        assert_eq!(
-            split.older.journal.date,
-            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
-        );
-        assert_eq!(split.older.journal.entries[0].text, "yesterday add");
-        assert!(split.newer.journal.entries.is_empty());
-        assert_eq!(app.journal.date, split.older.journal.date);
-        assert_eq!(app.selected, Some(0));
-        assert_eq!(
-            fs::read_to_string(root.join("2026-05-20.md"))?,
-            "  - yesterday add\n"
-        );
-        assert!(!root.join("2026-05-21.md").exists());
+        assert_eq!(split.layout, SplitLayout::Days);
+        assert_eq!(split.panes.len(), 2);
+        assert_eq!(split.panes[1].journal.date, day(21));
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
+    fn day(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 5, day).unwrap()
+    }
+
     #[test]
-    fn split_view_applies_entry_actions_to_focused_pane() -> io::Result<()> {
+    fn adds_changes_and_closes_panes_for_any_date() -> io::Result<()> {
         let (mut app, root) = test_app()?;
-        fs::create_dir_all(&root)?;
-        fs::write(root.join("2026-05-20.md"), "· old task\n")?;
-        fs::write(root.join("2026-05-21.md"), "· today task\n")?;
+        let dates = |app: &App| {
+            app.split
+                .as_ref()
+                .map(|split| {
+                    split
+                        .panes
+                        .iter()
+                        .map(|pane| pane.journal.date.day())
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        };
+
+        app.execute_command(":pane -7d", CommandContext::CommandPane)?;
+        assert_eq!(dates(&app), [21, 14]);
+        assert_eq!(app.journal.date, day(14));
+        assert_eq!(app.split.as_ref().unwrap().layout, SplitLayout::Custom);
+
+        app.execute_command(":pane set 2026-05-07", CommandContext::CommandPane)?;
+        assert_eq!(dates(&app), [21, 7]);
+
+        app.execute_command(":pane tomorrow", CommandContext::CommandPane)?;
+        assert_eq!(dates(&app), [21, 7, 22]);
+        assert_eq!(app.split.as_ref().unwrap().active, 2);
+
+        app.execute_command(":pane today", CommandContext::CommandPane)?;
+        assert_eq!(dates(&app), [21, 7, 22]);
+        assert_eq!(app.split.as_ref().unwrap().active, 0);
+        assert_eq!(status(&app), "2026-05-21 is already shown.");
 
-        toggle_split(&mut app)?;
         app.handle_key(key(KeyCode::Left))?;
-        run_journal_search(&mut app, "x")?;
+        assert_eq!(dates(&app), [20, 6, 21]);
+        assert_eq!(app.split.as_ref().unwrap().active, 0);
 
-        let split = app.split.as_ref().expect("split view should be active");
-        assert_eq!(split.active, SplitPane::Older);
+        app.execute_command(":pane close", CommandContext::CommandPane)?;
+        assert_eq!(dates(&app), [6, 21]);
+        assert_eq!(app.journal.date, day(6));
+
+        app.execute_command(":pane close", CommandContext::CommandPane)?;
+        assert!(app.split.is_none());
+        assert_eq!(app.journal.date, day(21));
+        assert_eq!(status(&app), "Closed 2026-05-06. Loaded 2026-05-21.");
+        assert!(fs::read_dir(&root).is_err());
+
+        toggle_split(&mut app)?;
+        assert_eq!(dates(&app), [20, 21]);
+        assert_eq!(app.split.as_ref().unwrap().layout, SplitLayout::Days);
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn moves_and_copies_entries_between_days() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        fs::write(root.join("2026-05-20.md"), "- yesterday note\n")?;
+        fs::write(root.join("2026-05-21.md"), "· today task\n- today note\n")?;
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+        app.clock = test_now;
+        toggle_split(&mut app)?;
+        app.handle_key(key(KeyCode::Up))?;
+
+        run_journal_search(&mut app, "move")?;
+        assert_eq!(status(&app), "Migrated to 2026-05-20.");
         assert_eq!(
-            split.older.journal.entries[0].state,
-            crate::journal::EntryState::Completed
+            fs::read_to_string(root.join("2026-05-21.md"))?,
+            "  → today task <!-- migrated 2026-05-21T09:30 -->\n- today note\n"
         );
         assert_eq!(
-            split.newer.journal.entries[0].state,
-            crate::journal::EntryState::Open
+            fs::read_to_string(root.join("2026-05-20.md"))?,
+            "- yesterday note\n  · today task <!-- created 2026-05-21T09:30 -->\n"
         );
+        let split = app.split.as_ref().expect("split view should be active");
+        assert_eq!(split.active, 0);
+        assert_eq!(app.selected, Some(1));
+        assert_eq!(app.journal.entries[1].text, "today task");
+
+        app.handle_key(key(KeyCode::Up))?;
+        run_journal_search(&mut app, "move")?;
+        assert_eq!(status(&app), "Moved to 2026-05-21.");
         assert_eq!(
             fs::read_to_string(root.join("2026-05-20.md"))?,
-            "  X old task\n"
+            "  · today task <!-- created 2026-05-21T09:30 -->\n"
         );
+        assert_eq!(app.split.as_ref().unwrap().active, 1);
+        assert_eq!(app.selected, Some(2));
+
+        app.handle_key(key(KeyCode::Up))?;
+        run_journal_search(&mut app, "copy tomorrow")?;
+        assert_eq!(status(&app), "Copied to 2026-05-22.");
         assert_eq!(
-            fs::read_to_string(root.join("2026-05-21.md"))?,
-            "· today task\n"
+            fs::read_to_string(root.join("2026-05-22.md"))?,
+            "  - today note <!-- created 2026-05-21T09:30 -->\n"
         );
+        assert_eq!(app.journal.entries[1].text, "today note");
+        assert_eq!(app.split.as_ref().unwrap().active, 1);
+
+        app.handle_key(key(KeyCode::Up))?;
+        run_journal_search(&mut app, "move")?;
+        assert_eq!(status(&app), "Task was already migrated.");
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn split_view_toggles_importance_on_focused_pane() -> io::Result<()> {
-        let (mut app, root) = test_app()?;
-        fs::create_dir_all(&root)?;
-        fs::write(root.join("2026-05-20.md"), "- old note\n")?;
-        fs::write(root.join("2026-05-21.md"), "- today note\n")?;
-
-        toggle_split(&mut app)?;
-        app.handle_key(key(KeyCode::Left))?;
-        run_journal_search(&mut app, "i")?;
-
-        let split = app.split.as_ref().expect("split view should be active");
-        assert_eq!(split.active, SplitPane::Older);
-        assert!(split.older.journal.entries[0].important);
-        assert!(!split.newer.journal.entries[0].important);
+    fn parses_goto_commands() {
         assert_eq!(
-            fs::read_to_string(root.join("2026-05-20.md"))?,
-            "* - old note\n"
+            parse_command(":goto 2026-04-01").unwrap(),
+            Command::Goto(DateExpr::Date(NaiveDate::from_ymd_opt(2026, 4, 1).unwrap()))
         );
         assert_eq!(
-            fs::read_to_string(root.join("2026-05-21.md"))?,
-            "- today note\n"
+            parse_command(":goto -1w").unwrap(),
+            Command::Goto(DateExpr::Offset(-7))
+        );
+        assert_eq!(parse_command(":cal").unwrap(), Command::OpenCalendar);
+        assert_eq!(parse_command(":goto"), Err(String::from("Enter a date.")));
+        assert_eq!(
+            parse_command(":goto someday"),
+            Err(String::from("Unknown date: someday"))
         );
-
-        let _ = fs::remove_dir_all(root);
-        Ok(())
     }
 
     #[test]
-    fn toggling_split_view_off_keeps_the_active_day() -> io::Result<()> {
+    fn jumps_by_weeks_and_months_and_back_to_today() -> io::Result<()> {
         let (mut app, root) = test_app()?;
-
-        toggle_split(&mut app)?;
-        app.handle_key(key(KeyCode::Left))?;
-        toggle_split(&mut app)?;
-
-        assert!(app.split.is_none());
+        let shifted = |character| KeyEvent::new(KeyCode::Char(character), KeyModifiers::SHIFT);
+        let june = |day| NaiveDate::from_ymd_opt(2026, 6, day).unwrap();
+
+        app.handle_key(shifted('L'))?;
+        assert_eq!(app.journal.date, day(28));
+        app.handle_key(key(KeyCode::Char('>')))?;
+        assert_eq!(app.journal.date, june(28));
+        app.handle_key(shifted('H'))?;
+        assert_eq!(app.journal.date, june(21));
+        app.handle_key(key(KeyCode::Char('t')))?;
+        assert_eq!(app.journal.date, day(21));
+
+        run_journal_search(&mut app, "goto 2026-03-31")?;
         assert_eq!(
             app.journal.date,
-            NaiveDate::from_ymd_opt(2026, 5, 20).unwrap()
+            NaiveDate::from_ymd_opt(2026, 3, 31).unwrap()
         );
-
-        app.handle_key(key(KeyCode::Left))?;
-
+        app.handle_key(key(KeyCode::Char('>')))?;
         assert_eq!(
             app.journal.date,
-            NaiveDate::from_ymd_opt(2026, 5, 19).unwrap()
+            NaiveDate::from_ymd_opt(2026, 4, 30).unwrap()
         );
+        assert_eq!(status(&app), "Loaded 2026-04-30.");
+
+        run_journal_search(&mut app, "goto fri")?;
+        assert_eq!(app.journal.date, day(22));
+        assert_eq!(app.focus, Focus::Journal);
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn split_view_does_not_create_files_for_empty_displayed_days() -> io::Result<()> {
+    fn goto_keeps_split_spacing_and_week_alignment() -> io::Result<()> {
         let (mut app, root) = test_app()?;
+        let june = |day| NaiveDate::from_ymd_opt(2026, 6, day).unwrap();
+        let pane_dates = |app: &App| {
+            let split = app.split.as_ref().expect("split view should be active");
+            let dates = split
+                .panes
+                .iter()
+                .map(|pane| pane.journal.date)
+                .collect::<Vec<_>>();
+            (dates, split.active)
+        };
 
         toggle_split(&mut app)?;
+        run_journal_search(&mut app, "goto 2026-06-10")?;
+        assert_eq!(pane_dates(&app), (vec![june(9), june(10)], 1));
+        assert_eq!(app.journal.date, june(10));
 
-        assert!(app.split.is_some());
-        assert!(!root.join("2026-05-20.md").exists());
-        assert!(!root.join("2026-05-21.md").exists());
+        run_journal_search(&mut app, "week")?;
+        assert_eq!(pane_dates(&app).0[0], june(8));
+        app.handle_key(KeyEvent::new(KeyCode::Char('L'), KeyModifiers::SHIFT))?;
+        let (dates, active) = pane_dates(&app);
+        assert_eq!(dates[0], june(15));
+        assert_eq!(active, 2);
+        assert_eq!(app.journal.date, june(17));
+
+        app.handle_key(key(KeyCode::Char('t')))?;
+        assert_eq!(pane_dates(&app), ((18..=24).map(day).collect(), 3));
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn matches_fuzzy_subsequences_case_insensitively() {
-        assert!(fuzzy_subsequence_match("nt", "note"));
-        assert!(fuzzy_subsequence_match("TD", "todo"));
-        assert!(!fuzzy_subsequence_match("task", "note"));
-    }
-
-    #[test]
-    fn searches_only_command_pane_commands() {
-        let task_results = command_search_results("t");
-        assert_eq!(task_results[0].name, "task");
-
-        assert!(command_search_results("cw").is_empty());
-
-        let all_names = command_search_results("")
-            .into_iter()
-            .map(|result| result.name)
-            .collect::<Vec<_>>();
-        assert!(all_names.contains(&"split"));
-        assert!(!all_names.contains(&"switch to journal"));
-        assert!(!all_names.contains(&"yesterday"));
-        assert!(!all_names.contains(&"tomorrow"));
-        assert!(!all_names.contains(&"complete"));
-        assert!(!all_names.contains(&"cancel"));
-        assert!(!all_names.contains(&"important"));
-    }
-
-    #[test]
-    fn selects_entry_command_from_fuzzy_search_and_submits_text() -> io::Result<()> {
+    fn calendar_picks_a_day_to_show() -> io::Result<()> {
         let (mut app, root) = test_app()?;
+        fs::create_dir_all(&root)?;
+        fs::write(root.join("2026-05-05.md"), "  · open task\n")?;
 
-        app.handle_key(key(KeyCode::Char(':')))?;
-        assert_eq!(app.command_mode, CommandPaneMode::Search);
-        assert_eq!(app.focus, Focus::Command);
-
-        type_text(&mut app, "nt")?;
-        app.handle_key(key(KeyCode::Enter))?;
-
+        app.handle_key(KeyEvent::new(KeyCode::Char('C'), KeyModifiers::SHIFT))?;
+        let Some(Overlay::Calendar(picker)) = &app.overlay else {
+            panic!("expected the calendar");
+        };
+        assert_eq!(picker.selected, day(21));
         assert_eq!(
-            app.command_mode,
-            CommandPaneMode::Entry(CommandAction::Add(EntryKind::Note))
+            picker.mark(day(5)),
+            Some(crate::calendar::DayMark::OpenTasks)
         );
-        assert_eq!(app.command_title(), "Enter a note");
 
-        type_text(&mut app, "a fuzzy note")?;
+        app.handle_key(key(KeyCode::Up))?;
+        app.handle_key(key(KeyCode::Up))?;
+        app.handle_key(key(KeyCode::Char('h')))?;
+        app.handle_key(key(KeyCode::Char('h')))?;
         app.handle_key(key(KeyCode::Enter))?;
+        assert!(app.overlay.is_none());
+        assert_eq!(app.journal.date, day(5));
+        assert_eq!(app.journal.entries[0].text, "open task");
+        assert_eq!(status(&app), "Loaded 2026-05-05.");
 
-        assert_eq!(app.command_mode, CommandPaneMode::Normal);
-        assert_eq!(app.focus, Focus::Journal);
-        assert_eq!(app.journal.entries.len(), 1);
-        assert_eq!(app.journal.entries[0].kind, EntryKind::Note);
-        assert_eq!(app.journal.entries[0].text, "a fuzzy note");
+        run_journal_search(&mut app, "cal")?;
+        app.handle_key(key(KeyCode::PageDown))?;
+        app.handle_key(key(KeyCode::Esc))?;
+        assert!(app.overlay.is_none());
+        assert_eq!(app.journal.date, day(5));
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn navigates_search_results_with_arrow_keys() -> io::Result<()> {
+    fn brackets_skip_to_days_with_files() -> io::Result<()> {
         let (mut app, root) = test_app()?;
-
-        app.handle_key(key(KeyCode::Char(':')))?;
-        app.handle_key(key(KeyCode::Down))?;
-        app.handle_key(key(KeyCode::Enter))?;
-
-        assert_eq!(
-            app.command_mode,
-            CommandPaneMode::Entry(CommandAction::Add(EntryKind::Event))
-        );
-        assert_eq!(app.command_title(), "Enter an event");
+        fs::create_dir_all(&root)?;
+        fs::write(root.join("2026-05-02.md"), "  - early\n")?;
+        fs::write(root.join("2026-05-10.md"), "  - middle\n")?;
+
+        app.handle_key(key(KeyCode::Char('[')))?;
+        assert_eq!(app.journal.date, day(10));
+        assert_eq!(app.journal.entries[0].text, "middle");
+        app.handle_key(key(KeyCode::Char('[')))?;
+        assert_eq!(app.journal.date, day(2));
+        app.handle_key(key(KeyCode::Char('[')))?;
+        assert_eq!(app.journal.date, day(2));
+        assert_eq!(status(&app), "No earlier day files.");
+
+        app.handle_key(key(KeyCode::Char(']')))?;
+        assert_eq!(app.journal.date, day(10));
+        app.handle_key(key(KeyCode::Char(']')))?;
+        assert_eq!(status(&app), "No later day files.");
+
+        run_journal_search(&mut app, "goto 2026-05-21")?;
+        run_journal_search(&mut app, "n written today")?;
+        app.handle_key(key(KeyCode::Char('h')))?;
+        app.handle_key(key(KeyCode::Char(']')))?;
+        assert_eq!(app.journal.date, day(21));
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
+    fn after_midnight() -> NaiveDateTime {
+        day(22).and_hms_opt(0, 0, 5).unwrap()
+    }
+
     #[test]
-    fn escape_hides_command_search_and_focuses_journal() -> io::Result<()> {
+    fn tick_moves_today_and_the_view_past_midnight() -> io::Result<()> {
         let (mut app, root) = test_app()?;
 
+        app.tick();
+        assert_eq!(app.today, day(21));
+
         app.handle_key(key(KeyCode::Char(':')))?;
-        type_text(&mut app, "nt")?;
-        app.handle_key(key(KeyCode::Esc))?;
+        type_text(&mut app, "t after midnight")?;
+        app.clock = after_midnight;
+        app.tick();
+        assert_eq!(app.today, day(22));
+        assert_eq!(app.journal.date, day(22));
+        assert_eq!(status(&app), "It is now 2026-05-22. Moved to the new day.");
+        assert_eq!(app.focus, Focus::Command);
+        assert_eq!(app.command_input, "t after midnight");
 
-        assert_eq!(app.focus, Focus::Journal);
-        assert_eq!(app.command_mode, CommandPaneMode::Normal);
-        assert!(app.command_input.is_empty());
+        app.handle_key(key(KeyCode::Enter))?;
+        assert!(root.join("2026-05-22.md").exists());
+        assert!(!root.join("2026-05-21.md").exists());
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn escape_hides_selected_command_entry_and_focuses_journal() -> io::Result<()> {
+    fn tick_keeps_other_days_and_shifts_split_view() -> io::Result<()> {
         let (mut app, root) = test_app()?;
 
-        app.handle_key(key(KeyCode::Char(':')))?;
-        type_text(&mut app, "nt")?;
-        app.handle_key(key(KeyCode::Enter))?;
-        type_text(&mut app, "draft")?;
-        app.handle_key(key(KeyCode::Esc))?;
+        app.handle_key(key(KeyCode::Char('h')))?;
+        app.clock = after_midnight;
+        app.tick();
+        assert_eq!(app.journal.date, day(20));
+        assert_eq!(status(&app), "It is now 2026-05-22.");
+        let _ = fs::remove_dir_all(root);
 
-        assert_eq!(app.focus, Focus::Journal);
-        assert_eq!(app.command_mode, CommandPaneMode::Normal);
-        assert!(app.command_input.is_empty());
-        assert!(app.journal.entries.is_empty());
+        let (mut app, root) = test_app()?;
+        toggle_split(&mut app)?;
+        app.clock = after_midnight;
+        app.tick();
+        let split = app.split.as_ref().expect("split view should stay open");
+        assert_eq!(split.panes[0].journal.date, day(21));
+        assert_eq!(split.panes[1].journal.date, day(22));
+        assert_eq!(app.journal.date, day(22));
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
+    fn before_standup() -> NaiveDateTime {
+        date().and_hms_opt(13, 52, 0).unwrap()
+    }
+
+    fn after_snooze() -> NaiveDateTime {
+        date().and_hms_opt(13, 58, 0).unwrap()
+    }
+
+    fn during_standup() -> NaiveDateTime {
+        date().and_hms_opt(14, 30, 0).unwrap()
+    }
+
     #[test]
-    fn executes_no_text_command_immediately_from_search() -> io::Result<()> {
-        let (mut app, root) = test_app()?;
+    fn parses_reminder_commands() {
+        assert_eq!(parse_command(":snooze").unwrap(), Command::Snooze(None));
+        assert_eq!(
+            parse_command(":snooze 15").unwrap(),
+            Command::Snooze(Some(15))
+        );
+        assert_eq!(
+            parse_command(":snooze 5m").unwrap(),
+            Command::Snooze(Some(5))
+        );
+        assert!(parse_command(":snooze later").is_err());
+        assert_eq!(parse_command(":dismiss").unwrap(), Command::Dismiss);
+    }
 
-        app.handle_key(key(KeyCode::Char(':')))?;
-        type_text(&mut app, "q")?;
-        app.handle_key(key(KeyCode::Enter))?;
+    #[test]
+    fn fires_snoozes_and_dismisses_reminders() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        fs::write(
+            root.join("settings.md"),
+            "- reminder command: notify-send \"$1\"\n- task reminder time: off\n",
+        )?;
+        fs::write(
+            root.join("2026-05-21.md"),
+            "  ◦ 14:00-15:00 standup\n  · open task\n",
+        )?;
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
 
-        assert_eq!(app.command_mode, CommandPaneMode::Normal);
-        assert!(app.should_quit);
+        app.clock = test_now;
+        app.tick();
+        assert!(app.reminders.is_empty());
+        assert!(app.take_effects().is_empty());
+
+        app.clock = before_standup;
+        app.tick();
+        assert_eq!(app.reminders.len(), 1);
+        assert_eq!(status(&app), "Reminder: 2026-05-21 14:00-15:00 standup.");
+        assert_eq!(
+            app.take_effects(),
+            [
+                Effect::Bell,
+                Effect::RunCommand {
+                    command: String::from("notify-send \"$1\""),
+                    argument: String::from("2026-05-21 14:00-15:00 standup"),
+                },
+            ]
+        );
+        app.tick();
+        assert!(app.take_effects().is_empty());
+
+        run_journal_search(&mut app, "snooze 5")?;
+        assert!(app.reminders.is_empty());
+        assert_eq!(status(&app), "Snoozed reminders for 5 minutes.");
+        app.tick();
+        assert!(app.reminders.is_empty());
+
+        app.clock = after_snooze;
+        app.tick();
+        assert_eq!(app.reminders.len(), 1);
+
+        run_journal_search(&mut app, "dismiss")?;
+        assert_eq!(status(&app), "Dismissed reminders.");
+        app.clock = during_standup;
+        app.tick();
+        assert!(app.reminders.is_empty());
+        run_journal_search(&mut app, "dismiss")?;
+        assert_eq!(status(&app), "No reminders to dismiss.");
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn keeps_exact_command_submission_available_from_search() -> io::Result<()> {
+    fn keeps_a_bounded_message_history_with_severities() -> io::Result<()> {
         let (mut app, root) = test_app()?;
 
-        app.handle_key(key(KeyCode::Char(':')))?;
-        type_text(&mut app, "n exact note")?;
-        app.handle_key(key(KeyCode::Enter))?;
+        run_journal_search(&mut app, "x")?;
+        let latest = app.latest_message().unwrap();
+        assert_eq!(latest.text, "No entry selected.");
+        assert_eq!(latest.severity, Severity::Warning);
 
-        assert_eq!(app.command_mode, CommandPaneMode::Normal);
-        assert_eq!(app.focus, Focus::Journal);
-        assert_eq!(app.journal.entries.len(), 1);
-        assert_eq!(app.journal.entries[0].kind, EntryKind::Note);
-        assert_eq!(app.journal.entries[0].text, "exact note");
+        app.handle_key(key(KeyCode::Esc))?;
+        assert_eq!(app.latest_message().unwrap().severity, Severity::Info);
+        app.set_error("Could not write.");
+        app.set_error("Could not write.");
+        let latest = app.latest_message().unwrap();
+        assert_eq!((latest.severity, latest.repeats), (Severity::Error, 2));
+
+        for index in 0..MESSAGE_HISTORY_LIMIT {
+            app.set_status(format!("message {index}"));
+        }
+        assert_eq!(app.messages().len(), MESSAGE_HISTORY_LIMIT);
+        assert_eq!(app.messages()[0].text, "message 0");
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
+    fn help_sections(app: &App) -> &[HelpSection] {
+        match &app.overlay {
+            Some(Overlay::Help { sections, .. }) => sections,
+            overlay => panic!("expected help, got {overlay:?}"),
+        }
+    }
+
+    fn help_row<'a>(sections: &'a [HelpSection], description: &str) -> &'a str {
+        sections
+            .iter()
+            .flat_map(|section| &section.rows)
+            .find(|(_, row)| *row == description)
+            .map(|(key, _)| key.as_str())
+            .unwrap_or_else(|| panic!("no help row for {description}"))
+    }
+
     #[test]
-    fn identifies_exact_shortcut_input_during_search() -> io::Result<()> {
+    fn help_lists_keys_highlighted_entry_actions_and_commands() -> io::Result<()> {
         let (mut app, root) = test_app()?;
+        assert_eq!(parse_command(":help").unwrap(), Command::ShowHelp);
+
+        app.handle_key(key(KeyCode::Char('?')))?;
+        let sections = help_sections(&app);
+        assert_eq!(sections.len(), 2);
+        assert_eq!(help_row(sections, "Highlight the next entry"), "j Down");
+        assert_eq!(help_row(sections, "Search commands"), ":");
+        assert_eq!(help_row(sections, "Open the leader menu"), "Space");
+        assert_eq!(
+            sections[1].rows.len(),
+            COMMAND_PANE_OPTIONS.len(),
+            "every registered command is listed"
+        );
+        assert_eq!(help_row(sections, "Show keys and commands"), ":help");
 
-        app.handle_key(key(KeyCode::Char(':')))?;
-        type_text(&mut app, "n exact note")?;
+        app.handle_key(key(KeyCode::End))?;
+        assert!(matches!(app.overlay, Some(Overlay::Help { scroll, .. }) if scroll > 0));
+        app.handle_key(key(KeyCode::Char('?')))?;
+        assert!(app.overlay.is_none());
 
-        assert!(app.visible_command_search_results().is_empty());
-        assert!(app.command_search_input_is_exact_command());
+        run_journal_search(&mut app, "t buy milk")?;
+        app.handle_key(key(KeyCode::Esc))?;
+        app.handle_key(key(KeyCode::Char('?')))?;
+        let sections = help_sections(&app);
+        assert_eq!(sections[1].title, "Highlighted open task");
+        let tokens = sections[1]
+            .rows
+            .iter()
+            .map(|(token, _)| token.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(tokens, [":x", ":c", ":i", ":move", ":copy", ":details"]);
+
+        app.handle_key(key(KeyCode::Esc))?;
+        run_journal_search(&mut app, "x")?;
+        run_journal_search(&mut app, "help")?;
+        let sections = help_sections(&app);
+        assert_eq!(sections[1].title, "Highlighted completed task");
+        assert!(!sections[1].rows.iter().any(|(token, _)| token == ":c"));
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn clears_no_match_status_when_search_input_changes() -> io::Result<()> {
-        let (mut app, root) = test_app()?;
+    fn remapped_keys_drive_navigation_and_help() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        fs::write(
+            root.join("settings.md"),
+            "- key next entry: n\n- key help: F1\n- key today: j\n",
+        )?;
+        fs::write(root.join("2026-05-21.md"), "  - first\n  - second\n")?;
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+        app.clock = test_now;
 
-        app.handle_key(key(KeyCode::Char(':')))?;
-        type_text(&mut app, "zzz")?;
-        app.handle_key(key(KeyCode::Enter))?;
-        assert_eq!(app.status, "No matching commands.");
+        app.handle_key(key(KeyCode::Up))?;
+        assert_eq!(app.selected, Some(0));
+        app.handle_key(key(KeyCode::Char('n')))?;
+        assert_eq!(app.selected, Some(1));
 
-        for _ in 0.."zzz".len() {
-            app.handle_key(key(KeyCode::Backspace))?;
-        }
-        type_text(&mut app, "n exact note")?;
+        app.handle_key(key(KeyCode::Char('h')))?;
+        assert_eq!(app.journal.date, date() - Days::new(1));
+        app.handle_key(key(KeyCode::Char('j')))?;
+        assert_eq!(app.journal.date, date());
 
-        assert_eq!(app.status, "Search commands.");
-        assert!(app.command_search_input_is_exact_command());
+        app.handle_key(key(KeyCode::Char('?')))?;
+        assert!(app.overlay.is_none());
+        app.handle_key(key(KeyCode::F(1)))?;
+        let sections = help_sections(&app);
+        assert_eq!(help_row(sections, "Highlight the next entry"), "n");
+        assert_eq!(help_row(sections, "Go to today"), "j");
+        assert_eq!(help_row(sections, "Show this help"), "F1");
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
+    fn journal_texts(app: &App) -> Vec<&str> {
+        app.journal
+            .entries
+            .iter()
+            .map(|entry| entry.text.as_str())
+            .collect()
+    }
+
     #[test]
-    fn reports_empty_search_results_without_exiting_search() -> io::Result<()> {
-        let (mut app, root) = test_app()?;
+    fn counts_motions_and_sections_move_the_highlight() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        fs::write(
+            root.join("2026-05-21.md"),
+            "# Morning\n  · a\n  · b\n  - n1\n  - n2\n  · c\n",
+        )?;
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+        app.clock = test_now;
+        assert_eq!(app.selected, Some(5));
 
-        app.handle_key(key(KeyCode::Char(':')))?;
-        type_text(&mut app, "zzz")?;
-        app.handle_key(key(KeyCode::Enter))?;
+        type_text(&mut app, "gg")?;
+        assert_eq!(app.selected, Some(0));
+        type_text(&mut app, "3j")?;
+        assert_eq!(app.selected, Some(3));
+        type_text(&mut app, "G")?;
+        assert_eq!(app.selected, Some(5));
+        type_text(&mut app, "2G")?;
+        assert_eq!(app.selected, Some(1));
+        type_text(&mut app, "12gg")?;
+        assert_eq!(app.selected, Some(5));
 
-        assert_eq!(app.command_mode, CommandPaneMode::Search);
-        assert_eq!(app.status, "No matching commands.");
+        type_text(&mut app, "{")?;
+        assert_eq!(app.selected, Some(3));
+        type_text(&mut app, "{")?;
+        assert_eq!(app.selected, Some(1));
+        type_text(&mut app, "}")?;
+        assert_eq!(app.selected, Some(3));
+        type_text(&mut app, "2{")?;
+        assert_eq!(app.selected, Some(0));
+        type_text(&mut app, "2}")?;
+        assert_eq!(app.selected, Some(3));
+
+        type_text(&mut app, "1")?;
+        type_text(&mut app, "0")?;
+        assert_eq!(app.pending_keys(), "10");
+        app.handle_key(key(KeyCode::Esc))?;
+        assert_eq!(app.pending_keys(), "");
+        type_text(&mut app, "3h")?;
+        assert_eq!(app.journal.date, date() - Days::new(3));
+        type_text(&mut app, "2L")?;
+        assert_eq!(app.journal.date, date() + Days::new(11));
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn command_text_inputs_keep_vim_keys_as_text() -> io::Result<()> {
-        let (mut app, root) = test_app()?;
-
-        app.handle_key(key(KeyCode::Char(':')))?;
-        type_text(&mut app, "hjkl")?;
-        assert_eq!(app.focus, Focus::Command);
-        assert_eq!(app.command_mode, CommandPaneMode::Search);
-        assert_eq!(app.command_input, "hjkl");
-
-        app.handle_key(key(KeyCode::Esc))?;
-        app.handle_key(key(KeyCode::Char(':')))?;
-        type_text(&mut app, "n")?;
-        app.handle_key(key(KeyCode::Enter))?;
-        type_text(&mut app, "hjkl")?;
+    fn deletes_yanks_pastes_and_undoes_entries() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        let path = root.join("2026-05-21.md");
+        fs::write(&path, "  · a\n  · b\n  · c\n  · d\n")?;
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+        app.clock = test_now;
+
+        type_text(&mut app, "gg2d")?;
+        assert_eq!(app.pending_keys(), "2d");
+        type_text(&mut app, "d")?;
+        assert_eq!(journal_texts(&app), ["c", "d"]);
+        assert_eq!(fs::read_to_string(&path)?, "  · c\n  · d\n");
+        assert_eq!(status(&app), "Deleted 2 entries.");
+
+        type_text(&mut app, "p")?;
+        assert_eq!(journal_texts(&app), ["c", "a", "b", "d"]);
+        assert_eq!(app.selected, Some(2));
 
-        assert_eq!(
-            app.command_mode,
-            CommandPaneMode::Entry(CommandAction::Add(EntryKind::Note))
-        );
-        assert_eq!(app.command_input, "hjkl");
+        type_text(&mut app, "u")?;
+        assert_eq!(journal_texts(&app), ["c", "d"]);
+        type_text(&mut app, "u")?;
+        assert_eq!(journal_texts(&app), ["a", "b", "c", "d"]);
+        assert_eq!(fs::read_to_string(&path)?, "  · a\n  · b\n  · c\n  · d\n");
+        assert_eq!(status(&app), "Undid delete.");
+        type_text(&mut app, "u")?;
+        assert_eq!(status(&app), "Nothing to undo.");
+
+        type_text(&mut app, "ggdjyy")?;
+        assert_eq!(journal_texts(&app), ["a", "b", "c", "d"]);
+        type_text(&mut app, "jp")?;
+        assert_eq!(journal_texts(&app), ["a", "b", "a", "c", "d"]);
+
+        type_text(&mut app, "h2p")?;
+        let yesterday = root.join("2026-05-20.md");
+        assert_eq!(journal_texts(&app), ["a", "a"]);
+        assert!(yesterday.exists());
+        type_text(&mut app, "u")?;
+        assert!(app.journal.entries.is_empty());
+        assert!(!yesterday.exists());
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn exposes_and_executes_task_actions_for_highlighted_task() -> io::Result<()> {
-        let (mut app, root) = test_app()?;
-        app.journal.add_entry(EntryKind::Note, "keep note");
-        app.journal.add_entry(EntryKind::Task, "ship feature");
-        app.selected = Some(1);
-
+    fn applies_entry_actions_to_a_visual_range() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        let path = root.join("2026-05-21.md");
+        fs::write(&path, "  · a\n  X b\n  - c\n  · d\n")?;
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+        app.clock = test_now;
+
+        type_text(&mut app, "ggVjj")?;
+        assert_eq!(app.visual_range(), Some(0..=2));
+        app.handle_key(key(KeyCode::Char('?')))?;
+        assert!(help_sections(&app)
+            .iter()
+            .any(|section| section.title == "Selected 3 entries"));
+        app.handle_key(key(KeyCode::Esc))?;
         app.handle_key(key(KeyCode::Char(':')))?;
-
-        let names = search_result_names(&app);
-        assert_eq!(names[0], "complete");
-        assert_eq!(names[1], "cancel");
-        assert!(names.contains(&"important"));
+        let names = app
+            .entry_action_command_options()
+            .iter()
+            .map(|option| option.name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["complete", "cancel", "important", "move", "copy"]);
 
         type_text(&mut app, "complete")?;
         app.handle_key(key(KeyCode::Enter))?;
-
-        assert_eq!(app.focus, Focus::Journal);
+        assert_eq!(app.visual_range(), None);
         assert_eq!(
-            app.journal.entries[1].state,
-            crate::journal::EntryState::Completed
+            fs::read_to_string(&path)?,
+            "  X a <!-- completed 2026-05-21T09:30 -->\n  X b\n  - c\n  · d\n"
         );
+
+        type_text(&mut app, "ggVj")?;
+        run_journal_search(&mut app, "complete")?;
+        assert_eq!(status(&app), "Reopened 2 entries.");
+        type_text(&mut app, "u")?;
+        assert_eq!(status(&app), "Undid complete.");
         assert_eq!(
-            app.journal.entries[0].state,
-            crate::journal::EntryState::Open
+            fs::read_to_string(&path)?,
+            "  X a <!-- completed 2026-05-21T09:30 -->\n  X b\n  - c\n  · d\n"
         );
+
+        type_text(&mut app, "GVk")?;
+        run_journal_search(&mut app, "move tomorrow")?;
+        assert_eq!(status(&app), "Moved 2 entries to 2026-05-22.");
+        assert_eq!(journal_texts(&app), ["a", "b", "d"]);
         assert_eq!(
-            fs::read_to_string(root.join("2026-05-21.md"))?,
-            "  - keep note\n  X ship feature\n"
+            fs::read_to_string(root.join("2026-05-22.md"))?,
+            "  - c <!-- created 2026-05-21T09:30 -->\n  · d <!-- created 2026-05-21T09:30 -->\n"
         );
+        type_text(&mut app, "u")?;
+        assert_eq!(journal_texts(&app), ["a", "b", "c", "d"]);
+        assert!(!root.join("2026-05-22.md").exists());
 
-        run_journal_search(&mut app, "c")?;
+        type_text(&mut app, "ggVjd")?;
+        assert_eq!(status(&app), "Deleted 2 entries.");
+        assert_eq!(journal_texts(&app), ["c", "d"]);
 
-        assert_eq!(app.status, "Completed tasks cannot be cancelled.");
-        assert_eq!(
-            app.journal.entries[1].state,
-            crate::journal::EntryState::Completed
+        type_text(&mut app, "VV")?;
+        assert_eq!(app.visual_range(), None);
+        type_text(&mut app, "Vh")?;
+        assert_eq!(app.visual_range(), None);
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    fn mouse(kind: MouseEventKind, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column: 1,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    fn click(app: &mut App, hits: &HitMap, row: u16) -> io::Result<()> {
+        app.handle_mouse(mouse(MouseEventKind::Down(MouseButton::Left), row), hits)
+    }
+
+    #[test]
+    fn clicks_and_scrolls_select_panes_entries_and_results() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        fs::write(root.join("2026-05-20.md"), "- yesterday note\n")?;
+        fs::write(root.join("2026-05-21.md"), "· a\n· b\n· c\n")?;
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+        app.clock = test_now;
+        toggle_split(&mut app)?;
+        let mut hits = HitMap::default();
+        hits.push(Rect::new(0, 0, 10, 5), HitTarget::Pane(0));
+        hits.push(
+            Rect::new(0, 1, 10, 1),
+            HitTarget::Entry { pane: 0, index: 0 },
         );
+        hits.push(Rect::new(0, 5, 10, 5), HitTarget::Pane(1));
+        for index in 0..3 {
+            hits.push(
+                Rect::new(0, 6 + index as u16, 10, 1),
+                HitTarget::Entry { pane: 1, index },
+            );
+        }
+
+        click(&mut app, &hits, 2)?;
+        assert_eq!(app.split.as_ref().unwrap().active, 0);
+        assert_eq!(app.journal.date, day(20));
+
+        click(&mut app, &hits, 7)?;
+        assert_eq!(app.split.as_ref().unwrap().active, 1);
+        assert_eq!(app.selected, Some(1));
+        assert_eq!(app.focus, Focus::Journal);
+
+        app.handle_mouse(mouse(MouseEventKind::ScrollDown, 9), &hits)?;
+        assert_eq!(app.selected, Some(2));
+        app.handle_mouse(mouse(MouseEventKind::ScrollUp, 20), &hits)?;
+        assert_eq!(app.selected, Some(1));
+
+        click(&mut app, &hits, 6)?;
+        click(&mut app, &hits, 6)?;
+        assert_eq!(app.selected, Some(0));
+        assert_eq!(app.focus, Focus::Command);
+        assert_eq!(app.command_mode, CommandPaneMode::Search);
+        assert_eq!(search_result_names(&app)[0], "complete");
+
+        let mut results = HitMap::default();
+        results.push(Rect::new(0, 12, 10, 1), HitTarget::CommandResult(0));
+        results.push(Rect::new(0, 13, 10, 1), HitTarget::CommandResult(1));
+        click(&mut app, &results, 13)?;
+        assert_eq!(app.command_result_index, 1);
+        app.handle_mouse(mouse(MouseEventKind::ScrollUp, 13), &results)?;
+        assert_eq!(app.command_result_index, 0);
+        click(&mut app, &results, 12)?;
+        click(&mut app, &results, 12)?;
+        assert_eq!(app.focus, Focus::Journal);
         assert_eq!(
             fs::read_to_string(root.join("2026-05-21.md"))?,
-            "  - keep note\n  X ship feature\n"
+            "  X a <!-- completed 2026-05-21T09:30 -->\n· b\n· c\n"
         );
 
+        app.handle_key(key(KeyCode::Char('?')))?;
+        click(&mut app, &hits, 2)?;
+        assert_eq!(app.split.as_ref().unwrap().active, 1);
+
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn exposes_and_executes_event_actions_for_highlighted_event() -> io::Result<()> {
-        let (mut app, root) = test_app()?;
-        app.journal.add_entry(EntryKind::Note, "keep note");
-        app.journal.add_entry(EntryKind::Event, "planning");
-        app.journal.add_entry(EntryKind::Task, "ship feature");
-        app.selected = Some(1);
-
-        app.handle_key(key(KeyCode::Char(':')))?;
+    fn opens_and_copies_links_from_the_highlighted_entry() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        fs::write(root.join("settings.md"), "- open command: browse \"$1\"\n")?;
+        fs::write(
+            root.join("2026-05-21.md"),
+            "· fix https://example.com/T-1 per [plan](./plan.md)\n- no links\n- see /tmp/a.md\n",
+        )?;
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
 
-        let names = search_result_names(&app);
-        assert_eq!(names[0], "cancel");
-        assert!(names.contains(&"important"));
-        assert!(!names.contains(&"complete"));
+        type_text(&mut app, "k")?;
+        run_journal_search(&mut app, "open")?;
+        assert_eq!(status(&app), "No links in the entry.");
+        assert!(app.take_effects().is_empty());
 
-        type_text(&mut app, "cancel")?;
-        app.handle_key(key(KeyCode::Enter))?;
+        type_text(&mut app, "gg")?;
+        app.handle_key(key(KeyCode::Char('?')))?;
+        assert_eq!(
+            help_row(help_sections(&app), "Open a URL or path from the entry"),
+            ":open"
+        );
+        app.handle_key(key(KeyCode::Esc))?;
 
-        assert_eq!(app.focus, Focus::Journal);
+        run_journal_search(&mut app, "open")?;
         assert_eq!(
-            app.journal.entries[1].state,
-            crate::journal::EntryState::Cancelled
+            app.overlay,
+            Some(Overlay::Links {
+                links: vec![
+                    String::from("https://example.com/T-1"),
+                    String::from("./plan.md"),
+                ],
+                selected: 0,
+                copy: false,
+            })
         );
+        type_text(&mut app, "j")?;
+        app.handle_key(key(KeyCode::Enter))?;
+        assert_eq!(app.overlay, None);
+        assert_eq!(status(&app), "Opening ./plan.md.");
         assert_eq!(
-            app.journal.entries[0].state,
-            crate::journal::EntryState::Open
+            app.take_effects(),
+            [Effect::Open {
+                command: String::from("browse \"$1\""),
+                link: String::from("./plan.md"),
+            }]
         );
+
+        run_journal_search(&mut app, "clip")?;
+        type_text(&mut app, "y")?;
         assert_eq!(
-            app.journal.entries[2].state,
-            crate::journal::EntryState::Open
+            app.take_effects(),
+            [Effect::Copy(String::from("https://example.com/T-1"))]
         );
+
+        type_text(&mut app, "G")?;
+        run_journal_search(&mut app, "clip")?;
+        assert_eq!(app.overlay, None);
+        assert_eq!(status(&app), "Copied /tmp/a.md to the clipboard.");
         assert_eq!(
-            fs::read_to_string(root.join("2026-05-21.md"))?,
-            "  - keep note\n  ◦ ~~planning~~\n  · ship feature\n"
+            app.take_effects(),
+            [Effect::Copy(String::from("/tmp/a.md"))]
         );
 
         let _ = fs::remove_dir_all(root);
@@ -2061,108 +6188,171 @@ mod tests {
     }
 
     #[test]
-    fn toggles_importance_for_highlighted_entry_and_alias() -> io::Result<()> {
-        let (mut app, root) = test_app()?;
-        app.journal.add_entry(EntryKind::Note, "plain note");
-        app.selected = Some(0);
-
-        run_journal_search(&mut app, "i")?;
+    fn edits_the_focused_day_in_an_editor_and_reloads_it() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        let path = root.join("2026-05-21.md");
+        fs::write(&path, "· a\n· b\n· c\n")?;
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+        app.clock = test_now;
+        toggle_split(&mut app)?;
+        type_text(&mut app, "dd")?;
 
-        assert_eq!(app.status, "Entry marked important.");
-        assert!(app.journal.entries[0].important);
+        run_journal_search(&mut app, "editor")?;
         assert_eq!(
-            fs::read_to_string(root.join("2026-05-21.md"))?,
-            "* - plain note\n"
+            app.take_effects(),
+            [Effect::Edit {
+                path: path.clone(),
+                date: date(),
+            }]
         );
 
-        run_journal_search(&mut app, "important")?;
+        fs::write(&path, "· a\n· edited\n")?;
+        app.reload_day(date())?;
+        assert_eq!(journal_texts(&app), ["a", "edited"]);
+        assert_eq!(app.selected, Some(1));
+        let split = app.split.as_ref().expect("split view should stay open");
+        assert_eq!(split.panes[1].journal.entries.len(), 2);
+        assert_eq!(status(&app), "Reloaded 2026-05-21.");
 
-        assert_eq!(app.status, "Entry unmarked important.");
-        assert!(!app.journal.entries[0].important);
-        assert_eq!(
-            fs::read_to_string(root.join("2026-05-21.md"))?,
-            "  - plain note\n"
-        );
+        type_text(&mut app, "u")?;
+        assert_eq!(status(&app), "Nothing to undo.");
+        assert_eq!(fs::read_to_string(&path)?, "· a\n· edited\n");
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn exposes_only_importance_for_notes_and_feelings_and_keeps_invalid_actions_unchanged(
-    ) -> io::Result<()> {
-        let (mut app, root) = test_app()?;
-        app.journal.add_entry(EntryKind::Note, "plain note");
-        app.journal.add_entry(EntryKind::Feeling, "focused");
-        app.journal.add_entry(EntryKind::Task, "ship feature");
-        app.journal.save()?;
-        let before = fs::read_to_string(root.join("2026-05-21.md"))?;
+    fn picks_up_days_changed_outside_the_app() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        let path = root.join("2026-05-21.md");
+        fs::write(&path, "· task\n")?;
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+        app.clock = test_now;
+
+        // A change made before a key is shown first; the key is not applied
+        // to the old entries, and nothing is saved over the change.
+        fs::write(&path, "· task\n· outside\n")?;
+        run_journal_search(&mut app, "x")?;
+        assert_eq!(status(&app), "2026-05-21 changed on disk and was reloaded.");
+        assert_eq!(journal_texts(&app), ["task", "outside"]);
+        assert_eq!(fs::read_to_string(&path)?, "· task\n· outside\n");
+        run_journal_search(&mut app, "x")?;
+        assert_eq!(app.journal.entries[0].state, EntryState::Completed);
 
-        app.selected = Some(0);
-        app.handle_key(key(KeyCode::Char(':')))?;
-        let note_names = search_result_names(&app);
-        assert!(note_names.contains(&"important"));
-        assert!(!note_names.contains(&"complete"));
-        assert!(!note_names.contains(&"cancel"));
-        type_text(&mut app, "x")?;
-        app.handle_key(key(KeyCode::Enter))?;
+        // A cached day is read again when its file changed while away.
+        app.handle_key(key(KeyCode::Right))?;
+        fs::write(&path, "· while away\n")?;
+        app.handle_key(key(KeyCode::Left))?;
+        assert_eq!(journal_texts(&app), ["while away"]);
+
+        // The visible day is refreshed in the background while idle.
+        fs::write(&path, "- while idle, longer\n")?;
+        let started = Instant::now();
+        while journal_texts(&app) != ["while idle, longer"] {
+            assert!(started.elapsed() < Duration::from_secs(5));
+            thread::sleep(Duration::from_millis(1));
+            app.tick();
+        }
+        assert_eq!(status(&app), "2026-05-21 changed on disk and was reloaded.");
 
-        app.selected = Some(1);
-        app.handle_key(key(KeyCode::Char(':')))?;
-        let feeling_names = search_result_names(&app);
-        assert!(feeling_names.contains(&"important"));
-        assert!(!feeling_names.contains(&"complete"));
-        assert!(!feeling_names.contains(&"cancel"));
-        type_text(&mut app, "c")?;
-        app.handle_key(key(KeyCode::Enter))?;
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
 
+    #[test]
+    fn links_edits_and_previews_detail_notes() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        let path = root.join("2026-05-21.md");
+        fs::write(&path, "◦ planning\n- loose\n")?;
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+        app.clock = test_now;
+        let note = root.join("notes").join("2026-05-21-1.md");
+
+        run_journal_search(&mut app, "details")?;
+        assert_eq!(app.take_effects(), [Effect::EditNote(note.clone())]);
+        assert_eq!(status(&app), "Created note 2026-05-21-1.");
+        assert_eq!(fs::read_to_string(&note)?, "# loose\n\n");
         assert_eq!(
-            app.journal.entries[0].state,
-            crate::journal::EntryState::Open
-        );
-        assert_eq!(
-            app.journal.entries[1].state,
-            crate::journal::EntryState::Open
-        );
-        assert_eq!(
-            app.journal.entries[2].state,
-            crate::journal::EntryState::Open
+            fs::read_to_string(&path)?,
+            "◦ planning\n  - loose <!-- note 2026-05-21-1 -->\n"
         );
-        assert_eq!(fs::read_to_string(root.join("2026-05-21.md"))?, before);
+
+        assert_eq!(app.detail_note(), None);
+        app.tick();
+        let body = app.detail_note().map(|note| note.body.clone());
+        assert_eq!(body, Some(Ok(String::from("# loose\n\n"))));
+
+        type_text(&mut app, "k")?;
+        app.tick();
+        assert_eq!(app.detail_note(), None);
+
+        type_text(&mut app, "j")?;
+        run_journal_search(&mut app, "body")?;
+        assert_eq!(app.take_effects(), [Effect::EditNote(note.clone())]);
+        fs::write(&note, "# loose\n\nMore thoughts.\n")?;
+        app.tick();
+        let body = app.detail_note().map(|note| note.body.clone());
+        assert_eq!(body, Some(Ok(String::from("# loose\n\nMore thoughts.\n"))));
+
+        type_text(&mut app, "u")?;
+        assert_eq!(fs::read_to_string(&path)?, "◦ planning\n- loose\n");
+        assert!(note.exists());
+
+        type_text(&mut app, "gg")?;
+        run_journal_search(&mut app, "details")?;
+        let unedited = root.join("notes").join("2026-05-21-2.md");
+        assert_eq!(status(&app), "Created note 2026-05-21-2.");
+        assert!(unedited.exists());
+        type_text(&mut app, "u")?;
+        assert!(!unedited.exists());
+        assert!(note.exists());
 
         let _ = fs::remove_dir_all(root);
         Ok(())
     }
 
     #[test]
-    fn does_not_apply_actions_without_a_highlighted_entry() -> io::Result<()> {
-        let (mut app, root) = test_app()?;
-        app.journal.add_entry(EntryKind::Task, "leave open");
-        app.journal.save()?;
-        app.selected = None;
-
-        run_journal_search(&mut app, "x")?;
+    fn moved_and_cut_entries_keep_their_detail_notes() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        let path = root.join("2026-05-21.md");
+        fs::write(
+            &path,
+            "- moved <!-- note 2026-05-21-1 -->\n- cut <!-- note 2026-05-21-2 -->\n",
+        )?;
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+        app.clock = test_now;
+        let note_of = |app: &App, text: &str| {
+            app.journal
+                .entries
+                .iter()
+                .find(|entry| entry.text == text)
+                .and_then(|entry| entry.note.clone())
+        };
 
-        assert_eq!(app.status, "No entry selected.");
-        assert_eq!(app.selected, None);
-        assert_eq!(
-            app.journal.entries[0].state,
-            crate::journal::EntryState::Open
-        );
+        type_text(&mut app, "gg")?;
+        run_journal_search(&mut app, "copy tomorrow")?;
+        run_journal_search(&mut app, "move tomorrow")?;
+        let tomorrow = fs::read_to_string(root.join("2026-05-22.md"))?;
         assert_eq!(
-            fs::read_to_string(root.join("2026-05-21.md"))?,
-            "  · leave open\n"
+            tomorrow.lines().collect::<Vec<_>>(),
+            [
+                "  - moved <!-- created 2026-05-21T09:30 -->",
+                "  - moved <!-- created 2026-05-21T09:30 note 2026-05-21-1 -->",
+            ]
         );
 
-        run_journal_search(&mut app, "i")?;
-
-        assert_eq!(app.status, "No entry selected.");
-        assert_eq!(app.selected, None);
-        assert!(!app.journal.entries[0].important);
-        assert_eq!(
-            fs::read_to_string(root.join("2026-05-21.md"))?,
-            "  · leave open\n"
-        );
+        type_text(&mut app, "yyp")?;
+        assert_eq!(note_of(&app, "cut"), Some(String::from("2026-05-21-2")));
+        assert_eq!(app.journal.entries.len(), 2);
+        assert_eq!(app.journal.entries[1].note, None);
+        type_text(&mut app, "ddkddp")?;
+        assert_eq!(journal_texts(&app), ["cut"]);
+        assert_eq!(note_of(&app, "cut"), Some(String::from("2026-05-21-2")));
 
         let _ = fs::remove_dir_all(root);
         Ok(())
@@ -0,0 +1,269 @@
+//! Parsed days kept in memory, with a background thread that loads the days
+//! next to the view before they are needed and reads again the days on screen
+//! whose files changed.
+
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{
+        mpsc::{self, Sender},
+        Arc, Mutex, PoisonError,
+    },
+    thread,
+    time::SystemTime,
+};
+
+use chrono::NaiveDate;
+
+use crate::{
+    journal::{journal_path, Journal},
+    recurring::RecurringRules,
+};
+
+/// The most days kept in memory. Older days are dropped first.
+const CACHE_CAPACITY: usize = 64;
+
+/// The size and modification time of a file, or `None` when it is missing.
+pub type FileStamp = Option<(SystemTime, u64)>;
+
+#[derive(Debug)]
+struct CachedDay {
+    journal: Journal,
+    last_used: u64,
+}
+
+#[derive(Debug, Default)]
+struct Days {
+    days: HashMap<NaiveDate, CachedDay>,
+    uses: u64,
+    /// Days the prefetch thread read since the app last asked.
+    refreshed: Vec<NaiveDate>,
+}
+
+impl Days {
+    /// The cached copy of a day, if it was read with the given stamp.
+    fn get(&mut self, date: NaiveDate, stamp: FileStamp) -> Option<Journal> {
+        self.uses += 1;
+        let day = self
+            .days
+            .get_mut(&date)
+            .filter(|day| day.journal.stamp() == stamp)?;
+        day.last_used = self.uses;
+        Some(day.journal.clone())
+    }
+
+    fn insert(&mut self, journal: Journal) {
+        self.uses += 1;
+        self.days.insert(
+            journal.date,
+            CachedDay {
+                journal,
+                last_used: self.uses,
+            },
+        );
+
+        if self.days.len() > CACHE_CAPACITY {
+            let oldest = self
+                .days
+                .iter()
+                .min_by_key(|(_, day)| day.last_used)
+                .map(|(date, _)| *date);
+            if let Some(oldest) = oldest {
+                self.days.remove(&oldest);
+            }
+        }
+    }
+}
+
+/// Loads days through an in-memory cache. Every lookup compares the day
+/// file's size and modification time with the cached copy, so edits made
+/// outside the app are picked up; only a changed or missing day is read. A
+/// background thread reads days before they are needed, and reads again the
+/// days it is asked about whose files changed.
+#[derive(Debug)]
+pub struct JournalCache {
+    root: PathBuf,
+    days: Arc<Mutex<Days>>,
+    prefetch: Sender<Vec<NaiveDate>>,
+}
+
+impl JournalCache {
+    /// Starts the prefetch thread. It stops when the cache is dropped.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        let root = root.into();
+        let days = Arc::new(Mutex::new(Days::default()));
+        let (prefetch, requests) = mpsc::channel::<Vec<NaiveDate>>();
+
+        let worker_root = root.clone();
+        let worker_days = Arc::clone(&days);
+        thread::spawn(move || {
+            for dates in requests {
+                for date in dates {
+                    // Prefetching is only a head start; `load` reports errors.
+                    let _ = refresh(&worker_root, &worker_days, date);
+                }
+            }
+        });
+
+        Self {
+            root,
+            days,
+            prefetch,
+        }
+    }
+
+    /// Returns the cached copy of a day while its file is unchanged, and reads
+    /// the file otherwise. The cache holds day files alone; occurrences of
+    /// `rules` are added to the copy returned, so rule changes never leave a
+    /// stale day.
+    pub fn load(&self, date: NaiveDate, rules: &RecurringRules) -> io::Result<Journal> {
+        let stamp = file_stamp(&journal_path(&self.root, date))?;
+        if let Some(journal) = lock(&self.days).get(date, stamp) {
+            return Ok(journal.with_rules(rules));
+        }
+
+        let journal = Journal::load_for_date(&self.root, date)?;
+        lock(&self.days).insert(journal.clone());
+        Ok(journal.with_rules(rules))
+    }
+
+    /// Reads `dates` in the background if they are not cached or their files
+    /// changed, so a later `load` finds them ready.
+    pub fn prefetch(&self, dates: Vec<NaiveDate>) {
+        let _ = self.prefetch.send(dates);
+    }
+
+    /// The days the prefetch thread has read since the last call, so copies
+    /// on screen can be swapped for newer ones.
+    pub fn take_refreshed(&self) -> Vec<NaiveDate> {
+        std::mem::take(&mut lock(&self.days).refreshed)
+    }
+}
+
+/// Reads a day that is not cached, or whose file changed since it was.
+fn refresh(root: &Path, days: &Mutex<Days>, date: NaiveDate) -> io::Result<()> {
+    let stamp = file_stamp(&journal_path(root, date))?;
+    if lock(days).get(date, stamp).is_some() {
+        return Ok(());
+    }
+
+    let journal = Journal::load_for_date(root, date)?;
+    let mut days = lock(days);
+    days.insert(journal);
+    days.refreshed.push(date);
+    Ok(())
+}
+
+fn lock(days: &Mutex<Days>) -> std::sync::MutexGuard<'_, Days> {
+    days.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+pub fn file_stamp(path: &Path) -> io::Result<FileStamp> {
+    match fs::metadata(path) {
+        Ok(metadata) => Ok(Some((metadata.modified()?, metadata.len()))),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{
+        env,
+        time::{Duration, Instant, UNIX_EPOCH},
+    };
+
+    fn day(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 5, day).unwrap()
+    }
+
+    fn temp_root() -> PathBuf {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        env::temp_dir().join(format!("bullet-journal-cache-test-{unique}"))
+    }
+
+    fn is_cached(cache: &JournalCache, date: NaiveDate) -> bool {
+        lock(&cache.days).days.contains_key(&date)
+    }
+
+    fn wait_for(mut done: impl FnMut() -> bool) {
+        let started = Instant::now();
+        while !done() {
+            assert!(started.elapsed() < Duration::from_secs(5));
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    fn first_text(cache: &JournalCache, date: NaiveDate) -> String {
+        let rules = RecurringRules::empty(&cache.root);
+        cache.load(date, &rules).unwrap().entries[0].text.clone()
+    }
+
+    #[test]
+    fn reloads_days_whose_files_change() -> io::Result<()> {
+        let root = temp_root();
+        fs::create_dir_all(&root)?;
+        let path = journal_path(&root, day(21));
+        fs::write(&path, "  - first\n")?;
+        let cache = JournalCache::new(&root);
+
+        assert_eq!(first_text(&cache, day(21)), "first");
+        assert!(is_cached(&cache, day(21)));
+
+        // Each rewrite changes the length, so the stamp differs even within
+        // the clock's resolution.
+        fs::write(&path, "  - second day\n")?;
+        assert_eq!(first_text(&cache, day(21)), "second day");
+
+        fs::write(&path, "  - third\n")?;
+        cache.prefetch(vec![day(21)]);
+        wait_for(|| cache.take_refreshed() == [day(21)]);
+        assert_eq!(first_text(&cache, day(21)), "third");
+
+        // Unchanged days are not read again.
+        let mut refreshed = Vec::new();
+        cache.prefetch(vec![day(21), day(22)]);
+        wait_for(|| {
+            refreshed.extend(cache.take_refreshed());
+            refreshed.contains(&day(22))
+        });
+        assert_eq!(refreshed, [day(22)]);
+
+        fs::remove_dir_all(root)
+    }
+
+    #[test]
+    fn prefetches_days_in_the_background() -> io::Result<()> {
+        let root = temp_root();
+        let cache = JournalCache::new(&root);
+
+        cache.prefetch(vec![day(20), day(22)]);
+        wait_for(|| is_cached(&cache, day(20)) && is_cached(&cache, day(22)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn drops_the_least_recently_used_day() {
+        let root = temp_root();
+        let mut days = Days::default();
+
+        for offset in 0..=CACHE_CAPACITY as u64 {
+            let date = day(1) + chrono::Days::new(offset);
+            days.insert(Journal::load_for_date(&root, date).unwrap());
+            if offset == 1 {
+                days.get(day(1), None);
+            }
+        }
+
+        assert_eq!(days.days.len(), CACHE_CAPACITY);
+        assert!(days.days.contains_key(&day(1)));
+        assert!(!days.days.contains_key(&day(2)));
+    }
+}
@@ -0,0 +1,188 @@
+//! The month calendar popup used to pick a day to show.
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+use chrono::{Datelike, Days, Months, NaiveDate};
+
+use crate::journal::{journal_path, parse_markdown, EntryKind, EntryState};
+
+/// What the calendar shows about a day that has a journal file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayMark {
+    /// The day has a file but no open tasks.
+    File,
+    /// The day's file has at least one open task.
+    OpenTasks,
+}
+
+/// A month grid with a highlighted day. Day marks are read from disk when the
+/// highlight enters a new month.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarPicker {
+    pub selected: NaiveDate,
+    marks: BTreeMap<NaiveDate, DayMark>,
+}
+
+impl CalendarPicker {
+    pub fn open(root: &Path, selected: NaiveDate) -> Self {
+        Self {
+            selected,
+            marks: read_month_marks(root, first_of_month(selected)),
+        }
+    }
+
+    pub fn mark(&self, date: NaiveDate) -> Option<DayMark> {
+        self.marks.get(&date).copied()
+    }
+
+    /// The first day of the month being shown.
+    pub fn month(&self) -> NaiveDate {
+        first_of_month(self.selected)
+    }
+
+    /// The weeks of the shown month, Monday first. Days from neighbouring
+    /// months are `None`.
+    pub fn weeks(&self) -> Vec<[Option<NaiveDate>; 7]> {
+        let month = self.month();
+        let mut weeks = Vec::new();
+        let mut week = [None; 7];
+
+        for date in month
+            .iter_days()
+            .take_while(|date| date.month() == month.month())
+        {
+            let column = date.weekday().num_days_from_monday() as usize;
+            week[column] = Some(date);
+            if column == 6 {
+                weeks.push(std::mem::take(&mut week));
+            }
+        }
+        if week.iter().any(Option::is_some) {
+            weeks.push(week);
+        }
+
+        weeks
+    }
+}
+
+// Moving the highlight.
+impl CalendarPicker {
+    pub fn move_days(&mut self, root: &Path, days: i64) {
+        let date = if days < 0 {
+            self.selected
+                .checked_sub_days(Days::new(days.unsigned_abs()))
+        } else {
+            self.selected.checked_add_days(Days::new(days as u64))
+        };
+
+        if let Some(date) = date {
+            self.select(root, date);
+        }
+    }
+
+    /// Moves the highlight by whole months, keeping the day of the month where
+    /// the new month has it.
+    pub fn move_months(&mut self, root: &Path, months: i32) {
+        let date = if months < 0 {
+            self.selected
+                .checked_sub_months(Months::new(months.unsigned_abs()))
+        } else {
+            self.selected.checked_add_months(Months::new(months as u32))
+        };
+
+        if let Some(date) = date {
+            self.select(root, date);
+        }
+    }
+
+    pub fn select(&mut self, root: &Path, date: NaiveDate) {
+        let month_changed = first_of_month(date) != self.month();
+        self.selected = date;
+        if month_changed {
+            self.marks = read_month_marks(root, self.month());
+        }
+    }
+}
+
+fn first_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+/// Marks are only hints, so files that cannot be read are left unmarked.
+fn read_month_marks(root: &Path, month: NaiveDate) -> BTreeMap<NaiveDate, DayMark> {
+    month
+        .iter_days()
+        .take_while(|date| date.month() == month.month())
+        .filter_map(|date| {
+            let contents = fs::read_to_string(journal_path(root, date)).ok()?;
+            let has_open_tasks = parse_markdown(&contents, date)
+                .iter()
+                .any(|entry| entry.kind == EntryKind::Task && entry.state == EntryState::Open);
+            let mark = if has_open_tasks {
+                DayMark::OpenTasks
+            } else {
+                DayMark::File
+            };
+            Some((date, mark))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{
+        env,
+        path::PathBuf,
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    fn day(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+    }
+
+    fn temp_root() -> PathBuf {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        env::temp_dir().join(format!("bullet-journal-calendar-test-{unique}"))
+    }
+
+    #[test]
+    fn lays_out_months_from_monday() {
+        let picker = CalendarPicker::open(&temp_root(), day(5, 21));
+        let weeks = picker.weeks();
+
+        assert_eq!(weeks.len(), 5);
+        assert_eq!(weeks[0][..4], [None; 4]);
+        assert_eq!(weeks[0][4], Some(day(5, 1)));
+        assert_eq!(weeks[4][6], Some(day(5, 31)));
+    }
+
+    #[test]
+    fn marks_days_with_files_and_open_tasks() -> std::io::Result<()> {
+        let root = temp_root();
+        fs::create_dir_all(&root)?;
+        fs::write(journal_path(&root, day(5, 4)), "  - a note\n")?;
+        fs::write(journal_path(&root, day(5, 5)), "  X done\n  · open\n")?;
+        fs::write(journal_path(&root, day(6, 1)), "  · open\n")?;
+
+        let mut picker = CalendarPicker::open(&root, day(5, 21));
+        assert_eq!(picker.mark(day(5, 4)), Some(DayMark::File));
+        assert_eq!(picker.mark(day(5, 5)), Some(DayMark::OpenTasks));
+        assert_eq!(picker.mark(day(5, 6)), None);
+        assert_eq!(picker.mark(day(6, 1)), None);
+
+        picker.move_months(&root, 1);
+        assert_eq!(picker.selected, day(6, 21));
+        assert_eq!(picker.mark(day(6, 1)), Some(DayMark::OpenTasks));
+
+        picker.move_days(&root, -21);
+        assert_eq!(picker.selected, day(5, 31));
+        assert_eq!(picker.mark(day(5, 5)), Some(DayMark::OpenTasks));
+
+        fs::remove_dir_all(root)
+    }
+}
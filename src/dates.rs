@@ -0,0 +1,292 @@
+//! Parsing for the dates and times users type into commands.
+
+use chrono::{Datelike, Days, NaiveDate, NaiveTime, Weekday};
+
+use crate::journal::TimeRange;
+
+/// A date as the user wrote it, resolved against today when it is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateExpr {
+    /// A number of days from today, for `today`, `tomorrow`, `yesterday`,
+    /// `+3d` and `-1w`.
+    Offset(i64),
+    /// The first matching weekday after today, for `fri`.
+    Weekday(Weekday),
+    /// The matching weekday in the following Monday-based week, for `next fri`.
+    NextWeekday(Weekday),
+    /// The Monday of the following week, for `next week`.
+    NextWeek,
+    /// An ISO date such as `2026-05-21`.
+    Date(NaiveDate),
+}
+
+impl DateExpr {
+    pub fn resolve(self, today: NaiveDate) -> Option<NaiveDate> {
+        let start_of_next_week = || {
+            let days = 7 - u64::from(today.weekday().num_days_from_monday());
+            today.checked_add_days(Days::new(days))
+        };
+
+        match self {
+            Self::Offset(days) if days < 0 => {
+                today.checked_sub_days(Days::new(days.unsigned_abs()))
+            }
+            Self::Offset(days) => today.checked_add_days(Days::new(days.unsigned_abs())),
+            Self::Weekday(weekday) => {
+                let ahead = (weekday.num_days_from_monday() + 7
+                    - today.weekday().num_days_from_monday())
+                    % 7;
+                let ahead = if ahead == 0 { 7 } else { ahead };
+                today.checked_add_days(Days::new(u64::from(ahead)))
+            }
+            Self::NextWeekday(weekday) => start_of_next_week()?
+                .checked_add_days(Days::new(u64::from(weekday.num_days_from_monday()))),
+            Self::NextWeek => start_of_next_week(),
+            Self::Date(date) => Some(date),
+        }
+    }
+}
+
+/// Parses a leading date expression such as `tomorrow`, `next fri`, `+3d` or
+/// `2026-05-21`, returning it with the rest of the input.
+pub fn parse_date_prefix(input: &str) -> Option<(DateExpr, &str)> {
+    let (word, rest) = split_word(input);
+    let lower = word.to_ascii_lowercase();
+
+    if lower == "next" {
+        let (word, rest) = split_word(rest);
+        let lower = word.to_ascii_lowercase();
+        if lower == "week" {
+            return Some((DateExpr::NextWeek, rest));
+        }
+        return Some((DateExpr::NextWeekday(parse_weekday(&lower)?), rest));
+    }
+
+    let date = match lower.as_str() {
+        "today" => DateExpr::Offset(0),
+        "tomorrow" => DateExpr::Offset(1),
+        "yesterday" => DateExpr::Offset(-1),
+        _ => parse_offset(&lower)
+            .or_else(|| parse_weekday(&lower).map(DateExpr::Weekday))
+            .or_else(|| {
+                NaiveDate::parse_from_str(&lower, "%Y-%m-%d")
+                    .ok()
+                    .map(DateExpr::Date)
+            })?,
+    };
+
+    Some((date, rest))
+}
+
+fn split_word(input: &str) -> (&str, &str) {
+    let input = input.trim_start();
+    match input.find(char::is_whitespace) {
+        Some(index) => (&input[..index], input[index..].trim_start()),
+        None => (input, ""),
+    }
+}
+
+fn parse_offset(value: &str) -> Option<DateExpr> {
+    let negative = match value.chars().next()? {
+        '+' => false,
+        '-' => true,
+        _ => return None,
+    };
+    let value = &value[1..];
+    let (unit_start, _) = value.char_indices().next_back()?;
+    let (count, unit) = value.split_at(unit_start);
+    let count = if count.chars().all(|c| c.is_ascii_digit()) {
+        count.parse::<i64>().ok()?
+    } else {
+        return None;
+    };
+    let days = match unit {
+        "d" => count,
+        "w" => count.checked_mul(7)?,
+        _ => return None,
+    };
+
+    Some(DateExpr::Offset(if negative { -days } else { days }))
+}
+
+pub fn parse_weekday(value: &str) -> Option<Weekday> {
+    let weekday = match value {
+        "mon" | "monday" => Weekday::Mon,
+        "tue" | "tues" | "tuesday" => Weekday::Tue,
+        "wed" | "wednesday" => Weekday::Wed,
+        "thu" | "thur" | "thurs" | "thursday" => Weekday::Thu,
+        "fri" | "friday" => Weekday::Fri,
+        "sat" | "saturday" => Weekday::Sat,
+        "sun" | "sunday" => Weekday::Sun,
+        _ => return None,
+    };
+
+    Some(weekday)
+}
+
+/// Parses a leading time or time range such as `14:00`, `9:30am`, `2pm` or
+/// `14:00-15:30`, returning it with the rest of the input.
+pub fn parse_time_range_prefix(input: &str) -> Option<(TimeRange, &str)> {
+    let (token, rest) = split_word(input);
+
+    let (start, end) = match token.split_once('-') {
+        Some((start, end)) => (start, Some(end)),
+        None => (token, None),
+    };
+    let end = match end {
+        Some(end) => Some(parse_time_of_day(end)?),
+        None => None,
+    };
+    let range = TimeRange {
+        start: parse_time_of_day(start)?,
+        end,
+    };
+    if range.end.is_some_and(|end| end < range.start) {
+        return None;
+    }
+
+    Some((range, rest))
+}
+
+/// Parses `H:MM`, `HH:MM`, `Ham`, `H:MMpm` and similar clock times. A bare
+/// number is not a time, so text like `3 people` is left alone.
+pub fn parse_time_of_day(value: &str) -> Option<NaiveTime> {
+    let lower = value.to_ascii_lowercase();
+    let (clock, meridiem) = if let Some(clock) = lower.strip_suffix("am") {
+        (clock, Some(false))
+    } else if let Some(clock) = lower.strip_suffix("pm") {
+        (clock, Some(true))
+    } else {
+        (lower.as_str(), None)
+    };
+
+    let (hour, minute) = match clock.split_once(':') {
+        Some((hour, minute)) if minute.len() == 2 => (hour, minute),
+        Some(_) => return None,
+        None if meridiem.is_some() => (clock, "00"),
+        None => return None,
+    };
+    if hour.is_empty() || hour.len() > 2 {
+        return None;
+    }
+    if !hour.chars().all(|c| c.is_ascii_digit()) || !minute.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let mut hour = hour.parse::<u32>().ok()?;
+    let minute = minute.parse::<u32>().ok()?;
+    if let Some(pm) = meridiem {
+        if !(1..=12).contains(&hour) {
+            return None;
+        }
+        hour = match (hour, pm) {
+            (12, false) => 0,
+            (12, true) => 12,
+            (hour, true) => hour + 12,
+            (hour, false) => hour,
+        };
+    }
+
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    fn day(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+    }
+
+    fn parse_date(input: &str) -> Option<DateExpr> {
+        match parse_date_prefix(input) {
+            Some((date, "")) => Some(date),
+            _ => None,
+        }
+    }
+
+    fn resolve(input: &str) -> Option<NaiveDate> {
+        // 2026-05-21 is a Thursday.
+        parse_date(input)?.resolve(day(5, 21))
+    }
+
+    #[test]
+    fn resolves_relative_dates_against_today() {
+        assert_eq!(resolve("today"), Some(day(5, 21)));
+        assert_eq!(resolve("Tomorrow"), Some(day(5, 22)));
+        assert_eq!(resolve("yesterday"), Some(day(5, 20)));
+        assert_eq!(resolve("+3d"), Some(day(5, 24)));
+        assert_eq!(resolve("-2w"), Some(day(5, 7)));
+        assert_eq!(resolve("2026-06-01"), Some(day(6, 1)));
+    }
+
+    #[test]
+    fn resolves_weekdays_to_upcoming_days() {
+        assert_eq!(resolve("fri"), Some(day(5, 22)));
+        assert_eq!(resolve("thursday"), Some(day(5, 28)));
+        assert_eq!(resolve("mon"), Some(day(5, 25)));
+        assert_eq!(resolve("next fri"), Some(day(5, 29)));
+        assert_eq!(resolve("next mon"), Some(day(5, 25)));
+        assert_eq!(resolve("next week"), Some(day(5, 25)));
+    }
+
+    #[test]
+    fn leaves_text_that_is_not_a_date() {
+        assert_eq!(parse_date("later"), None);
+        assert_eq!(parse_date("next time"), None);
+        assert_eq!(parse_date("+3"), None);
+        assert_eq!(parse_date("3d"), None);
+        assert_eq!(parse_date("2026-13-01"), None);
+        assert_eq!(parse_date("friday night"), None);
+        assert_eq!(parse_date("-→"), None);
+        assert_eq!(parse_date("+3é"), None);
+        assert_eq!(parse_date_prefix("-→ refactor"), None);
+
+        let (date, rest) = parse_date_prefix("next fri 10am offsite").unwrap();
+        assert_eq!(date, DateExpr::NextWeekday(Weekday::Fri));
+        assert_eq!(rest, "10am offsite");
+    }
+
+    #[test]
+    fn parses_clock_times_in_24_and_12_hour_forms() {
+        assert_eq!(parse_time_of_day("14:00"), Some(time(14, 0)));
+        assert_eq!(parse_time_of_day("9:05"), Some(time(9, 5)));
+        assert_eq!(parse_time_of_day("10am"), Some(time(10, 0)));
+        assert_eq!(parse_time_of_day("2:30PM"), Some(time(14, 30)));
+        assert_eq!(parse_time_of_day("12am"), Some(time(0, 0)));
+        assert_eq!(parse_time_of_day("12pm"), Some(time(12, 0)));
+    }
+
+    #[test]
+    fn rejects_numbers_and_invalid_times() {
+        assert_eq!(parse_time_of_day("3"), None);
+        assert_eq!(parse_time_of_day("25:00"), None);
+        assert_eq!(parse_time_of_day("13pm"), None);
+        assert_eq!(parse_time_of_day("9:5"), None);
+        assert_eq!(parse_time_of_day("am"), None);
+        assert_eq!(parse_time_of_day("+9:30"), None);
+        assert_eq!(parse_time_of_day("+2pm"), None);
+        assert_eq!(parse_time_of_day("9:+3"), None);
+    }
+
+    #[test]
+    fn parses_time_range_prefixes() {
+        let (range, rest) = parse_time_range_prefix("14:00-15:00 design review").unwrap();
+        assert_eq!(range.start, time(14, 0));
+        assert_eq!(range.end, Some(time(15, 0)));
+        assert_eq!(rest, "design review");
+
+        let (range, rest) = parse_time_range_prefix("10am offsite").unwrap();
+        assert_eq!(range.start, time(10, 0));
+        assert_eq!(range.end, None);
+        assert_eq!(rest, "offsite");
+
+        assert!(parse_time_range_prefix("3 people").is_none());
+        assert!(parse_time_range_prefix("14:00-later sync").is_none());
+        assert!(parse_time_range_prefix("15:00-14:00 sync").is_none());
+    }
+}
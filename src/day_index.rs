@@ -0,0 +1,149 @@
+//! The days that have a file under the journal root, cached between lookups.
+
+use std::{
+    collections::BTreeSet,
+    fs, io,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use chrono::NaiveDate;
+
+/// Day files found under the journal root. The directory is only scanned
+/// again when its modification time changes, which happens whenever a day
+/// file is created or removed.
+#[derive(Debug, Clone)]
+pub struct DayIndex {
+    root: PathBuf,
+    dates: BTreeSet<NaiveDate>,
+    scanned_at: Option<SystemTime>,
+}
+
+impl DayIndex {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            dates: BTreeSet::new(),
+            scanned_at: None,
+        }
+    }
+
+    /// The latest day before `date` that has a file.
+    pub fn previous(&mut self, date: NaiveDate) -> io::Result<Option<NaiveDate>> {
+        self.refresh()?;
+        Ok(self.dates.range(..date).next_back().copied())
+    }
+
+    /// The earliest day after `date` that has a file.
+    pub fn next(&mut self, date: NaiveDate) -> io::Result<Option<NaiveDate>> {
+        self.refresh()?;
+        Ok(self
+            .dates
+            .range(date.succ_opt().unwrap_or(date)..)
+            .next()
+            .copied())
+    }
+
+    fn refresh(&mut self) -> io::Result<()> {
+        let modified = match fs::metadata(&self.root) {
+            Ok(metadata) => metadata.modified()?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                self.dates.clear();
+                self.scanned_at = None;
+                return Ok(());
+            }
+            Err(error) => return Err(error),
+        };
+
+        if self.scanned_at != Some(modified) {
+            self.dates = scan_day_files(&self.root)?;
+            self.scanned_at = Some(modified);
+        }
+
+        Ok(())
+    }
+}
+
+fn scan_day_files(root: &Path) -> io::Result<BTreeSet<NaiveDate>> {
+    let mut dates = BTreeSet::new();
+
+    for entry in fs::read_dir(root)? {
+        let path = entry?.path();
+        if path.extension().and_then(|extension| extension.to_str()) != Some("md") {
+            continue;
+        }
+        let date = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| NaiveDate::parse_from_str(stem, "%Y-%m-%d").ok());
+        if let Some(date) = date {
+            dates.insert(date);
+        }
+    }
+
+    Ok(dates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{
+        env,
+        time::{Duration, UNIX_EPOCH},
+    };
+
+    fn day(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+    }
+
+    fn temp_root() -> PathBuf {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        env::temp_dir().join(format!("bullet-journal-day-index-test-{unique}"))
+    }
+
+    #[test]
+    fn finds_neighbouring_day_files() -> io::Result<()> {
+        let root = temp_root();
+        let mut index = DayIndex::new(&root);
+        assert_eq!(index.previous(day(5, 21))?, None);
+
+        fs::create_dir_all(&root)?;
+        fs::write(root.join("2026-05-02.md"), "")?;
+        fs::write(root.join("2026-05-21.md"), "")?;
+        fs::write(root.join("2026-06-30.md"), "")?;
+        fs::write(root.join("recurring.md"), "")?;
+        fs::write(root.join("2026-05-10.txt"), "")?;
+
+        assert_eq!(index.previous(day(5, 21))?, Some(day(5, 2)));
+        assert_eq!(index.previous(day(5, 2))?, None);
+        assert_eq!(index.next(day(5, 2))?, Some(day(5, 21)));
+        assert_eq!(index.next(day(5, 22))?, Some(day(6, 30)));
+        assert_eq!(index.next(day(6, 30))?, None);
+
+        fs::remove_dir_all(root)
+    }
+
+    #[test]
+    fn rescans_only_when_the_directory_changes() -> io::Result<()> {
+        let root = temp_root();
+        fs::create_dir_all(&root)?;
+        fs::write(root.join("2026-05-02.md"), "")?;
+        let mut index = DayIndex::new(&root);
+        assert_eq!(index.next(day(5, 1))?, Some(day(5, 2)));
+
+        // Put the old modification time back so the new file goes unnoticed.
+        let modified = fs::metadata(&root)?.modified()?;
+        fs::write(root.join("2026-05-01.md"), "")?;
+        fs::File::open(&root)?.set_modified(modified)?;
+        assert_eq!(index.previous(day(5, 2))?, None);
+
+        fs::File::open(&root)?.set_modified(modified + Duration::from_secs(1))?;
+        assert_eq!(index.previous(day(5, 2))?, Some(day(5, 1)));
+
+        fs::remove_dir_all(root)
+    }
+}
@@ -0,0 +1,162 @@
+//! The inline Markdown that entry text may contain: bold, italic, code, links
+//! and strikethrough. Only the display uses it; day files keep the Markdown.
+
+/// A run of entry text and how it is marked up. Markup does not nest, so the
+/// text inside a span is shown as written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Inline {
+    Text(String),
+    Bold(String),
+    Italic(String),
+    Code(String),
+    Strikethrough(String),
+    Link { label: String, url: String },
+}
+
+/// Splits entry text into plain and marked-up runs. Markers without a match,
+/// such as a lone `*` or the `_` in `snake_case`, stay plain text.
+pub fn parse_inline(text: &str) -> Vec<Inline> {
+    let mut runs = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text;
+
+    while let Some(character) = rest.chars().next() {
+        let previous = plain.chars().next_back();
+        if let Some((run, length)) = markup_at(rest, previous) {
+            if !plain.is_empty() {
+                runs.push(Inline::Text(std::mem::take(&mut plain)));
+            }
+            runs.push(run);
+            rest = &rest[length..];
+        } else {
+            plain.push(character);
+            rest = &rest[character.len_utf8()..];
+        }
+    }
+
+    if !plain.is_empty() {
+        runs.push(Inline::Text(plain));
+    }
+    runs
+}
+
+/// The marked-up run at the start of `text` and how many bytes it takes.
+/// `previous` is the character before it, so `_` inside a word is left alone.
+fn markup_at(text: &str, previous: Option<char>) -> Option<(Inline, usize)> {
+    if let Some(rest) = text.strip_prefix('`') {
+        let end = rest.find('`').filter(|&end| end > 0)?;
+        return Some((Inline::Code(rest[..end].to_string()), end + 2));
+    }
+    if text.starts_with('[') {
+        return link_at(text);
+    }
+
+    for (marker, run) in [
+        ("**", Inline::Bold as fn(String) -> Inline),
+        ("__", Inline::Bold),
+        ("~~", Inline::Strikethrough),
+        ("*", Inline::Italic),
+        ("_", Inline::Italic),
+    ] {
+        if !text.starts_with(marker) {
+            continue;
+        }
+        if marker.starts_with('_') && previous.is_some_and(char::is_alphanumeric) {
+            return None;
+        }
+        return delimited(text, marker).map(|(inner, length)| (run(inner.to_string()), length));
+    }
+
+    None
+}
+
+/// The text between `marker` and its closing match. The text may not start
+/// or end with a space, so `2 * 3 * 4` is not italic.
+fn delimited<'a>(text: &'a str, marker: &str) -> Option<(&'a str, usize)> {
+    let rest = &text[marker.len()..];
+    let end = rest.find(marker)?;
+    let inner = &rest[..end];
+    let after = rest[end + marker.len()..].chars().next();
+    let ends_inside_word = marker.starts_with('_') && after.is_some_and(char::is_alphanumeric);
+    let valid = !inner.is_empty()
+        && !inner.starts_with(char::is_whitespace)
+        && !inner.ends_with(char::is_whitespace)
+        && !ends_inside_word;
+    valid.then_some((inner, end + marker.len() * 2))
+}
+
+fn link_at(text: &str) -> Option<(Inline, usize)> {
+    let label_end = text.find("](")?;
+    let label = &text[1..label_end];
+    let rest = &text[label_end + 2..];
+    let url_end = rest.find(')')?;
+    let url = &rest[..url_end];
+    if label.is_empty() || label.contains(['[', ']']) || url.is_empty() {
+        return None;
+    }
+    if url.contains(char::is_whitespace) {
+        return None;
+    }
+
+    Some((
+        Inline::Link {
+            label: label.to_string(),
+            url: url.to_string(),
+        },
+        label_end + 2 + url_end + 1,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(text: &str) -> Inline {
+        Inline::Text(text.to_string())
+    }
+
+    #[test]
+    fn parses_bold_italic_code_links_and_strikethrough() {
+        assert_eq!(
+            parse_inline(
+                "ship **v2** with `cargo run`, see [spec](https://example.com/a) ~~not~~ _now_"
+            ),
+            [
+                text("ship "),
+                Inline::Bold(String::from("v2")),
+                text(" with "),
+                Inline::Code(String::from("cargo run")),
+                text(", see "),
+                Inline::Link {
+                    label: String::from("spec"),
+                    url: String::from("https://example.com/a"),
+                },
+                text(" "),
+                Inline::Strikethrough(String::from("not")),
+                text(" "),
+                Inline::Italic(String::from("now")),
+            ]
+        );
+        assert_eq!(
+            parse_inline("*a `b` c*"),
+            [Inline::Italic(String::from("a `b` c"))]
+        );
+    }
+
+    #[test]
+    fn leaves_unmatched_markers_as_text() {
+        for plain in [
+            "2 * 3 * 4",
+            "snake_case_name",
+            "a lone ` backtick",
+            "``",
+            "[not a link] (here)",
+            "[label](has space)",
+            "**",
+            "~~ spaced ~~",
+        ] {
+            assert_eq!(parse_inline(plain), [text(plain)], "{plain}");
+        }
+        assert!(parse_inline("").is_empty());
+    }
+}
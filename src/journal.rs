@@ -1,9 +1,16 @@
 use std::{
-    fs, io,
+    borrow::Cow,
+    fmt, fs, io,
     path::{Path, PathBuf},
 };
 
-use chrono::{Datelike, NaiveDate, Weekday};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
+
+use crate::{
+    cache::{file_stamp, FileStamp},
+    notes::is_note_name,
+    recurring::RecurringRules,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EntryKind {
@@ -19,17 +26,105 @@ pub enum EntryState {
     Open,
     Completed,
     Cancelled,
+    /// A task moved to another day. The original stays as a record.
+    Migrated,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StateChange {
+    pub state: EntryState,
+    pub at: NaiveDateTime,
+}
+
+/// The time of day an event happens. Events without an end are points in
+/// time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TimeRange {
+    pub start: NaiveTime,
+    pub end: Option<NaiveTime>,
+}
+
+impl TimeRange {
+    /// Parses the canonical `HH:MM` or `HH:MM-HH:MM` form written to Markdown,
+    /// followed by a space, from the start of `text`.
+    pub fn parse_prefix(text: &str) -> Option<(Self, &str)> {
+        let (range, rest) = text.split_once(' ')?;
+        let (start, end) = match range.split_once('-') {
+            Some((start, end)) => (start, Some(end)),
+            None => (range, None),
+        };
+
+        let end = match end {
+            Some(end) => Some(parse_clock_time(end)?),
+            None => None,
+        };
+        let time = Self {
+            start: parse_clock_time(start)?,
+            end,
+        };
+        Some((time, rest))
+    }
+
+    /// The end of the range, or the start for point-in-time events.
+    pub fn end_or_start(&self) -> NaiveTime {
+        self.end.unwrap_or(self.start)
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+impl fmt::Display for TimeRange {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}", self.start.format("%H:%M"))?;
+        if let Some(end) = self.end {
+            write!(formatter, "-{}", end.format("%H:%M"))?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_clock_time(value: &str) -> Option<NaiveTime> {
+    let (hour, minute) = value.split_once(':')?;
+    if hour.len() != 2 || minute.len() != 2 {
+        return None;
+    }
+    NaiveTime::parse_from_str(value, "%H:%M").ok()
+}
+
+#[derive(Debug, Clone)]
 pub struct JournalEntry {
     pub kind: EntryKind,
     pub text: String,
     pub state: EntryState,
     pub important: bool,
     pub created_on: NaiveDate,
+    pub time: Option<TimeRange>,
+    pub created_at: Option<NaiveDateTime>,
+    pub state_changes: Vec<StateChange>,
+    /// The id of the recurring rule this entry was added from.
+    pub recurrence: Option<String>,
+    /// The name of the detail note in `notes/` that holds the entry's body.
+    pub note: Option<String>,
+    /// The exact line this entry was parsed from, kept so untouched lines are
+    /// saved byte-for-byte. It is not part of the entry's identity.
+    source: Option<String>,
 }
 
+impl PartialEq for JournalEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+            && self.text == other.text
+            && self.state == other.state
+            && self.important == other.important
+            && self.created_on == other.created_on
+            && self.time == other.time
+            && self.created_at == other.created_at
+            && self.state_changes == other.state_changes
+            && self.recurrence == other.recurrence
+            && self.note == other.note
+    }
+}
+
+impl Eq for JournalEntry {}
+
 impl JournalEntry {
     pub fn new(kind: EntryKind, text: impl Into<String>, created_on: NaiveDate) -> Self {
         Self {
@@ -38,6 +133,12 @@ impl JournalEntry {
             state: EntryState::Open,
             important: false,
             created_on,
+            time: None,
+            created_at: None,
+            state_changes: Vec::new(),
+            recurrence: None,
+            note: None,
+            source: None,
         }
     }
 
@@ -48,6 +149,12 @@ impl JournalEntry {
             state: EntryState::Open,
             important: false,
             created_on,
+            time: None,
+            created_at: None,
+            state_changes: Vec::new(),
+            recurrence: None,
+            note: None,
+            source: None,
         }
     }
 }
@@ -60,11 +167,17 @@ impl JournalEntry {
             EntryKind::Event => format!("◦ {}", self.render_text()),
             EntryKind::Feeling => format!("= {}", self.text),
             EntryKind::Task => match self.state {
-                EntryState::Completed => format!("X {}", self.text),
+                EntryState::Completed => {
+                    format!("X {}", escape_text(&self.text, starts_with_strikethrough))
+                }
+                EntryState::Migrated => {
+                    format!("→ {}", escape_text(&self.text, starts_with_strikethrough))
+                }
                 EntryState::Open | EntryState::Cancelled => format!("· {}", self.render_text()),
             },
-            EntryKind::Raw => self.text.clone(),
+            EntryKind::Raw => escape_text(&self.text, starts_with_entry_signifier).into_owned(),
         };
+        let line = self.append_metadata(line);
 
         if self.important {
             format!("* {line}")
@@ -73,15 +186,73 @@ impl JournalEntry {
         }
     }
 
+    /// Whether the entry was read from its day file, rather than added since
+    /// it was last read.
+    pub fn read_from_file(&self) -> bool {
+        self.source.is_some()
+    }
+
+    /// Returns the line to persist: the original source line when the entry
+    /// still matches it, otherwise the canonical Markdown rendering.
+    pub fn persisted_line(&self) -> Cow<'_, str> {
+        match &self.source {
+            Some(source) if parse_markdown_line(source, self.created_on) == *self => {
+                Cow::Borrowed(source)
+            }
+            _ => Cow::Owned(self.to_markdown_line()),
+        }
+    }
+
     pub fn is_struck(&self) -> bool {
         matches!(self.state, EntryState::Cancelled)
     }
 
+    /// Appends timestamps, the recurring rule id and the detail note as a
+    /// trailing HTML comment. Lines that already end like a comment get an
+    /// empty one so the parser never mistakes the text for metadata.
+    fn append_metadata(&self, line: String) -> String {
+        if self.kind == EntryKind::Raw {
+            return line;
+        }
+
+        let mut fields = Vec::new();
+        if let Some(created_at) = self.created_at {
+            fields.push(format!("created {}", format_timestamp(created_at)));
+        }
+        if let Some(recurrence) = &self.recurrence {
+            fields.push(format!("recur {recurrence}"));
+        }
+        if let Some(note) = &self.note {
+            fields.push(format!("note {note}"));
+        }
+        for change in &self.state_changes {
+            fields.push(format!(
+                "{} {}",
+                state_change_label(change.state),
+                format_timestamp(change.at)
+            ));
+        }
+
+        if !fields.is_empty() {
+            format!("{line} <!-- {} -->", fields.join(" "))
+        } else if line.ends_with("-->") {
+            format!("{line} <!-- -->")
+        } else {
+            line
+        }
+    }
+
     fn render_text(&self) -> String {
+        let text = escape_text(&self.text, text_markup(self.kind));
+        let body = match self.time {
+            Some(time) if self.kind == EntryKind::Event => format!("{time} {text}"),
+            _ => text.into_owned(),
+        };
+
         if self.is_struck() {
-            format!("~~{}~~", self.text)
+            format!("~~{body}~~")
         } else {
-            self.text.clone()
+            body
         }
     }
 }
@@ -92,6 +263,9 @@ impl JournalEntry {
         if self.kind != EntryKind::Task {
             return Err("Only tasks can be completed.");
         }
+        if self.state == EntryState::Migrated {
+            return Err("Migrated tasks cannot be completed.");
+        }
 
         self.state = if self.state == EntryState::Completed {
             EntryState::Open
@@ -110,6 +284,9 @@ impl JournalEntry {
             EntryKind::Task if self.state == EntryState::Completed => {
                 Err("Completed tasks cannot be cancelled.")
             }
+            EntryKind::Task if self.state == EntryState::Migrated => {
+                Err("Migrated tasks cannot be cancelled.")
+            }
             EntryKind::Task | EntryKind::Event => {
                 self.state = if self.state == EntryState::Cancelled {
                     EntryState::Open
@@ -126,6 +303,34 @@ impl JournalEntry {
         }
     }
 
+    /// Marks an open task as moved to another day.
+    pub fn migrate(&mut self) -> Result<&'static str, &'static str> {
+        match (self.kind, self.state) {
+            (EntryKind::Task, EntryState::Open) => {
+                self.state = EntryState::Migrated;
+                Ok("Task migrated.")
+            }
+            (EntryKind::Task, _) => Err("Only open tasks can be migrated."),
+            _ => Err("Only tasks can be migrated."),
+        }
+    }
+
+    pub fn stamp_created(&mut self, at: NaiveDateTime) {
+        self.created_at = Some(minute_precision(at));
+    }
+
+    /// Records the time the entry moved into its current state.
+    pub fn record_state_change(&mut self, at: NaiveDateTime) {
+        self.state_changes.push(StateChange {
+            state: self.state,
+            at: minute_precision(at),
+        });
+    }
+
+    pub fn last_state_change(&self) -> Option<&StateChange> {
+        self.state_changes.last()
+    }
+
     pub fn toggle_important(&mut self) -> &'static str {
         self.important = !self.important;
 
@@ -142,38 +347,92 @@ pub struct Journal {
     pub date: NaiveDate,
     pub entries: Vec<JournalEntry>,
     path: PathBuf,
+    line_ending: &'static str,
+    final_newline: bool,
+    /// The file's stamp when it was last read or written.
+    stamp: FileStamp,
 }
 
 impl Journal {
+    /// Reads the day file alone. Days that are shown or written come from
+    /// `load_for_date_with_rules`, which also adds recurring occurrences.
     pub fn load_for_date(root: &Path, date: NaiveDate) -> io::Result<Self> {
-        let path = root.join(format!("{}.md", date.format("%Y-%m-%d")));
-        let entries = if path.exists() {
-            let contents = fs::read_to_string(&path)?;
-            parse_markdown(&contents, date)
-        } else {
-            Vec::new()
-        };
-
-        Ok(Self {
+        let path = journal_path(root, date);
+        // The stamp is read first, so a file that changes while it is being
+        // parsed still counts as changed afterwards.
+        let stamp = file_stamp(&path)?;
+        let mut journal = Self {
             date,
-            entries,
+            entries: Vec::new(),
             path,
-        })
+            line_ending: "\n",
+            final_newline: true,
+            stamp,
+        };
+
+        if journal.path.exists() {
+            let contents = fs::read_to_string(&journal.path)?;
+            journal.entries = parse_markdown(&contents, date);
+            journal.line_ending = detect_line_ending(&contents);
+            journal.final_newline = contents.is_empty() || contents.ends_with('\n');
+        }
+
+        Ok(journal)
+    }
+
+    /// Reads a day and adds one occurrence of every rule that falls on it and
+    /// is not in the file yet.
+    pub fn load_for_date_with_rules(
+        root: &Path,
+        date: NaiveDate,
+        rules: &RecurringRules,
+    ) -> io::Result<Self> {
+        Self::load_for_date(root, date).map(|journal| journal.with_rules(rules))
+    }
+
+    /// Adds the occurrences of `rules` that fall on this day and are missing.
+    pub fn with_rules(mut self, rules: &RecurringRules) -> Self {
+        rules.apply(&mut self);
+        self
     }
 }
 
 // Journal mutation and persistence.
 impl Journal {
-    pub fn add_entry(&mut self, kind: EntryKind, text: impl Into<String>) {
+    pub fn add_entry(&mut self, kind: EntryKind, text: impl Into<String>) -> &mut JournalEntry {
         self.entries.push(JournalEntry::new(kind, text, self.date));
+        self.entries.last_mut().expect("entry was just added")
+    }
+
+    /// Timed events ordered by start time, then end time.
+    pub fn timed_events(&self) -> Vec<&JournalEntry> {
+        let mut events = self
+            .entries
+            .iter()
+            .filter(|entry| entry.kind == EntryKind::Event && entry.time.is_some())
+            .collect::<Vec<_>>();
+        events.sort_by_key(|entry| entry.time);
+        events
     }
 
-    pub fn save(&self) -> io::Result<()> {
+    pub fn save(&mut self) -> io::Result<()> {
         if let Some(parent) = self.path.parent() {
             fs::create_dir_all(parent)?;
         }
 
-        fs::write(&self.path, self.to_markdown())
+        fs::write(&self.path, self.to_markdown())?;
+        self.stamp = file_stamp(&self.path)?;
+        Ok(())
+    }
+
+    /// Removes the day file, if there is one.
+    pub fn remove_file(&mut self) -> io::Result<()> {
+        match fs::remove_file(&self.path) {
+            Err(error) if error.kind() != io::ErrorKind::NotFound => return Err(error),
+            _ => {}
+        }
+        self.stamp = None;
+        Ok(())
     }
 
     pub fn to_markdown(&self) -> String {
@@ -184,10 +443,12 @@ impl Journal {
         let mut markdown = self
             .entries
             .iter()
-            .map(JournalEntry::to_markdown_line)
+            .map(JournalEntry::persisted_line)
             .collect::<Vec<_>>()
-            .join("\n");
-        markdown.push('\n');
+            .join(self.line_ending);
+        if self.final_newline {
+            markdown.push_str(self.line_ending);
+        }
         markdown
     }
 }
@@ -198,18 +459,46 @@ impl Journal {
         &self.path
     }
 
+    /// The size and modification time of the file as this copy last read or
+    /// wrote it.
+    pub fn stamp(&self) -> FileStamp {
+        self.stamp
+    }
+
+    /// Whether the file was changed by someone else since this copy last read
+    /// or wrote it.
+    pub fn changed_on_disk(&self) -> io::Result<bool> {
+        Ok(file_stamp(&self.path)? != self.stamp)
+    }
+
     pub fn title(&self) -> String {
         format_journal_title(self.date)
     }
 }
 
+/// The day file for `date` under the journal root.
+pub fn journal_path(root: &Path, date: NaiveDate) -> PathBuf {
+    root.join(format!("{}.md", date.format("%Y-%m-%d")))
+}
+
 pub fn parse_markdown(contents: &str, date: NaiveDate) -> Vec<JournalEntry> {
     contents
         .lines()
-        .map(|line| parse_markdown_line(line, date))
+        .map(|line| {
+            let mut entry = parse_markdown_line(line, date);
+            entry.source = Some(line.to_string());
+            entry
+        })
         .collect()
 }
 
+fn detect_line_ending(contents: &str) -> &'static str {
+    match contents.find('\n') {
+        Some(index) if contents[..index].ends_with('\r') => "\r\n",
+        _ => "\n",
+    }
+}
+
 pub fn parse_markdown_line(line: &str, date: NaiveDate) -> JournalEntry {
     let (important, line) = match line.strip_prefix("* ") {
         Some(rest) => (true, rest),
@@ -219,49 +508,146 @@ pub fn parse_markdown_line(line: &str, date: NaiveDate) -> JournalEntry {
         },
     };
 
-    if let Some(rest) = line.strip_prefix("- ") {
-        let mut entry = JournalEntry::new(EntryKind::Note, rest, date);
+    let Some(mut entry) = parse_entry_body(line, date) else {
+        let mut entry = JournalEntry::raw(unescape_text(line, starts_with_entry_signifier), date);
         entry.important = important;
         return entry;
-    }
+    };
 
-    if let Some(rest) = line.strip_prefix("◦ ") {
-        let (cancelled, text) = unwrap_strikethrough(rest);
-        let mut entry = JournalEntry::new(EntryKind::Event, text, date);
-        entry.important = important;
-        if cancelled {
-            entry.state = EntryState::Cancelled;
+    entry.important = important;
+    entry
+}
+
+fn parse_entry_body(line: &str, date: NaiveDate) -> Option<JournalEntry> {
+    let (kind, rest) = [
+        ("- ", EntryKind::Note),
+        ("◦ ", EntryKind::Event),
+        ("= ", EntryKind::Feeling),
+        ("· ", EntryKind::Task),
+        ("X ", EntryKind::Task),
+        ("→ ", EntryKind::Task),
+    ]
+    .into_iter()
+    .find_map(|(signifier, kind)| line.strip_prefix(signifier).map(|rest| (kind, rest)))?;
+    let (rest, metadata) = split_metadata(rest);
+
+    let mut entry = match kind {
+        EntryKind::Note | EntryKind::Feeling => JournalEntry::new(kind, rest, date),
+        _ => {
+            let (cancelled, body) = unwrap_strikethrough(rest);
+            let (time, text) = match TimeRange::parse_prefix(body) {
+                Some((time, text)) if kind == EntryKind::Event => (Some(time), text),
+                _ => (None, body),
+            };
+            let mut entry = JournalEntry::new(kind, unescape_text(text, text_markup(kind)), date);
+            entry.time = time;
+            if line.starts_with("X ") {
+                entry.state = EntryState::Completed;
+            } else if line.starts_with("→ ") {
+                entry.state = EntryState::Migrated;
+            } else if cancelled {
+                entry.state = EntryState::Cancelled;
+            }
+            entry
         }
-        return entry;
+    };
+
+    if let Some(metadata) = metadata {
+        entry.created_at = metadata.created_at;
+        entry.state_changes = metadata.state_changes;
+        entry.recurrence = metadata.recurrence;
+        entry.note = metadata.note;
     }
+    Some(entry)
+}
 
-    if let Some(rest) = line.strip_prefix("= ") {
-        let mut entry = JournalEntry::new(EntryKind::Feeling, rest, date);
-        entry.important = important;
-        return entry;
+#[derive(Debug, Default)]
+struct EntryMetadata {
+    created_at: Option<NaiveDateTime>,
+    state_changes: Vec<StateChange>,
+    recurrence: Option<String>,
+    note: Option<String>,
+}
+
+/// Splits a trailing `<!-- ... -->` metadata comment from an entry body.
+/// Comments that do not hold valid metadata are left as entry text.
+fn split_metadata(body: &str) -> (&str, Option<EntryMetadata>) {
+    let Some(without_close) = body.strip_suffix("-->") else {
+        return (body, None);
+    };
+    let Some(open) = without_close.rfind(" <!--") else {
+        return (body, None);
+    };
+
+    match parse_metadata(&without_close[open + " <!--".len()..]) {
+        Some(metadata) => (&body[..open], Some(metadata)),
+        None => (body, None),
     }
+}
 
-    if let Some(rest) = line.strip_prefix("· ") {
-        let (cancelled, text) = unwrap_strikethrough(rest);
-        let mut entry = JournalEntry::new(EntryKind::Task, text, date);
-        entry.important = important;
-        if cancelled {
-            entry.state = EntryState::Cancelled;
+fn parse_metadata(fields: &str) -> Option<EntryMetadata> {
+    let mut metadata = EntryMetadata::default();
+    let mut tokens = fields.split_whitespace();
+
+    while let Some(label) = tokens.next() {
+        let value = tokens.next()?;
+        match label {
+            "created" if metadata.created_at.is_none() && metadata.state_changes.is_empty() => {
+                metadata.created_at = Some(parse_timestamp(value)?);
+            }
+            "recur" if metadata.recurrence.is_none() && metadata.state_changes.is_empty() => {
+                metadata.recurrence = Some(value.to_string());
+            }
+            "note" if metadata.note.is_none() && metadata.state_changes.is_empty() => {
+                if !is_note_name(value) {
+                    return None;
+                }
+                metadata.note = Some(value.to_string());
+            }
+            _ => metadata.state_changes.push(StateChange {
+                state: state_change_from_label(label)?,
+                at: parse_timestamp(value)?,
+            }),
         }
-        return entry;
     }
 
-    if let Some(rest) = line.strip_prefix("X ") {
-        let (_, text) = unwrap_strikethrough(rest);
-        let mut entry = JournalEntry::new(EntryKind::Task, text, date);
-        entry.important = important;
-        entry.state = EntryState::Completed;
-        return entry;
+    Some(metadata)
+}
+
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M";
+
+fn format_timestamp(at: NaiveDateTime) -> String {
+    at.format(TIMESTAMP_FORMAT).to_string()
+}
+
+fn parse_timestamp(value: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(value, TIMESTAMP_FORMAT).ok()
+}
+
+fn minute_precision(at: NaiveDateTime) -> NaiveDateTime {
+    at.with_second(0)
+        .and_then(|at| at.with_nanosecond(0))
+        .unwrap_or(at)
+}
+
+/// The word a state change is written with, both in day files and on screen.
+pub(crate) fn state_change_label(state: EntryState) -> &'static str {
+    match state {
+        EntryState::Open => "reopened",
+        EntryState::Completed => "completed",
+        EntryState::Cancelled => "cancelled",
+        EntryState::Migrated => "migrated",
     }
+}
 
-    let mut entry = JournalEntry::raw(line, date);
-    entry.important = important;
-    entry
+fn state_change_from_label(label: &str) -> Option<EntryState> {
+    match label {
+        "reopened" => Some(EntryState::Open),
+        "completed" => Some(EntryState::Completed),
+        "cancelled" => Some(EntryState::Cancelled),
+        "migrated" => Some(EntryState::Migrated),
+        _ => None,
+    }
 }
 
 pub fn format_journal_title(date: NaiveDate) -> String {
@@ -285,18 +671,62 @@ fn weekday_label(weekday: Weekday) -> &'static str {
     }
 }
 
-fn unwrap_strikethrough(text: &str) -> (bool, String) {
-    let trimmed = text.trim();
-    if trimmed.starts_with("~~") && trimmed.ends_with("~~") && trimmed.len() >= 4 {
-        (true, trimmed[2..trimmed.len() - 2].to_string())
+fn unwrap_strikethrough(text: &str) -> (bool, &str) {
+    if text.starts_with("~~") && text.ends_with("~~") && text.len() >= 4 {
+        (true, &text[2..text.len() - 2])
+    } else {
+        (false, text)
+    }
+}
+
+const ENTRY_SIGNIFIERS: [&str; 6] = ["- ", "◦ ", "= ", "· ", "X ", "→ "];
+
+fn starts_with_entry_signifier(text: &str) -> bool {
+    ENTRY_SIGNIFIERS
+        .iter()
+        .any(|signifier| text.starts_with(signifier))
+}
+
+fn starts_with_strikethrough(text: &str) -> bool {
+    text.starts_with("~~")
+}
+
+fn starts_with_event_markup(text: &str) -> bool {
+    starts_with_strikethrough(text) || TimeRange::parse_prefix(text).is_some()
+}
+
+fn text_markup(kind: EntryKind) -> fn(&str) -> bool {
+    match kind {
+        EntryKind::Event => starts_with_event_markup,
+        _ => starts_with_strikethrough,
+    }
+}
+
+/// Prefixes `text` with a backslash when it would otherwise be read back as
+/// markup. Text that already starts with a backslash is escaped too, so the
+/// escape itself round-trips.
+fn escape_text(text: &str, is_markup: fn(&str) -> bool) -> Cow<'_, str> {
+    if text.starts_with('\\') || is_markup(text) {
+        Cow::Owned(format!("\\{text}"))
     } else {
-        (false, trimmed.to_string())
+        Cow::Borrowed(text)
+    }
+}
+
+/// Reverses `escape_text`. A backslash that does not guard markup or another
+/// backslash is ordinary text and is kept.
+fn unescape_text(text: &str, is_markup: fn(&str) -> bool) -> &str {
+    match text.strip_prefix('\\') {
+        Some(rest) if rest.starts_with('\\') || is_markup(rest) => rest,
+        _ => text,
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Days;
+    use proptest::prelude::*;
     use std::{
         env, fs,
         time::{SystemTime, UNIX_EPOCH},
@@ -430,26 +860,77 @@ mod tests {
         assert_eq!(entries[5].text, "raw line");
     }
 
-    #[test]
-    fn normalizes_legacy_completed_task_markdown_on_save() -> io::Result<()> {
+    fn temp_root() -> PathBuf {
         let unique = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
             .as_nanos();
-        let root = env::temp_dir().join(format!("bullet-journal-tui-test-{unique}"));
+        env::temp_dir().join(format!("bullet-journal-tui-test-{unique}"))
+    }
+
+    #[test]
+    fn normalizes_legacy_completed_task_markdown_when_entry_changes() -> io::Result<()> {
+        let root = temp_root();
         fs::create_dir_all(&root)?;
         fs::write(
             root.join("2026-05-21.md"),
             "X ~~done~~\n· ~~cancelled task~~\n◦ ~~cancelled event~~\n",
         )?;
 
-        let journal = Journal::load_for_date(&root, date())?;
+        let mut journal = Journal::load_for_date(&root, date())?;
+        journal.entries[0].toggle_important();
         journal.save()?;
 
         let saved = fs::read_to_string(root.join("2026-05-21.md"))?;
         assert_eq!(
             saved,
-            "  X done\n  · ~~cancelled task~~\n  ◦ ~~cancelled event~~\n"
+            "* X done\n· ~~cancelled task~~\n◦ ~~cancelled event~~\n"
+        );
+
+        fs::remove_dir_all(root)?;
+        Ok(())
+    }
+
+    #[test]
+    fn round_trips_untouched_corpus_files_byte_for_byte() -> io::Result<()> {
+        let corpus = Path::new(env!("CARGO_MANIFEST_DIR")).join("testdata/round_trip");
+        let mut checked = 0;
+
+        for file in fs::read_dir(corpus)? {
+            let original = fs::read_to_string(file?.path())?;
+            let root = temp_root();
+            fs::create_dir_all(&root)?;
+            fs::write(root.join("2026-05-21.md"), &original)?;
+
+            let mut journal = Journal::load_for_date(&root, date())?;
+            journal.save()?;
+
+            assert_eq!(fs::read_to_string(root.join("2026-05-21.md"))?, original);
+            fs::remove_dir_all(root)?;
+            checked += 1;
+        }
+
+        assert!(checked > 0);
+        Ok(())
+    }
+
+    #[test]
+    fn rewrites_only_changed_lines() -> io::Result<()> {
+        let root = temp_root();
+        fs::create_dir_all(&root)?;
+        fs::write(
+            root.join("2026-05-21.md"),
+            "# Log\r\n\r\n- first\r\n·   spaced task\r\n    indented raw",
+        )?;
+
+        let mut journal = Journal::load_for_date(&root, date())?;
+        journal.entries[3].toggle_complete().unwrap();
+        journal.add_entry(EntryKind::Note, "appended");
+        journal.save()?;
+
+        assert_eq!(
+            fs::read_to_string(root.join("2026-05-21.md"))?,
+            "# Log\r\n\r\n- first\r\n  X   spaced task\r\n    indented raw\r\n  - appended"
         );
 
         fs::remove_dir_all(root)?;
@@ -503,11 +984,7 @@ mod tests {
 
     #[test]
     fn persists_journal_file_after_changes() -> io::Result<()> {
-        let unique = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_nanos();
-        let root = env::temp_dir().join(format!("bullet-journal-tui-test-{unique}"));
+        let root = temp_root();
 
         let mut journal = Journal::load_for_date(&root, date())?;
         journal.add_entry(EntryKind::Task, "persist this");
@@ -519,4 +996,306 @@ mod tests {
         fs::remove_dir_all(root)?;
         Ok(())
     }
+
+    #[test]
+    fn escapes_text_that_looks_like_markup() {
+        let mut task = JournalEntry::new(EntryKind::Task, "~~draft~~", date());
+        assert_eq!(task.to_markdown_line(), "  · \\~~draft~~");
+        task.state = EntryState::Cancelled;
+        assert_eq!(task.to_markdown_line(), "  · ~~\\~~draft~~~~");
+
+        let raw = JournalEntry::raw("- not a note", date());
+        assert_eq!(raw.to_markdown_line(), "  \\- not a note");
+
+        let raw = JournalEntry::raw("· not a task", date());
+        assert_eq!(raw.to_markdown_line(), "  \\· not a task");
+
+        let note = JournalEntry::new(EntryKind::Note, "~~draft~~", date());
+        assert_eq!(note.to_markdown_line(), "  - ~~draft~~");
+    }
+
+    #[test]
+    fn keeps_unneeded_backslashes_in_hand_written_lines() {
+        let raw = parse_markdown_line("\\begin{document}", date());
+        assert_eq!(raw.kind, EntryKind::Raw);
+        assert_eq!(raw.text, "\\begin{document}");
+
+        let task = parse_markdown_line("· C:\\notes", date());
+        assert_eq!(task.text, "C:\\notes");
+    }
+
+    fn entry_text() -> impl Strategy<Value = String> {
+        let markup = prop_oneof![
+            Just("- "),
+            Just("◦ "),
+            Just("= "),
+            Just("· "),
+            Just("X "),
+            Just("→ "),
+            Just("* "),
+            Just("  "),
+            Just("~~"),
+            Just("\\"),
+            Just("draft"),
+            Just(" <!--"),
+            Just("-->"),
+            Just(" <!-- -->"),
+            Just(" <!-- created 2026-05-21T09:14 -->"),
+            Just("14:00 "),
+            Just("09:30-10:00 "),
+        ];
+
+        prop_oneof![
+            proptest::collection::vec(markup, 0..6).prop_map(|pieces| pieces.concat()),
+            "\\PC{0,24}",
+        ]
+    }
+
+    fn journal_entry() -> impl Strategy<Value = JournalEntry> {
+        let kind_and_state = prop_oneof![
+            Just((EntryKind::Note, EntryState::Open)),
+            Just((EntryKind::Event, EntryState::Open)),
+            Just((EntryKind::Event, EntryState::Cancelled)),
+            Just((EntryKind::Feeling, EntryState::Open)),
+            Just((EntryKind::Task, EntryState::Open)),
+            Just((EntryKind::Task, EntryState::Completed)),
+            Just((EntryKind::Task, EntryState::Cancelled)),
+            Just((EntryKind::Task, EntryState::Migrated)),
+            Just((EntryKind::Raw, EntryState::Open)),
+        ];
+
+        let state = prop_oneof![
+            Just(EntryState::Open),
+            Just(EntryState::Completed),
+            Just(EntryState::Cancelled),
+            Just(EntryState::Migrated),
+        ];
+        let state_changes = proptest::collection::vec((state, timestamp()), 0..3);
+
+        (
+            kind_and_state,
+            entry_text(),
+            any::<bool>(),
+            proptest::option::of(timestamp()),
+            state_changes,
+            proptest::option::of(time_range()),
+            proptest::option::of("r[0-9]{1,3}"),
+            proptest::option::of("2026-05-2[0-9]-[0-9]{1,2}"),
+        )
+            .prop_map(
+                |(
+                    (kind, state),
+                    text,
+                    important,
+                    created_at,
+                    state_changes,
+                    time,
+                    recurrence,
+                    note,
+                )| {
+                    let mut entry = JournalEntry::new(kind, text, date());
+                    entry.state = state;
+                    entry.important = important;
+                    if kind == EntryKind::Event {
+                        entry.time = time;
+                    }
+                    if kind != EntryKind::Raw {
+                        entry.recurrence = recurrence;
+                        entry.note = note;
+                        entry.created_at = created_at;
+                        entry.state_changes = state_changes
+                            .into_iter()
+                            .map(|(state, at)| StateChange { state, at })
+                            .collect();
+                    }
+                    entry
+                },
+            )
+    }
+
+    fn timestamp() -> impl Strategy<Value = NaiveDateTime> {
+        (0u32..24, 0u32..60, 0u64..3).prop_map(|(hour, minute, days)| {
+            (date() + Days::new(days))
+                .and_hms_opt(hour, minute, 0)
+                .unwrap()
+        })
+    }
+
+    fn time_range() -> impl Strategy<Value = TimeRange> {
+        let time = (0u32..24, 0u32..60)
+            .prop_map(|(hour, minute)| NaiveTime::from_hms_opt(hour, minute, 0).unwrap());
+        (time.clone(), proptest::option::of(time)).prop_map(|(start, end)| TimeRange { start, end })
+    }
+
+    #[test]
+    fn migrates_open_tasks_only() {
+        let mut task = JournalEntry::new(EntryKind::Task, "call dentist", date());
+        assert_eq!(task.migrate(), Ok("Task migrated."));
+        assert_eq!(task.to_markdown_line(), "  → call dentist");
+        assert_eq!(parse_markdown_line("→ call dentist", date()), task);
+        assert_eq!(
+            task.toggle_complete(),
+            Err("Migrated tasks cannot be completed.")
+        );
+        assert_eq!(
+            task.toggle_cancel(),
+            Err("Migrated tasks cannot be cancelled.")
+        );
+        assert_eq!(task.migrate(), Err("Only open tasks can be migrated."));
+
+        let mut note = JournalEntry::new(EntryKind::Note, "idea", date());
+        assert_eq!(note.migrate(), Err("Only tasks can be migrated."));
+        assert_eq!(
+            JournalEntry::raw("→ arrow", date()).to_markdown_line(),
+            "  \\→ arrow"
+        );
+    }
+
+    #[test]
+    fn renders_and_parses_timed_events() {
+        let mut event = JournalEntry::new(EntryKind::Event, "design review", date());
+        event.time = Some(TimeRange {
+            start: NaiveTime::from_hms_opt(14, 0, 0).unwrap(),
+            end: Some(NaiveTime::from_hms_opt(15, 0, 0).unwrap()),
+        });
+        assert_eq!(event.to_markdown_line(), "  ◦ 14:00-15:00 design review");
+
+        event.state = EntryState::Cancelled;
+        assert_eq!(
+            event.to_markdown_line(),
+            "  ◦ ~~14:00-15:00 design review~~"
+        );
+        assert_eq!(
+            parse_markdown_line(&event.to_markdown_line(), date()),
+            event
+        );
+
+        let point = parse_markdown_line("◦ 09:05 standup", date());
+        assert_eq!(point.text, "standup");
+        assert_eq!(
+            point.time,
+            Some(TimeRange {
+                start: NaiveTime::from_hms_opt(9, 5, 0).unwrap(),
+                end: None,
+            })
+        );
+    }
+
+    #[test]
+    fn escapes_untimed_events_that_start_with_a_time() {
+        let event = JournalEntry::new(EntryKind::Event, "10:00 is the new deadline", date());
+        assert_eq!(event.to_markdown_line(), "  ◦ \\10:00 is the new deadline");
+        assert_eq!(
+            parse_markdown_line(&event.to_markdown_line(), date()),
+            event
+        );
+
+        let task = parse_markdown_line("· 10:00 call dentist", date());
+        assert_eq!(task.time, None);
+        assert_eq!(task.text, "10:00 call dentist");
+    }
+
+    #[test]
+    fn orders_timed_events_by_start_time() -> io::Result<()> {
+        let mut journal = Journal::load_for_date(&temp_root(), date())?;
+        journal.entries = parse_markdown(
+            "◦ 15:00 late\n◦ untimed\n· 08:00 task\n◦ 09:00-10:00 early\n",
+            date(),
+        );
+
+        let texts = journal
+            .timed_events()
+            .into_iter()
+            .map(|entry| entry.text.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(texts, ["early", "late"]);
+        Ok(())
+    }
+
+    fn at(hour: u32, minute: u32) -> NaiveDateTime {
+        date().and_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn renders_and_parses_timestamps_as_trailing_comment() {
+        let mut task = JournalEntry::new(EntryKind::Task, "ship", date());
+        task.stamp_created(date().and_hms_opt(9, 14, 42).unwrap());
+        task.toggle_complete().unwrap();
+        task.record_state_change(at(17, 2));
+
+        let line = task.to_markdown_line();
+        assert_eq!(
+            line,
+            "  X ship <!-- created 2026-05-21T09:14 completed 2026-05-21T17:02 -->"
+        );
+
+        let parsed = parse_markdown_line(&line, date());
+        assert_eq!(parsed.text, "ship");
+        assert_eq!(parsed.created_at, Some(at(9, 14)));
+        assert_eq!(
+            parsed.last_state_change(),
+            Some(&StateChange {
+                state: EntryState::Completed,
+                at: at(17, 2),
+            })
+        );
+        assert_eq!(parsed, task);
+    }
+
+    #[test]
+    fn renders_and_parses_the_detail_note_link() {
+        let mut event = JournalEntry::new(EntryKind::Event, "planning", date());
+        event.stamp_created(at(9, 14));
+        event.note = Some(String::from("2026-05-21-1"));
+
+        let line = event.to_markdown_line();
+        assert_eq!(
+            line,
+            "  ◦ planning <!-- created 2026-05-21T09:14 note 2026-05-21-1 -->"
+        );
+        assert_eq!(parse_markdown_line(&line, date()), event);
+
+        let outside = parse_markdown_line("- planning <!-- note ../secret -->", date());
+        assert_eq!(outside.note, None);
+        assert_eq!(outside.text, "planning <!-- note ../secret -->");
+    }
+
+    #[test]
+    fn keeps_unrecognized_trailing_comments_as_entry_text() {
+        let note = parse_markdown_line("- call back <!-- ask about invoice -->", date());
+        assert_eq!(note.text, "call back <!-- ask about invoice -->");
+        assert_eq!(note.created_at, None);
+
+        assert_eq!(
+            note.to_markdown_line(),
+            "  - call back <!-- ask about invoice --> <!-- -->"
+        );
+    }
+
+    #[test]
+    fn loads_entries_without_timestamps() {
+        let task = parse_markdown_line("  · ~~legacy~~", date());
+        assert_eq!(task.state, EntryState::Cancelled);
+        assert_eq!(task.created_at, None);
+        assert!(task.state_changes.is_empty());
+    }
+
+    proptest! {
+        #[test]
+        fn markdown_lines_round_trip_through_the_parser(entry in journal_entry()) {
+            prop_assert_eq!(parse_markdown_line(&entry.to_markdown_line(), date()), entry);
+        }
+
+        #[test]
+        fn markdown_files_round_trip_through_the_parser(
+            entries in proptest::collection::vec(journal_entry(), 0..8)
+        ) {
+            let markdown = entries
+                .iter()
+                .map(JournalEntry::to_markdown_line)
+                .collect::<Vec<_>>()
+                .join("\n");
+            prop_assert_eq!(parse_markdown(&markdown, date()), entries);
+        }
+    }
 }
@@ -0,0 +1,347 @@
+//! Journal-pane key bindings, which can be changed in `settings.md`.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::settings::Settings;
+
+/// Something a key does when the journal pane is focused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAction {
+    PreviousEntry,
+    NextEntry,
+    FirstEntry,
+    LastEntry,
+    PreviousSection,
+    NextSection,
+    PreviousDay,
+    NextDay,
+    WeekBack,
+    WeekForward,
+    MonthBack,
+    MonthForward,
+    Today,
+    Calendar,
+    PreviousDayFile,
+    NextDayFile,
+    Delete,
+    Yank,
+    Paste,
+    Undo,
+    Visual,
+    Search,
+    Leader,
+    Help,
+}
+
+impl KeyAction {
+    /// Every action, in the order the help popup lists them.
+    pub const ALL: [KeyAction; 24] = [
+        KeyAction::PreviousEntry,
+        KeyAction::NextEntry,
+        KeyAction::FirstEntry,
+        KeyAction::LastEntry,
+        KeyAction::PreviousSection,
+        KeyAction::NextSection,
+        KeyAction::PreviousDay,
+        KeyAction::NextDay,
+        KeyAction::WeekBack,
+        KeyAction::WeekForward,
+        KeyAction::MonthBack,
+        KeyAction::MonthForward,
+        KeyAction::Today,
+        KeyAction::Calendar,
+        KeyAction::PreviousDayFile,
+        KeyAction::NextDayFile,
+        KeyAction::Delete,
+        KeyAction::Yank,
+        KeyAction::Paste,
+        KeyAction::Undo,
+        KeyAction::Visual,
+        KeyAction::Search,
+        KeyAction::Leader,
+        KeyAction::Help,
+    ];
+
+    /// The name used in `settings.md`, as in `- key next entry: n`.
+    pub fn name(self) -> &'static str {
+        match self {
+            KeyAction::PreviousEntry => "previous entry",
+            KeyAction::NextEntry => "next entry",
+            KeyAction::FirstEntry => "first entry",
+            KeyAction::LastEntry => "last entry",
+            KeyAction::PreviousSection => "previous section",
+            KeyAction::NextSection => "next section",
+            KeyAction::PreviousDay => "previous day",
+            KeyAction::NextDay => "next day",
+            KeyAction::WeekBack => "week back",
+            KeyAction::WeekForward => "week forward",
+            KeyAction::MonthBack => "month back",
+            KeyAction::MonthForward => "month forward",
+            KeyAction::Today => "today",
+            KeyAction::Calendar => "calendar",
+            KeyAction::PreviousDayFile => "previous day file",
+            KeyAction::NextDayFile => "next day file",
+            KeyAction::Delete => "delete",
+            KeyAction::Yank => "yank",
+            KeyAction::Paste => "paste",
+            KeyAction::Undo => "undo",
+            KeyAction::Visual => "visual",
+            KeyAction::Search => "search",
+            KeyAction::Leader => "leader",
+            KeyAction::Help => "help",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            KeyAction::PreviousEntry => "Highlight the previous entry",
+            KeyAction::NextEntry => "Highlight the next entry",
+            KeyAction::FirstEntry => "Highlight the first entry, or entry N after a count",
+            KeyAction::LastEntry => "Highlight the last entry, or entry N after a count",
+            KeyAction::PreviousSection => "Go to the start of this or the previous section",
+            KeyAction::NextSection => "Go to the start of the next section",
+            KeyAction::PreviousDay => "Show the previous day or focus the pane to the left",
+            KeyAction::NextDay => "Show the next day or focus the pane to the right",
+            KeyAction::WeekBack => "Go back a week",
+            KeyAction::WeekForward => "Go forward a week",
+            KeyAction::MonthBack => "Go back a month",
+            KeyAction::MonthForward => "Go forward a month",
+            KeyAction::Today => "Go to today",
+            KeyAction::Calendar => "Open the month calendar",
+            KeyAction::PreviousDayFile => "Skip to the previous day with a file",
+            KeyAction::NextDayFile => "Skip to the next day with a file",
+            KeyAction::Delete => "Delete the entry, or N entries after a count",
+            KeyAction::Yank => "Yank the entry, or N entries after a count",
+            KeyAction::Paste => "Paste yanked entries below the highlighted one",
+            KeyAction::Undo => "Undo the last change to entries",
+            KeyAction::Visual => "Select a range of entries, or stop selecting",
+            KeyAction::Search => "Search commands",
+            KeyAction::Leader => "Open the leader menu",
+            KeyAction::Help => "Show this help",
+        }
+    }
+
+    /// Actions whose key is pressed twice, as in `gg` and `dd`. In visual mode
+    /// `Delete` and `Yank` act on the first press.
+    pub fn is_doubled(self) -> bool {
+        matches!(
+            self,
+            KeyAction::FirstEntry | KeyAction::Delete | KeyAction::Yank
+        )
+    }
+
+    fn default_keys(self) -> &'static [KeyCode] {
+        match self {
+            KeyAction::PreviousEntry => &[KeyCode::Char('k'), KeyCode::Up],
+            KeyAction::NextEntry => &[KeyCode::Char('j'), KeyCode::Down],
+            KeyAction::FirstEntry => &[KeyCode::Char('g')],
+            KeyAction::LastEntry => &[KeyCode::Char('G')],
+            KeyAction::PreviousSection => &[KeyCode::Char('{')],
+            KeyAction::NextSection => &[KeyCode::Char('}')],
+            KeyAction::PreviousDay => &[KeyCode::Char('h'), KeyCode::Left],
+            KeyAction::NextDay => &[KeyCode::Char('l'), KeyCode::Right],
+            KeyAction::WeekBack => &[KeyCode::Char('H')],
+            KeyAction::WeekForward => &[KeyCode::Char('L')],
+            KeyAction::MonthBack => &[KeyCode::Char('<')],
+            KeyAction::MonthForward => &[KeyCode::Char('>')],
+            KeyAction::Today => &[KeyCode::Char('t')],
+            KeyAction::Calendar => &[KeyCode::Char('C')],
+            KeyAction::PreviousDayFile => &[KeyCode::Char('[')],
+            KeyAction::NextDayFile => &[KeyCode::Char(']')],
+            KeyAction::Delete => &[KeyCode::Char('d')],
+            KeyAction::Yank => &[KeyCode::Char('y')],
+            KeyAction::Paste => &[KeyCode::Char('p')],
+            KeyAction::Undo => &[KeyCode::Char('u')],
+            KeyAction::Visual => &[KeyCode::Char('V')],
+            KeyAction::Search => &[KeyCode::Char(':')],
+            KeyAction::Leader => &[KeyCode::Char(' ')],
+            KeyAction::Help => &[KeyCode::Char('?')],
+        }
+    }
+}
+
+/// The keys bound to each journal-pane action. A `- key <action>: <keys>`
+/// line in `settings.md` replaces the action's default keys with a
+/// space-separated list, such as `- key next entry: n down`. A key taken by a
+/// remapped action no longer does what it did by default. Values that cannot
+/// be read fall back to the defaults.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keymap {
+    bindings: Vec<(KeyCode, KeyAction)>,
+}
+
+impl Keymap {
+    pub fn from_settings(settings: &Settings) -> Self {
+        let remapped = KeyAction::ALL
+            .into_iter()
+            .filter_map(|action| {
+                let keys = settings.get(&format!("key {}", action.name()))?;
+                parse_keys(keys).map(|keys| (action, keys))
+            })
+            .collect::<Vec<_>>();
+        let is_remapped_key = |code: &KeyCode| remapped.iter().any(|(_, keys)| keys.contains(code));
+
+        let mut bindings = Vec::new();
+        for action in KeyAction::ALL {
+            match remapped.iter().find(|(remapped, _)| *remapped == action) {
+                Some((_, keys)) => bindings.extend(keys.iter().map(|&code| (code, action))),
+                None => bindings.extend(
+                    action
+                        .default_keys()
+                        .iter()
+                        .filter(|code| !is_remapped_key(code))
+                        .map(|&code| (code, action)),
+                ),
+            }
+        }
+
+        Self { bindings }
+    }
+
+    /// The action for a key press. Character keys only count without `Ctrl`
+    /// or `Alt`, so those chords stay free.
+    pub fn action_for(&self, key: KeyEvent) -> Option<KeyAction> {
+        if matches!(key.code, KeyCode::Char(_))
+            && key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return None;
+        }
+
+        self.bindings
+            .iter()
+            .find(|(code, _)| *code == key.code)
+            .map(|(_, action)| *action)
+    }
+
+    pub fn keys_for(&self, action: KeyAction) -> Vec<KeyCode> {
+        self.bindings
+            .iter()
+            .filter(|(_, bound)| *bound == action)
+            .map(|(code, _)| *code)
+            .collect()
+    }
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::from_settings(&Settings::default())
+    }
+}
+
+/// How a key is written in `settings.md` and shown in the help popup.
+pub fn key_name(code: KeyCode) -> String {
+    match code {
+        KeyCode::Char(' ') => String::from("Space"),
+        KeyCode::Char(character) => character.to_string(),
+        KeyCode::F(number) => format!("F{number}"),
+        KeyCode::Up => String::from("Up"),
+        KeyCode::Down => String::from("Down"),
+        KeyCode::Left => String::from("Left"),
+        KeyCode::Right => String::from("Right"),
+        KeyCode::Home => String::from("Home"),
+        KeyCode::End => String::from("End"),
+        KeyCode::PageUp => String::from("PageUp"),
+        KeyCode::PageDown => String::from("PageDown"),
+        KeyCode::Tab => String::from("Tab"),
+        KeyCode::Enter => String::from("Enter"),
+        KeyCode::Backspace => String::from("Backspace"),
+        KeyCode::Esc => String::from("Esc"),
+        code => format!("{code:?}"),
+    }
+}
+
+fn parse_keys(value: &str) -> Option<Vec<KeyCode>> {
+    let keys = value
+        .split_whitespace()
+        .map(parse_key)
+        .collect::<Option<Vec<_>>>()?;
+    (!keys.is_empty()).then_some(keys)
+}
+
+/// Reads a single character, or a key name such as `space`, `up` or `f1`.
+fn parse_key(name: &str) -> Option<KeyCode> {
+    let mut characters = name.chars();
+    if let (Some(character), None) = (characters.next(), characters.next()) {
+        return Some(KeyCode::Char(character));
+    }
+
+    let code = match name.to_ascii_lowercase().as_str() {
+        "space" => KeyCode::Char(' '),
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "tab" => KeyCode::Tab,
+        lower => KeyCode::F(lower.strip_prefix('f')?.parse().ok()?),
+    };
+    Some(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn binds_default_keys_and_leaves_modified_characters_free() {
+        let keymap = Keymap::default();
+
+        assert_eq!(
+            keymap.action_for(press(KeyCode::Char('j'))),
+            Some(KeyAction::NextEntry)
+        );
+        assert_eq!(
+            keymap.action_for(press(KeyCode::Down)),
+            Some(KeyAction::NextEntry)
+        );
+        assert_eq!(
+            keymap.action_for(KeyEvent::new(KeyCode::Char('L'), KeyModifiers::SHIFT)),
+            Some(KeyAction::WeekForward)
+        );
+        assert_eq!(
+            keymap.action_for(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::CONTROL)),
+            None
+        );
+        assert_eq!(keymap.keys_for(KeyAction::Leader), [KeyCode::Char(' ')]);
+    }
+
+    #[test]
+    fn remaps_keys_from_settings() {
+        let keymap = Keymap::from_settings(&Settings::parse(
+            "- key next entry: n down\n- key help: F1\n- key today: j\n- key calendar: ctrl-c\n",
+        ));
+
+        assert_eq!(
+            keymap.keys_for(KeyAction::NextEntry),
+            [KeyCode::Char('n'), KeyCode::Down]
+        );
+        assert_eq!(
+            keymap.action_for(press(KeyCode::Char('j'))),
+            Some(KeyAction::Today)
+        );
+        assert_eq!(keymap.keys_for(KeyAction::Today), [KeyCode::Char('j')]);
+        assert_eq!(
+            keymap.action_for(press(KeyCode::F(1))),
+            Some(KeyAction::Help)
+        );
+        assert_eq!(keymap.action_for(press(KeyCode::Char('?'))), None);
+        assert_eq!(keymap.keys_for(KeyAction::Calendar), [KeyCode::Char('C')]);
+        assert_eq!(
+            keymap
+                .keys_for(KeyAction::NextEntry)
+                .into_iter()
+                .map(key_name)
+                .collect::<Vec<_>>(),
+            ["n", "Down"]
+        );
+    }
+}
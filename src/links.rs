@@ -0,0 +1,142 @@
+//! URLs and file paths referenced in entry text, and the ways to hand them to
+//! the rest of the system: an opener command and the terminal clipboard.
+
+use crate::{
+    inline::{parse_inline, Inline},
+    settings::Settings,
+};
+
+/// The opener used unless `open command` is set. `$1` is the link.
+const DEFAULT_OPEN_COMMAND: &str = if cfg!(target_os = "macos") {
+    "open \"$1\""
+} else {
+    "xdg-open \"$1\""
+};
+
+const URL_SCHEMES: [&str; 3] = ["http://", "https://", "file://"];
+
+const PATH_PREFIXES: [&str; 4] = ["/", "~/", "./", "../"];
+
+/// The shell command that opens a link, read from `settings.md` as
+/// `- open command: firefox "$1"`.
+pub fn open_command(settings: &Settings) -> String {
+    settings
+        .get("open command")
+        .filter(|command| !command.is_empty())
+        .unwrap_or(DEFAULT_OPEN_COMMAND)
+        .to_string()
+}
+
+/// The URLs and paths in entry text, in order and without repeats. Markdown
+/// link targets count, as do bare words that start with a URL scheme or look
+/// like a path: `/`, `~/`, `./` or `../`.
+pub fn find_links(text: &str) -> Vec<String> {
+    let mut links = Vec::<String>::new();
+    for run in parse_inline(text) {
+        let found = match run {
+            Inline::Link { url, .. } => vec![url],
+            Inline::Text(text)
+            | Inline::Bold(text)
+            | Inline::Italic(text)
+            | Inline::Code(text)
+            | Inline::Strikethrough(text) => {
+                text.split_whitespace().filter_map(link_word).collect()
+            }
+        };
+        for link in found {
+            if !links.contains(&link) {
+                links.push(link);
+            }
+        }
+    }
+    links
+}
+
+fn link_word(word: &str) -> Option<String> {
+    let word = word
+        .trim_start_matches(['(', '<', '"', '\''])
+        .trim_end_matches(['.', ',', ';', ':', '!', '?', ')', '>', '"', '\'']);
+    let is_url = URL_SCHEMES
+        .iter()
+        .any(|scheme| word.len() > scheme.len() && word.starts_with(scheme));
+    let is_path = PATH_PREFIXES
+        .iter()
+        .any(|prefix| word.len() > prefix.len() && word.starts_with(prefix));
+    (is_url || is_path).then(|| word.to_string())
+}
+
+/// Replaces a leading `~/` with the home directory, because the opener gets
+/// the link quoted and the shell would not expand it.
+pub fn expand_home(link: &str, home: Option<&str>) -> String {
+    match (link.strip_prefix("~/"), home) {
+        (Some(rest), Some(home)) => format!("{}/{rest}", home.trim_end_matches('/')),
+        _ => link.to_string(),
+    }
+}
+
+/// The OSC 52 escape sequence that asks the terminal to put `text` on the
+/// system clipboard. It works over SSH, but some terminals ignore it.
+pub fn osc52_copy(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64(text.as_bytes()))
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let value = chunk.iter().enumerate().fold(0u32, |value, (index, byte)| {
+            value | u32::from(*byte) << (16 - 8 * index)
+        });
+        for index in 0..4 {
+            if index <= chunk.len() {
+                let sextet = (value >> (18 - 6 * index)) & 0x3f;
+                encoded.push(char::from(ALPHABET[sextet as usize]));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_urls_and_paths_in_entry_text() {
+        assert_eq!(
+            find_links(
+                "fix (https://example.com/T-1), see [notes](docs/plan.md) and `~/notes/a.md`. Also https://example.com/T-1 ./x /",
+            ),
+            [
+                "https://example.com/T-1",
+                "docs/plan.md",
+                "~/notes/a.md",
+                "./x",
+            ]
+        );
+        assert!(find_links("call mum at 5/6, http:// and and/or").is_empty());
+    }
+
+    #[test]
+    fn reads_the_open_command_and_expands_home() {
+        assert_eq!(open_command(&Settings::default()), DEFAULT_OPEN_COMMAND);
+        assert_eq!(
+            open_command(&Settings::parse("- open command: firefox \"$1\"\n")),
+            "firefox \"$1\""
+        );
+        assert_eq!(expand_home("~/a.md", Some("/home/me/")), "/home/me/a.md");
+        assert_eq!(expand_home("~/a.md", None), "~/a.md");
+        assert_eq!(expand_home("/tmp/a", Some("/home/me")), "/tmp/a");
+    }
+
+    #[test]
+    fn encodes_clipboard_copies_as_osc_52() {
+        assert_eq!(osc52_copy("hi"), "\x1b]52;c;aGk=\x07");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+        assert_eq!(base64(b"fooba"), "Zm9vYmE=");
+        assert_eq!(base64(b""), "");
+    }
+}
@@ -1,26 +1,65 @@
 mod app;
+mod cache;
+mod calendar;
+mod dates;
+mod day_index;
+mod inline;
 mod journal;
+mod keymap;
+mod links;
+mod notes;
+mod recurring;
+mod reminders;
+mod settings;
 mod ui;
 
 use std::{
+    env,
     error::Error,
-    io::{self, Stdout},
+    io::{self, Stdout, Write},
     path::Path,
+    process::{Command, Stdio},
+    thread,
+    time::Duration,
 };
 
-use app::App;
-use chrono::Local;
+use app::{App, Effect, HitMap};
+use chrono::{Local, TimeDelta};
 use crossterm::{
-    event::{self, Event},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use journal::Journal;
+use links::osc52_copy;
 use ratatui::{backend::CrosstermBackend, Terminal};
+use recurring::RecurringRules;
+use reminders::{due_within, reminders_for, ReminderSettings};
+use settings::Settings;
+
+/// How long to wait for a key before the app ticks and redraws anyway.
+const TICK_RATE: Duration = Duration::from_secs(1);
+
+/// The editor used when neither `$VISUAL` nor `$EDITOR` is set.
+const DEFAULT_EDITOR: &str = "vi";
+
+/// How far back `remind` looks for reminders that became due, unless
+/// `--window <minutes>` is given.
+const DEFAULT_REMIND_WINDOW_MINUTES: u32 = 5;
 
 fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = env::args().skip(1);
+    match args.next().as_deref() {
+        None => {}
+        Some("remind") => return remind(args),
+        Some(other) => return Err(format!("Unknown subcommand: {other}").into()),
+    }
+
     let today = Local::now().date_naive();
-    let journal = Journal::load_for_date(Path::new("journal"), today)?;
+    let root = Path::new("journal");
+    // The app reports a rules file it cannot read once it starts.
+    let rules = RecurringRules::load(root).unwrap_or_else(|_| RecurringRules::empty(root));
+    let journal = Journal::load_for_date_with_rules(root, today, &rules)?;
     let mut app = App::new(journal);
 
     let mut terminal = setup_terminal()?;
@@ -38,24 +77,161 @@ fn main() -> Result<(), Box<dyn Error>> {
 fn setup_terminal() -> io::Result<Terminal<CrosstermBackend<Stdout>>> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     Terminal::new(CrosstermBackend::new(stdout))
 }
 
 fn restore_terminal(terminal: &mut Terminal<CrosstermBackend<Stdout>>) -> io::Result<()> {
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
     terminal.show_cursor()
 }
 
 fn run_app(terminal: &mut Terminal<CrosstermBackend<Stdout>>, app: &mut App) -> io::Result<()> {
+    let mut hits = HitMap::default();
     while !app.should_quit {
-        terminal.draw(|frame| ui::draw(frame, app))?;
+        terminal.draw(|frame| hits = ui::draw(frame, app))?;
+
+        if event::poll(TICK_RATE)? {
+            match event::read()? {
+                Event::Key(key) => app.handle_key(key)?,
+                Event::Mouse(mouse) => app.handle_mouse(mouse, &hits)?,
+                _ => {}
+            }
+        }
+        app.tick();
+
+        for effect in app.take_effects() {
+            let (failure, edited) = match &effect {
+                Effect::Bell | Effect::RunCommand { .. } => (String::from("run reminder"), None),
+                Effect::Open { link, .. } => (format!("open {link}"), None),
+                Effect::Copy(_) => (String::from("copy to the clipboard"), None),
+                Effect::Edit { date, .. } => (String::from("run the editor"), Some(*date)),
+                Effect::EditNote(_) => (String::from("run the editor"), None),
+            };
+            let result = run_effect(terminal, effect);
+            // The editor may have saved the day even if it exited with an error.
+            if let Some(date) = edited {
+                if let Err(error) = app.reload_day(date) {
+                    app.set_error(format!(
+                        "Could not reload {}: {error}",
+                        date.format("%Y-%m-%d")
+                    ));
+                }
+            }
+            if let Err(error) = result {
+                app.set_error(format!("Could not {failure}: {error}"));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn run_effect(terminal: &mut Terminal<CrosstermBackend<Stdout>>, effect: Effect) -> io::Result<()> {
+    match effect {
+        Effect::Bell => {
+            let mut stdout = io::stdout();
+            stdout.write_all(b"\x07")?;
+            stdout.flush()
+        }
+        Effect::RunCommand { command, argument } => spawn_shell(&command, &argument),
+        Effect::Open { command, link } => spawn_shell(&command, &link),
+        Effect::Copy(text) => {
+            let mut stdout = io::stdout();
+            stdout.write_all(osc52_copy(&text).as_bytes())?;
+            stdout.flush()
+        }
+        Effect::Edit { path, .. } | Effect::EditNote(path) => edit_in_editor(terminal, &path),
+    }
+}
+
+/// Leaves the app's screen, runs `$VISUAL` or `$EDITOR` on `path` until it
+/// exits, and takes the screen back. The terminal is restored even when the
+/// editor cannot be started.
+fn edit_in_editor(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    path: &Path,
+) -> io::Result<()> {
+    let editor = ["VISUAL", "EDITOR"]
+        .into_iter()
+        .filter_map(|name| env::var(name).ok())
+        .find(|editor| !editor.trim().is_empty())
+        .unwrap_or_else(|| String::from(DEFAULT_EDITOR));
 
-        if let Event::Key(key) = event::read()? {
-            app.handle_key(key)?;
+    restore_terminal(terminal)?;
+    // The editor may take arguments, such as `code --wait`, so run it
+    // through the shell with the path as `$1`.
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(format!("{editor} \"$1\""))
+        .arg("bullet-journal")
+        .arg(path)
+        .status();
+    enable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        EnterAlternateScreen,
+        EnableMouseCapture
+    )?;
+    terminal.clear()?;
+
+    match status? {
+        status if status.success() => Ok(()),
+        status => Err(io::Error::other(format!("{editor} exited with {status}"))),
+    }
+}
+
+/// Runs `command` with `sh -c`, passing `argument` as `$1`.
+fn spawn_shell(command: &str, argument: &str) -> io::Result<()> {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .arg("bullet-journal")
+        .arg(argument)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    // Wait in the background so the command never blocks the app.
+    thread::spawn(move || child.wait());
+    Ok(())
+}
+
+/// Prints the reminders for today and tomorrow that became due within the
+/// window, for running from cron without the TUI.
+fn remind(mut args: impl Iterator<Item = String>) -> Result<(), Box<dyn Error>> {
+    let mut window = DEFAULT_REMIND_WINDOW_MINUTES;
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), args.next().map(|value| value.parse::<u32>())) {
+            ("--window", Some(Ok(minutes))) if minutes > 0 => window = minutes,
+            _ => {
+                return Err(
+                    String::from("Usage: bullet-journal-tui remind [--window <minutes>]").into(),
+                )
+            }
         }
     }
 
+    let root = Path::new("journal");
+    let settings = ReminderSettings::from_settings(&Settings::load(root)?);
+    let rules = RecurringRules::load(root)?;
+    let now = Local::now().naive_local();
+    let mut reminders = Vec::new();
+    for date in [Some(now.date()), now.date().succ_opt()]
+        .into_iter()
+        .flatten()
+    {
+        let journal = Journal::load_for_date_with_rules(root, date, &rules)?;
+        reminders.extend(reminders_for(&journal, &settings));
+    }
+
+    for reminder in due_within(&reminders, now, TimeDelta::minutes(i64::from(window))) {
+        println!("{reminder}");
+    }
     Ok(())
 }
@@ -0,0 +1,178 @@
+//! Long-form entry bodies. Each lives in its own Markdown file under
+//! `notes/`, named `<date>-<number>.md` after the day it was started on, and
+//! an entry links to it with `note <name>` in its metadata comment.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use chrono::NaiveDate;
+
+use crate::cache::{file_stamp, FileStamp};
+
+pub const NOTES_DIR_NAME: &str = "notes";
+
+pub fn note_path(root: &Path, name: &str) -> PathBuf {
+    root.join(NOTES_DIR_NAME).join(format!("{name}.md"))
+}
+
+/// Whether `name` can name a note file. Only letters, digits, `-` and `_` are
+/// allowed, so a link never points outside `notes/`.
+pub fn is_note_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || matches!(character, '-' | '_'))
+}
+
+/// A note as it was last read, with the stamp of its file so a later change
+/// is noticed without reading it again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadedNote {
+    pub name: String,
+    /// The note's text, or why it could not be read.
+    pub body: Result<String, String>,
+    stamp: FileStamp,
+}
+
+impl LoadedNote {
+    pub fn load(root: &Path, name: &str) -> Self {
+        let path = note_path(root, name);
+        let stamp = file_stamp(&path).unwrap_or(None);
+        let body = fs::read_to_string(&path).map_err(|error| match error.kind() {
+            io::ErrorKind::NotFound => format!("{NOTES_DIR_NAME}/{name}.md is missing."),
+            _ => format!("Could not read {NOTES_DIR_NAME}/{name}.md: {error}"),
+        });
+        Self {
+            name: name.to_string(),
+            body,
+            stamp,
+        }
+    }
+
+    /// Reads the note again when its file changed since it was last read.
+    pub fn refresh(&mut self, root: &Path) {
+        if file_stamp(&note_path(root, &self.name)).unwrap_or(None) != self.stamp {
+            *self = Self::load(root, &self.name);
+        }
+    }
+}
+
+/// The name for a new note on `date`. Numbers count up from 1 past any note
+/// already started on that day. The `notes/` directory is created so the
+/// note can be written.
+pub fn next_note_name(root: &Path, date: NaiveDate) -> io::Result<String> {
+    let directory = root.join(NOTES_DIR_NAME);
+    fs::create_dir_all(&directory)?;
+
+    let prefix = format!("{}-", date.format("%Y-%m-%d"));
+    let mut last = 0;
+    for file in fs::read_dir(&directory)? {
+        let file_name = file?.file_name();
+        let number = file_name
+            .to_str()
+            .and_then(|name| name.strip_prefix(&prefix)?.strip_suffix(".md"))
+            .and_then(|number| number.parse::<u32>().ok());
+        last = last.max(number.unwrap_or(0));
+    }
+
+    Ok(format!("{prefix}{}", last + 1))
+}
+
+/// What a new note starts with: the entry's text as a heading.
+pub fn new_note_contents(title: &str) -> String {
+    format!("# {title}\n\n")
+}
+
+/// Removes a note the app created, unless it no longer holds `contents`.
+/// Returns whether it was removed.
+pub fn remove_unedited_note(path: &Path, contents: &str) -> io::Result<bool> {
+    match fs::read_to_string(path) {
+        Ok(current) if current == contents => fs::remove_file(path).map(|()| true),
+        Ok(_) => Ok(false),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(false),
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{
+        env,
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    fn temp_root() -> PathBuf {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        env::temp_dir().join(format!("bullet-journal-notes-test-{unique}"))
+    }
+
+    #[test]
+    fn numbers_notes_per_day() -> io::Result<()> {
+        let root = temp_root();
+        let date = NaiveDate::from_ymd_opt(2026, 5, 21).unwrap();
+
+        assert_eq!(next_note_name(&root, date)?, "2026-05-21-1");
+        assert_eq!(next_note_name(&root, date)?, "2026-05-21-1");
+        fs::write(note_path(&root, "2026-05-21-7"), "")?;
+        fs::write(note_path(&root, "2026-05-22-9"), "")?;
+        assert_eq!(next_note_name(&root, date)?, "2026-05-21-8");
+
+        assert!(is_note_name("2026-05-21-1"));
+        assert!(!is_note_name("../2026-05-21-1"));
+        assert!(!is_note_name(""));
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn reloads_notes_whose_files_change() -> io::Result<()> {
+        let root = temp_root();
+        let date = NaiveDate::from_ymd_opt(2026, 5, 21).unwrap();
+        let name = next_note_name(&root, date)?;
+        fs::write(note_path(&root, &name), new_note_contents("planning"))?;
+
+        let mut note = LoadedNote::load(&root, &name);
+        assert_eq!(note.body.as_deref(), Ok("# planning\n\n"));
+        fs::write(note_path(&root, &name), "# planning\n\nAgenda: budget\n")?;
+        note.refresh(&root);
+        assert_eq!(note.body.as_deref(), Ok("# planning\n\nAgenda: budget\n"));
+
+        fs::remove_file(note_path(&root, &name))?;
+        note.refresh(&root);
+        assert_eq!(
+            note.body,
+            Err(String::from("notes/2026-05-21-1.md is missing."))
+        );
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn removes_only_unedited_notes() -> io::Result<()> {
+        let root = temp_root();
+        fs::create_dir_all(root.join(NOTES_DIR_NAME))?;
+        let contents = new_note_contents("planning");
+        let path = note_path(&root, "2026-05-21-1");
+
+        fs::write(&path, "# planning\n\nAgenda\n")?;
+        assert!(!remove_unedited_note(&path, &contents)?);
+        assert!(path.exists());
+
+        fs::write(&path, &contents)?;
+        assert!(remove_unedited_note(&path, &contents)?);
+        assert!(!path.exists());
+        assert!(!remove_unedited_note(&path, &contents)?);
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+}
@@ -0,0 +1,567 @@
+//! Recurring entries stored in `recurring.md` and added to matching days.
+
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+use crate::{
+    dates::parse_weekday,
+    journal::{parse_markdown_line, EntryKind, Journal, JournalEntry},
+};
+
+pub const RECURRING_FILE_NAME: &str = "recurring.md";
+
+/// How often a recurring entry repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Recurrence {
+    Daily,
+    Weekdays,
+    Weekly(Weekday),
+    /// On a day of the month. Months without that day use their last day.
+    Monthly(u32),
+    /// Every N days, counted from the rule's start date.
+    EveryDays(u32),
+}
+
+impl Recurrence {
+    /// Parses `daily`, `weekdays`, `weekly <weekday>`, `monthly <day>` and
+    /// `every <n> days`.
+    pub fn parse(input: &str) -> Option<Self> {
+        let lower = input.to_ascii_lowercase();
+        let words = lower.split_whitespace().collect::<Vec<_>>();
+
+        let recurrence = match words.as_slice() {
+            ["daily"] => Self::Daily,
+            ["weekdays"] => Self::Weekdays,
+            ["weekly", weekday] => Self::Weekly(parse_weekday(weekday)?),
+            ["monthly", day] => match day.parse::<u32>().ok()? {
+                day @ 1..=31 => Self::Monthly(day),
+                _ => return None,
+            },
+            ["every", count, "days"] => match count.parse::<u32>().ok()? {
+                0 => return None,
+                count => Self::EveryDays(count),
+            },
+            _ => return None,
+        };
+
+        Some(recurrence)
+    }
+
+    fn occurs_on(self, start: NaiveDate, date: NaiveDate) -> bool {
+        match self {
+            Self::Daily => true,
+            Self::Weekdays => date.weekday().num_days_from_monday() < 5,
+            Self::Weekly(weekday) => date.weekday() == weekday,
+            Self::Monthly(day) => date.day() == day.min(days_in_month(date)),
+            Self::EveryDays(count) => (date - start).num_days() % i64::from(count) == 0,
+        }
+    }
+}
+
+impl fmt::Display for Recurrence {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Daily => write!(formatter, "daily"),
+            Self::Weekdays => write!(formatter, "weekdays"),
+            Self::Weekly(weekday) => {
+                write!(formatter, "weekly {}", weekday.to_string().to_lowercase())
+            }
+            Self::Monthly(day) => write!(formatter, "monthly {day}"),
+            Self::EveryDays(count) => write!(formatter, "every {count} days"),
+        }
+    }
+}
+
+fn days_in_month(date: NaiveDate) -> u32 {
+    let first = date.with_day(1).expect("every month has a first day");
+    first
+        .checked_add_months(chrono::Months::new(1))
+        .and_then(|next| next.checked_sub_days(Days::new(1)))
+        .map_or(31, |last| last.day())
+}
+
+/// A rule that adds a copy of `entry` to every matching day from `start` on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurringRule {
+    pub id: String,
+    pub recurrence: Recurrence,
+    pub start: NaiveDate,
+    pub entry: JournalEntry,
+    /// Days whose occurrence was deleted or moved away, in order. They are
+    /// not added again.
+    pub skipped: Vec<NaiveDate>,
+}
+
+impl RecurringRule {
+    pub fn occurs_on(&self, date: NaiveDate) -> bool {
+        date >= self.start
+            && self.recurrence.occurs_on(self.start, date)
+            && self.skipped.binary_search(&date).is_err()
+    }
+
+    /// Builds the entry added to `date` for this rule.
+    pub fn occurrence(&self, date: NaiveDate) -> JournalEntry {
+        let mut entry = JournalEntry::new(self.entry.kind, self.entry.text.clone(), date);
+        entry.time = self.entry.time;
+        entry.important = self.entry.important;
+        entry.recurrence = Some(self.id.clone());
+        entry
+    }
+
+    /// Parses the `r1 weekly mon from 2026-05-18: ◦ 14:00 1:1` form, with or
+    /// without a leading list marker. Skipped days follow the start, as in
+    /// `from 2026-05-18 except 2026-05-25 2026-06-01`.
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.strip_prefix("- ").unwrap_or(line);
+        let (schedule, entry) = line.split_once(": ")?;
+        let (id, schedule) = schedule.split_once(' ')?;
+        let (schedule, skipped) = match schedule.split_once(" except ") {
+            Some((schedule, skipped)) => (schedule, skipped),
+            None => (schedule, ""),
+        };
+        let (recurrence, start) = schedule.rsplit_once(" from ")?;
+        if !is_rule_id(id) {
+            return None;
+        }
+
+        let start = parse_date(start)?;
+        let mut skipped = skipped
+            .split_whitespace()
+            .map(parse_date)
+            .collect::<Option<Vec<_>>>()?;
+        skipped.sort();
+        skipped.dedup();
+        let entry = parse_markdown_line(entry, start);
+        if entry.kind == EntryKind::Raw {
+            return None;
+        }
+
+        Some(Self {
+            id: id.to_string(),
+            recurrence: Recurrence::parse(recurrence)?,
+            start,
+            entry,
+            skipped,
+        })
+    }
+
+    /// The number in an `r<n>` id, which is how new ids are counted.
+    fn number(&self) -> Option<u32> {
+        rule_number(&self.id)
+    }
+}
+
+impl fmt::Display for RecurringRule {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "{} {} from {}",
+            self.id,
+            self.recurrence,
+            self.start.format("%Y-%m-%d"),
+        )?;
+        if !self.skipped.is_empty() {
+            write!(formatter, " except")?;
+            for date in &self.skipped {
+                write!(formatter, " {}", date.format("%Y-%m-%d"))?;
+            }
+        }
+        write!(
+            formatter,
+            ": {}",
+            self.entry.to_markdown_line().trim_start()
+        )
+    }
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+}
+
+fn rule_number(id: &str) -> Option<u32> {
+    id.strip_prefix('r')?.parse().ok()
+}
+
+/// Parses the `<!-- last id r7 -->` line that records the newest id ever
+/// given out.
+fn parse_last_id(line: &str) -> Option<u32> {
+    rule_number(line.strip_prefix("<!-- last id ")?.strip_suffix(" -->")?)
+}
+
+fn is_rule_id(id: &str) -> bool {
+    !id.is_empty()
+        && id
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric())
+}
+
+#[derive(Debug, Clone)]
+enum RuleLine {
+    Rule(RecurringRule),
+    /// The newest `r<n>` id given out, so removed ids are never reused.
+    LastId(u32),
+    /// A line that is not a rule, kept as written.
+    Other(String),
+}
+
+/// The rules in a journal root's `recurring.md`.
+#[derive(Debug, Clone)]
+pub struct RecurringRules {
+    path: PathBuf,
+    lines: Vec<RuleLine>,
+}
+
+impl RecurringRules {
+    /// No rules, saved to `root` once one is added.
+    pub fn empty(root: &Path) -> Self {
+        Self {
+            path: root.join(RECURRING_FILE_NAME),
+            lines: Vec::new(),
+        }
+    }
+
+    pub fn load(root: &Path) -> io::Result<Self> {
+        let mut rules = Self::empty(root);
+        if rules.path.exists() {
+            rules.lines = fs::read_to_string(&rules.path)?
+                .lines()
+                .map(|line| match RecurringRule::parse(line) {
+                    Some(rule) => RuleLine::Rule(rule),
+                    None => match parse_last_id(line) {
+                        Some(last) => RuleLine::LastId(last),
+                        None => RuleLine::Other(line.to_string()),
+                    },
+                })
+                .collect();
+        }
+
+        Ok(rules)
+    }
+
+    pub fn rules(&self) -> impl Iterator<Item = &RecurringRule> {
+        self.lines.iter().filter_map(|line| match line {
+            RuleLine::Rule(rule) => Some(rule),
+            RuleLine::LastId(_) | RuleLine::Other(_) => None,
+        })
+    }
+
+    /// Adds a rule with a new `r<n>` id, one past the newest id ever given
+    /// out. Ids of removed rules are not reused, because day files may still
+    /// name them.
+    pub fn add(
+        &mut self,
+        recurrence: Recurrence,
+        start: NaiveDate,
+        entry: JournalEntry,
+    ) -> RecurringRule {
+        let recorded = self.lines.iter().filter_map(|line| match line {
+            RuleLine::LastId(last) => Some(*last),
+            _ => None,
+        });
+        let next = self
+            .rules()
+            .filter_map(RecurringRule::number)
+            .chain(recorded)
+            .max()
+            .unwrap_or(0)
+            + 1;
+
+        let rule = RecurringRule {
+            id: format!("r{next}"),
+            recurrence,
+            start,
+            entry,
+            skipped: Vec::new(),
+        };
+        self.lines
+            .retain(|line| !matches!(line, RuleLine::LastId(_)));
+        self.lines.push(RuleLine::Rule(rule.clone()));
+        self.lines.push(RuleLine::LastId(next));
+        rule
+    }
+
+    pub fn remove(&mut self, id: &str) -> Option<RecurringRule> {
+        let index = self
+            .lines
+            .iter()
+            .position(|line| matches!(line, RuleLine::Rule(rule) if rule.id == id))?;
+
+        match self.lines.remove(index) {
+            RuleLine::Rule(rule) => Some(rule),
+            RuleLine::LastId(_) | RuleLine::Other(_) => None,
+        }
+    }
+
+    /// Stops rule `id` from adding its occurrence to `date` again. Returns
+    /// whether that changed anything.
+    pub fn skip(&mut self, id: &str, date: NaiveDate) -> bool {
+        let rule = self.lines.iter_mut().find_map(|line| match line {
+            RuleLine::Rule(rule) if rule.id == id && rule.occurs_on(date) => Some(rule),
+            _ => None,
+        });
+        let Some(rule) = rule else {
+            return false;
+        };
+
+        if let Err(index) = rule.skipped.binary_search(&date) {
+            rule.skipped.insert(index, date);
+        }
+        true
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut contents = String::new();
+        for line in &self.lines {
+            match line {
+                RuleLine::Rule(rule) => contents.push_str(&format!("- {rule}")),
+                RuleLine::LastId(last) => contents.push_str(&format!("<!-- last id r{last} -->")),
+                RuleLine::Other(line) => contents.push_str(line),
+            }
+            contents.push('\n');
+        }
+        fs::write(&self.path, contents)
+    }
+
+    /// Adds an occurrence of every matching rule that the journal does not
+    /// already hold. Returns the number of entries added.
+    pub fn apply(&self, journal: &mut Journal) -> usize {
+        let mut added = 0;
+        for rule in self.rules().filter(|rule| rule.occurs_on(journal.date)) {
+            let exists = journal
+                .entries
+                .iter()
+                .any(|entry| entry.recurrence.as_deref() == Some(rule.id.as_str()));
+            if !exists {
+                journal.entries.push(rule.occurrence(journal.date));
+                added += 1;
+            }
+        }
+        added
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{
+        env,
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    fn day(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+    }
+
+    fn temp_root() -> PathBuf {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        env::temp_dir().join(format!("bullet-journal-recurring-test-{unique}"))
+    }
+
+    fn rule(recurrence: Recurrence, start: NaiveDate) -> RecurringRule {
+        RecurringRule {
+            id: String::from("r1"),
+            recurrence,
+            start,
+            entry: JournalEntry::new(EntryKind::Task, "standup", start),
+            skipped: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn parses_and_formats_recurrences() {
+        for input in [
+            "daily",
+            "weekdays",
+            "weekly mon",
+            "monthly 31",
+            "every 3 days",
+        ] {
+            assert_eq!(Recurrence::parse(input).unwrap().to_string(), input);
+        }
+
+        assert_eq!(
+            Recurrence::parse("Weekly Friday"),
+            Some(Recurrence::Weekly(Weekday::Fri))
+        );
+        assert_eq!(Recurrence::parse("monthly 32"), None);
+        assert_eq!(Recurrence::parse("every 0 days"), None);
+        assert_eq!(Recurrence::parse("sometimes"), None);
+    }
+
+    #[test]
+    fn matches_days_from_the_start_date() {
+        // 2026-05-18 is a Monday.
+        let weekdays = rule(Recurrence::Weekdays, day(5, 18));
+        assert!(weekdays.occurs_on(day(5, 22)));
+        assert!(!weekdays.occurs_on(day(5, 23)));
+        assert!(!weekdays.occurs_on(day(5, 15)));
+
+        let weekly = rule(Recurrence::Weekly(Weekday::Mon), day(5, 18));
+        assert!(weekly.occurs_on(day(5, 25)));
+        assert!(!weekly.occurs_on(day(5, 26)));
+
+        let every_three = rule(Recurrence::EveryDays(3), day(5, 18));
+        assert!(every_three.occurs_on(day(5, 18)));
+        assert!(every_three.occurs_on(day(5, 24)));
+        assert!(!every_three.occurs_on(day(5, 25)));
+
+        let month_end = rule(Recurrence::Monthly(31), day(1, 1));
+        assert!(month_end.occurs_on(day(5, 31)));
+        assert!(month_end.occurs_on(day(6, 30)));
+        assert!(month_end.occurs_on(day(2, 28)));
+        assert!(!month_end.occurs_on(day(6, 29)));
+
+        let mut skipping = rule(Recurrence::Daily, day(5, 18));
+        skipping.skipped = vec![day(5, 20)];
+        assert!(skipping.occurs_on(day(5, 19)));
+        assert!(!skipping.occurs_on(day(5, 20)));
+    }
+
+    #[test]
+    fn round_trips_rules_and_keeps_other_lines() -> io::Result<()> {
+        let root = temp_root();
+        fs::create_dir_all(&root)?;
+        let contents = "# Recurring\n\n- r1 weekly mon from 2026-05-18: ◦ 14:00 1:1\n- r3 daily from 2026-05-01: · standup\n";
+        fs::write(root.join(RECURRING_FILE_NAME), contents)?;
+
+        let mut rules = RecurringRules::load(&root)?;
+        let ids = rules
+            .rules()
+            .map(|rule| rule.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, ["r1", "r3"]);
+        rules.save()?;
+        assert_eq!(
+            fs::read_to_string(root.join(RECURRING_FILE_NAME))?,
+            contents
+        );
+
+        let entry = JournalEntry::new(EntryKind::Note, "invoice", day(5, 21));
+        let added = rules.add(Recurrence::Monthly(1), day(5, 21), entry);
+        assert_eq!(added.id, "r4");
+        assert!(rules.remove("r1").is_some());
+        assert!(rules.remove("r1").is_none());
+        rules.save()?;
+        assert_eq!(
+            fs::read_to_string(root.join(RECURRING_FILE_NAME))?,
+            "# Recurring\n\n- r3 daily from 2026-05-01: · standup\n- r4 monthly 1 from 2026-05-21: - invoice\n<!-- last id r4 -->\n"
+        );
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn never_reuses_the_id_of_a_removed_rule() -> io::Result<()> {
+        let root = temp_root();
+        let entry = JournalEntry::new(EntryKind::Task, "standup", day(5, 18));
+
+        let mut rules = RecurringRules::load(&root)?;
+        assert_eq!(
+            rules.add(Recurrence::Daily, day(5, 18), entry.clone()).id,
+            "r1"
+        );
+        assert_eq!(
+            rules
+                .add(Recurrence::Weekdays, day(5, 18), entry.clone())
+                .id,
+            "r2"
+        );
+        assert!(rules.remove("r2").is_some());
+        rules.save()?;
+
+        let mut rules = RecurringRules::load(&root)?;
+        assert_eq!(rules.add(Recurrence::Daily, day(5, 18), entry).id, "r3");
+        rules.save()?;
+        assert_eq!(
+            fs::read_to_string(root.join(RECURRING_FILE_NAME))?
+                .matches("last id")
+                .count(),
+            1
+        );
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn skips_days_whose_occurrence_was_dismissed() -> io::Result<()> {
+        let root = temp_root();
+        fs::create_dir_all(&root)?;
+        fs::write(
+            root.join(RECURRING_FILE_NAME),
+            "- r2 daily from 2026-05-18 except 2026-05-19: · standup\n",
+        )?;
+
+        let mut rules = RecurringRules::load(&root)?;
+        assert!(rules.skip("r2", day(5, 21)));
+        assert!(!rules.skip("r2", day(5, 21)));
+        assert!(!rules.skip("r2", day(5, 17)));
+        assert!(!rules.skip("r9", day(5, 22)));
+        rules.save()?;
+        assert_eq!(
+            fs::read_to_string(root.join(RECURRING_FILE_NAME))?,
+            "- r2 daily from 2026-05-18 except 2026-05-19 2026-05-21: · standup\n"
+        );
+
+        let rules = RecurringRules::load(&root)?;
+        for (date, expected) in [(day(5, 19), 0), (day(5, 20), 1), (day(5, 21), 0)] {
+            let mut journal = Journal::load_for_date(&root, date)?;
+            assert_eq!(rules.apply(&mut journal), expected);
+        }
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn adds_each_occurrence_once() -> io::Result<()> {
+        let root = temp_root();
+        fs::create_dir_all(&root)?;
+        fs::write(
+            root.join(RECURRING_FILE_NAME),
+            "- r1 weekly mon from 2026-05-18: ◦ 14:00 1:1\n- r2 daily from 2026-05-18: · standup\n",
+        )?;
+
+        let rules = RecurringRules::load(&root)?;
+        let mut journal = Journal::load_for_date_with_rules(&root, day(5, 25), &rules)?;
+        let texts = journal
+            .entries
+            .iter()
+            .map(|entry| entry.text.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(texts, ["1:1", "standup"]);
+        assert_eq!(journal.entries[0].time.unwrap().to_string(), "14:00");
+        assert!(!journal.path().exists());
+
+        journal.entries[1].toggle_complete().unwrap();
+        journal.save()?;
+        assert_eq!(
+            fs::read_to_string(journal.path())?,
+            "  ◦ 14:00 1:1 <!-- recur r1 -->\n  X standup <!-- recur r2 -->\n"
+        );
+
+        let mut reloaded = Journal::load_for_date(&root, day(5, 25))?;
+        assert_eq!(rules.apply(&mut reloaded), 0);
+        assert_eq!(reloaded.entries, journal.entries);
+
+        let mut before_start = Journal::load_for_date(&root, day(5, 17))?;
+        assert_eq!(rules.apply(&mut before_start), 0);
+        assert!(before_start.entries.is_empty());
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+}
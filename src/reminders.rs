@@ -0,0 +1,315 @@
+//! Reminders for timed events and open tasks, and the scheduler that decides
+//! when each one fires.
+
+use std::{collections::HashMap, fmt};
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
+
+use crate::{
+    dates::parse_time_of_day,
+    journal::{EntryKind, EntryState, Journal},
+    settings::Settings,
+};
+
+const DEFAULT_LEAD_MINUTES: i64 = 10;
+const DEFAULT_TASK_TIME: (u32, u32) = (9, 0);
+
+/// How reminders are raised, read from `settings.md`:
+///
+/// - `reminder minutes: 10` fires event reminders that many minutes early.
+/// - `reminder bell: on` rings the terminal bell. Use `off` to keep quiet.
+/// - `reminder command: notify-send Journal "$1"` runs a shell command with
+///   the reminder text as `$1`.
+/// - `task reminder time: 09:00` reminds about the day's open tasks from that
+///   time. Use `off` to skip tasks.
+///
+/// Values that cannot be read fall back to the defaults.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReminderSettings {
+    pub lead: TimeDelta,
+    pub bell: bool,
+    pub command: Option<String>,
+    pub task_time: Option<NaiveTime>,
+}
+
+impl ReminderSettings {
+    pub fn from_settings(settings: &Settings) -> Self {
+        let lead = settings
+            .get("reminder minutes")
+            .and_then(|minutes| minutes.parse::<u32>().ok())
+            .map_or(DEFAULT_LEAD_MINUTES, i64::from);
+        let task_time = match settings.get("task reminder time") {
+            Some(value) if is_off(value) => None,
+            value => value
+                .and_then(parse_time_of_day)
+                .or(NaiveTime::from_hms_opt(
+                    DEFAULT_TASK_TIME.0,
+                    DEFAULT_TASK_TIME.1,
+                    0,
+                )),
+        };
+
+        Self {
+            lead: TimeDelta::minutes(lead),
+            bell: !settings.get("reminder bell").is_some_and(is_off),
+            command: settings
+                .get("reminder command")
+                .filter(|command| !command.is_empty())
+                .map(str::to_string),
+            task_time,
+        }
+    }
+}
+
+fn is_off(value: &str) -> bool {
+    value.eq_ignore_ascii_case("off")
+}
+
+impl Default for ReminderSettings {
+    fn default() -> Self {
+        Self::from_settings(&Settings::default())
+    }
+}
+
+/// Something to remind about. It is due from `due` until `until`, after which
+/// it is no longer worth raising.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reminder {
+    pub date: NaiveDate,
+    pub text: String,
+    pub due: NaiveDateTime,
+    pub until: NaiveDateTime,
+    /// When the entry was created, if its line records it.
+    pub created: Option<NaiveDateTime>,
+    /// How many entries above this one on its day share its text and created
+    /// time, so duplicate entries keep their own reminders.
+    pub copy: usize,
+}
+
+impl Reminder {
+    /// Identifies the reminder across ticks, so it fires once until snoozed.
+    pub fn key(&self) -> String {
+        let created = self
+            .created
+            .map(|created| created.format("%Y-%m-%dT%H:%M").to_string())
+            .unwrap_or_default();
+        format!(
+            "{} {created} {} {}",
+            self.date.format("%Y-%m-%d"),
+            self.copy,
+            self.text
+        )
+    }
+
+    pub fn is_due(&self, now: NaiveDateTime) -> bool {
+        self.due <= now && now <= self.until
+    }
+}
+
+impl fmt::Display for Reminder {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{} {}", self.date.format("%Y-%m-%d"), self.text)
+    }
+}
+
+/// Reminders for a day's open timed events and, when enabled, its open tasks.
+pub fn reminders_for(journal: &Journal, settings: &ReminderSettings) -> Vec<Reminder> {
+    let end_of_day = journal.date.and_time(NaiveTime::MIN) + TimeDelta::days(1);
+
+    journal
+        .entries
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| entry.state == EntryState::Open)
+        .filter_map(|(index, entry)| {
+            let copy = journal.entries[..index]
+                .iter()
+                .filter(|other| other.text == entry.text && other.created_at == entry.created_at)
+                .count();
+            match (entry.kind, entry.time) {
+                (EntryKind::Event, Some(time)) => {
+                    let start = journal.date.and_time(time.start);
+                    Some(Reminder {
+                        date: journal.date,
+                        text: format!("{time} {}", entry.text),
+                        due: start - settings.lead,
+                        until: journal.date.and_time(time.end_or_start()).max(start),
+                        created: entry.created_at,
+                        copy,
+                    })
+                }
+                (EntryKind::Task, _) => settings.task_time.map(|task_time| Reminder {
+                    date: journal.date,
+                    text: format!("task: {}", entry.text),
+                    due: journal.date.and_time(task_time),
+                    until: end_of_day,
+                    created: entry.created_at,
+                    copy,
+                }),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Reminders that became due in the `window` before `now`. The `remind`
+/// subcommand uses this, so running it every `window` prints each reminder
+/// once.
+pub fn due_within(reminders: &[Reminder], now: NaiveDateTime, window: TimeDelta) -> Vec<Reminder> {
+    reminders
+        .iter()
+        .filter(|reminder| reminder.is_due(now) && now - reminder.due < window)
+        .cloned()
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReminderState {
+    Fired,
+    Snoozed(NaiveDateTime),
+    Dismissed,
+}
+
+/// Remembers which reminders have fired, been snoozed or been dismissed during
+/// this session.
+#[derive(Debug, Clone, Default)]
+pub struct ReminderScheduler {
+    states: HashMap<String, ReminderState>,
+}
+
+impl ReminderScheduler {
+    /// Returns the reminders that fire now and marks them fired. A reminder
+    /// fires when it first becomes due and again when its snooze ends.
+    pub fn fire(&mut self, reminders: &[Reminder], now: NaiveDateTime) -> Vec<Reminder> {
+        reminders
+            .iter()
+            .filter(|reminder| reminder.is_due(now))
+            .filter(|reminder| {
+                let key = reminder.key();
+                let fires = match self.states.get(&key) {
+                    None => true,
+                    Some(ReminderState::Snoozed(until)) => *until <= now,
+                    Some(ReminderState::Fired | ReminderState::Dismissed) => false,
+                };
+                if fires {
+                    self.states.insert(key, ReminderState::Fired);
+                }
+                fires
+            })
+            .cloned()
+            .collect()
+    }
+
+    pub fn snooze(&mut self, reminder: &Reminder, until: NaiveDateTime) {
+        self.states
+            .insert(reminder.key(), ReminderState::Snoozed(until));
+    }
+
+    pub fn dismiss(&mut self, reminder: &Reminder) {
+        self.states.insert(reminder.key(), ReminderState::Dismissed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::journal::parse_markdown;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 5, 21).unwrap()
+    }
+
+    fn at(hour: u32, minute: u32) -> NaiveDateTime {
+        date().and_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    fn journal(contents: &str) -> Journal {
+        let root = std::env::temp_dir().join("bullet-journal-reminders-test-missing");
+        let mut journal = Journal::load_for_date(&root, date()).unwrap();
+        journal.entries = parse_markdown(contents, date());
+        journal
+    }
+
+    #[test]
+    fn reads_reminder_settings_with_defaults() {
+        let defaults = ReminderSettings::default();
+        assert_eq!(defaults.lead, TimeDelta::minutes(10));
+        assert!(defaults.bell);
+        assert_eq!(defaults.command, None);
+        assert_eq!(defaults.task_time, NaiveTime::from_hms_opt(9, 0, 0));
+
+        let settings = ReminderSettings::from_settings(&Settings::parse(
+            "- reminder minutes: 5\n- reminder bell: off\n- reminder command: notify-send \"$1\"\n- task reminder time: off\n",
+        ));
+        assert_eq!(settings.lead, TimeDelta::minutes(5));
+        assert!(!settings.bell);
+        assert_eq!(settings.command.as_deref(), Some("notify-send \"$1\""));
+        assert_eq!(settings.task_time, None);
+    }
+
+    #[test]
+    fn reminds_about_open_timed_events_and_tasks() {
+        let journal = journal(
+            "  ◦ 14:00-15:00 design review\n  ◦ ~~13:00 cancelled lunch~~\n  ◦ untimed\n  · call dentist\n  X done\n",
+        );
+        let reminders = reminders_for(&journal, &ReminderSettings::default());
+
+        assert_eq!(reminders.len(), 2);
+        assert_eq!(reminders[0].text, "14:00-15:00 design review");
+        assert_eq!(reminders[0].due, at(13, 50));
+        assert_eq!(reminders[0].until, at(15, 0));
+        assert_eq!(reminders[1].text, "task: call dentist");
+        assert_eq!(reminders[1].due, at(9, 0));
+        assert!(reminders[1].is_due(at(23, 59)));
+    }
+
+    #[test]
+    fn fires_once_until_snoozed_and_never_after_dismissal() {
+        let reminders = reminders_for(
+            &journal("  ◦ 14:00 standup\n  ◦ 16:00 retro\n"),
+            &ReminderSettings::default(),
+        );
+        let mut scheduler = ReminderScheduler::default();
+
+        assert!(scheduler.fire(&reminders, at(13, 0)).is_empty());
+        let fired = scheduler.fire(&reminders, at(13, 50));
+        assert_eq!(fired, reminders[..1]);
+        assert!(scheduler.fire(&reminders, at(13, 51)).is_empty());
+
+        scheduler.snooze(&fired[0], at(13, 55));
+        assert!(scheduler.fire(&reminders, at(13, 54)).is_empty());
+        assert_eq!(scheduler.fire(&reminders, at(13, 55)), reminders[..1]);
+
+        scheduler.dismiss(&reminders[1]);
+        assert!(scheduler.fire(&reminders, at(15, 55)).is_empty());
+        assert!(scheduler.fire(&reminders, at(14, 1)).is_empty());
+    }
+
+    #[test]
+    fn keeps_separate_state_for_duplicate_entries() {
+        let reminders = reminders_for(
+            &journal("  · call dentist\n  ◦ 14:00 standup\n  · call dentist\n"),
+            &ReminderSettings::default(),
+        );
+        let mut scheduler = ReminderScheduler::default();
+
+        assert_eq!(reminders.len(), 3);
+        assert_ne!(reminders[0].key(), reminders[2].key());
+        scheduler.dismiss(&reminders[0]);
+        assert_eq!(scheduler.fire(&reminders, at(9, 0)), reminders[2..]);
+    }
+
+    #[test]
+    fn prints_reminders_that_became_due_within_the_window() {
+        let reminders = reminders_for(
+            &journal("  ◦ 14:00 standup\n  ◦ 16:00 retro\n"),
+            &ReminderSettings::default(),
+        );
+        let window = TimeDelta::minutes(5);
+
+        assert!(due_within(&reminders, at(13, 49), window).is_empty());
+        assert_eq!(due_within(&reminders, at(13, 54), window), reminders[..1]);
+        assert!(due_within(&reminders, at(13, 55), window).is_empty());
+    }
+}
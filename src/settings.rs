@@ -0,0 +1,57 @@
+//! User settings stored as `- key: value` lines in `settings.md`.
+
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+pub const SETTINGS_FILE_NAME: &str = "settings.md";
+
+/// Settings read from the journal root. Keys are matched without regard to
+/// case, and lines that are not `- key: value` are ignored so the file can
+/// hold headings and notes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Settings {
+    values: BTreeMap<String, String>,
+}
+
+impl Settings {
+    /// Reads `settings.md`. A missing file gives the defaults.
+    pub fn load(root: &Path) -> io::Result<Self> {
+        match fs::read_to_string(root.join(SETTINGS_FILE_NAME)) {
+            Ok(contents) => Ok(Self::parse(&contents)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Self {
+        let values = contents
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("- "))
+            .filter_map(|line| line.split_once(':'))
+            .map(|(key, value)| (key.trim().to_lowercase(), value.trim().to_string()))
+            .filter(|(key, _)| !key.is_empty())
+            .collect();
+
+        Self { values }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_key_value_lines_and_ignores_the_rest() {
+        let settings = Settings::parse(
+            "# Settings\n\n- Reminder minutes: 15\n- reminder command: notify-send \"$1\"\nnot a setting: 1\n- no value\n",
+        );
+
+        assert_eq!(settings.get("reminder minutes"), Some("15"));
+        assert_eq!(settings.get("reminder command"), Some("notify-send \"$1\""));
+        assert_eq!(settings.get("not a setting"), None);
+        assert_eq!(settings.get("no value"), None);
+    }
+}
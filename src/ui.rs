@@ -1,41 +1,380 @@
+use chrono::{Datelike, NaiveDate, NaiveTime};
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    widgets::{
+        Block, Borders, Clear, List, ListItem, ListState, Paragraph, Scrollbar,
+        ScrollbarOrientation, ScrollbarState, Wrap,
+    },
     Frame,
 };
 
 use crate::{
-    app::{App, CommandPaneMode, Focus, SplitJournalView, SplitPane},
-    journal::{EntryKind, Journal, JournalEntry},
+    app::{
+        help_line_count, App, CommandPaneMode, Focus, HelpSection, HitMap, HitTarget, Message,
+        Overlay, Severity, SplitJournalView,
+    },
+    calendar::{CalendarPicker, DayMark},
+    inline::{parse_inline, Inline},
+    journal::{state_change_label, EntryKind, EntryState, Journal, JournalEntry},
+    notes::LoadedNote,
 };
 
 const SPLIT_SIDE_BY_SIDE_MIN_WIDTH: u16 = 100;
 
-pub fn draw(frame: &mut Frame, app: &App) {
+/// The narrowest journal area that has the detail note beside the list
+/// rather than below it.
+const DETAIL_SIDE_BY_SIDE_MIN_WIDTH: u16 = 80;
+
+const REMINDER_BANNER_LIMIT: usize = 3;
+
+const LEADER_MENU_WIDTH: u16 = 30;
+
+/// Entries kept in view above and below the highlight as the list scrolls.
+const SCROLL_OFF: usize = 2;
+
+/// Draws the app and returns where its mouse targets ended up.
+pub fn draw(frame: &mut Frame, app: &App) -> HitMap {
+    let mut hits = HitMap::default();
     let command_height = command_pane_height(app);
+    let reminder_lines = reminder_lines(app);
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Min(1), Constraint::Length(command_height)])
+        .constraints([
+            Constraint::Min(1),
+            Constraint::Length(reminder_lines.len() as u16),
+            Constraint::Length(command_height),
+            Constraint::Length(1),
+        ])
         .split(frame.area());
 
-    draw_journal_area(frame, chunks[0], app);
-    draw_command(frame, chunks[1], app);
+    draw_journal_area(frame, chunks[0], app, &mut hits);
+    frame.render_widget(
+        Paragraph::new(reminder_lines).style(Style::default().fg(Color::Black).bg(Color::Yellow)),
+        chunks[1],
+    );
+    draw_command(frame, chunks[2], app, &mut hits);
+    draw_status_bar(frame, chunks[3], app);
+
+    if let Some(overlay) = &app.overlay {
+        draw_overlay(frame, app, overlay);
+    }
+
+    hits
+}
+
+fn draw_overlay(frame: &mut Frame, app: &App, overlay: &Overlay) {
+    let (title, lines, width) = match overlay {
+        Overlay::Recurring(rules) => {
+            let mut lines = rules
+                .iter()
+                .map(|rule| Line::from(rule.to_string()))
+                .collect::<Vec<_>>();
+            if lines.is_empty() {
+                lines.push(Line::from(Span::styled(
+                    "No recurring rules. Add one with :recur weekly mon :t <text>.",
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+            (
+                String::from("Recurring"),
+                lines,
+                frame.area().width * 8 / 10,
+            )
+        }
+        Overlay::Calendar(picker) => (
+            picker.month().format("%B %Y").to_string(),
+            calendar_lines(picker, app.today()),
+            CALENDAR_WIDTH,
+        ),
+        Overlay::Messages { scroll } => {
+            let rows = (frame.area().height * 8 / 10).saturating_sub(2).max(1) as usize;
+            let messages = app.messages();
+            let end = messages.len() - (*scroll).min(messages.len());
+            let start = end.saturating_sub(rows);
+            (
+                format!("Messages {end}/{}", messages.len()),
+                messages[start..end].iter().map(message_line).collect(),
+                frame.area().width * 8 / 10,
+            )
+        }
+        Overlay::Leader { keys } => {
+            let lines = app
+                .leader_menu(keys)
+                .into_iter()
+                .map(|item| {
+                    let label = if item.is_group {
+                        Span::styled(format!("+{}", item.label), Style::default().fg(Color::Cyan))
+                    } else {
+                        Span::raw(item.label)
+                    };
+                    Line::from(vec![
+                        Span::styled(
+                            format!(" {}  ", item.key),
+                            Style::default().add_modifier(Modifier::BOLD),
+                        ),
+                        label,
+                    ])
+                })
+                .collect();
+            let mut title = String::from("Leader");
+            for key in keys.chars() {
+                title.push(' ');
+                title.push(key);
+            }
+            (title, lines, LEADER_MENU_WIDTH)
+        }
+        Overlay::Help { sections, scroll } => {
+            let rows = (frame.area().height * 8 / 10).saturating_sub(2).max(1) as usize;
+            let total = help_line_count(sections);
+            let start = (*scroll).min(total.saturating_sub(rows));
+            let end = (start + rows).min(total);
+            (
+                format!("Help {}-{end}/{total}", start + 1),
+                help_lines(sections)
+                    .into_iter()
+                    .skip(start)
+                    .take(rows)
+                    .collect(),
+                frame.area().width * 8 / 10,
+            )
+        }
+        Overlay::Links {
+            links,
+            selected,
+            copy,
+        } => (
+            String::from(if *copy { "Copy link" } else { "Open link" }),
+            links
+                .iter()
+                .enumerate()
+                .map(|(index, link)| {
+                    if index == *selected {
+                        Line::from(vec![
+                            Span::styled("> ", Style::default().fg(Color::Cyan)),
+                            Span::styled(
+                                link.clone(),
+                                Style::default().add_modifier(Modifier::BOLD),
+                            ),
+                        ])
+                    } else {
+                        Line::from(format!("  {link}"))
+                    }
+                })
+                .collect(),
+            frame.area().width * 8 / 10,
+        ),
+    };
+
+    let area = centered_rect(frame.area(), width, lines.len() as u16 + 2);
+    let block = Block::default()
+        .title(title)
+        .title_bottom(Line::from(" Esc to close ").right_aligned())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// The latest message on the left, and any pending keys, the focused day,
+/// input mode and focus on the right.
+fn draw_status_bar(frame: &mut Frame, area: Rect, app: &App) {
+    let mode = match (app.overlay.is_some(), app.command_mode) {
+        (true, _) => "popup",
+        (false, CommandPaneMode::Normal) if app.visual_range().is_some() => "visual",
+        (false, CommandPaneMode::Normal) => "normal",
+        (false, CommandPaneMode::Search) => "search",
+        (false, CommandPaneMode::Entry(_)) => "entry",
+    };
+    let focus = match app.focus {
+        Focus::Journal => "journal",
+        Focus::Command => "command",
+    };
+    let mut context = format!(
+        " {} · {mode} · {focus} ",
+        app.journal.date.format("%a %Y-%m-%d")
+    );
+    let pending = app.pending_keys();
+    if !pending.is_empty() {
+        context.insert_str(0, &format!(" {pending} ·"));
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Min(1),
+            Constraint::Length(context.chars().count() as u16),
+        ])
+        .split(area);
+
+    let message = app.latest_message().map(message_line).unwrap_or_default();
+    frame.render_widget(Paragraph::new(message), chunks[0]);
+    frame.render_widget(
+        Paragraph::new(context).style(Style::default().fg(Color::DarkGray)),
+        chunks[1],
+    );
+}
+
+/// A message styled by its severity, with a count when it was repeated.
+fn message_line(message: &Message) -> Line<'static> {
+    let style = match message.severity {
+        Severity::Info => Style::default(),
+        Severity::Warning => Style::default().fg(Color::Yellow),
+        Severity::Error => Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+    };
+
+    let mut spans = vec![Span::raw(" "), Span::styled(message.text.clone(), style)];
+    if message.repeats > 1 {
+        spans.push(Span::styled(
+            format!(" ×{}", message.repeats),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    Line::from(spans)
+}
+
+/// Section titles in bold, then each key or command in a column wide enough
+/// for the longest one.
+fn help_lines(sections: &[HelpSection]) -> Vec<Line<'static>> {
+    let width = sections
+        .iter()
+        .flat_map(|section| &section.rows)
+        .map(|(key, _)| key.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut lines = Vec::new();
+    for section in sections {
+        if !lines.is_empty() {
+            lines.push(Line::default());
+        }
+        lines.push(Line::from(Span::styled(
+            section.title.clone(),
+            Style::default().add_modifier(Modifier::BOLD),
+        )));
+        lines.extend(section.rows.iter().map(|(key, description)| {
+            Line::from(vec![
+                Span::styled(
+                    format!("  {key:<width$}  "),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::raw(*description),
+            ])
+        }));
+    }
+    lines
 }
 
-fn draw_journal_area(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+/// The banner of fired reminders, at most `REMINDER_BANNER_LIMIT` rows.
+fn reminder_lines(app: &App) -> Vec<Line<'static>> {
+    let mut lines = app
+        .reminders
+        .iter()
+        .take(REMINDER_BANNER_LIMIT)
+        .map(|reminder| Line::from(format!(" Reminder: {reminder}")))
+        .collect::<Vec<_>>();
+
+    let hidden = app.reminders.len().saturating_sub(REMINDER_BANNER_LIMIT);
+    if hidden > 0 {
+        if let Some(last) = lines.last_mut() {
+            *last = Line::from(format!(" and {} more reminders", hidden + 1));
+        }
+    }
+    if let Some(first) = lines.first_mut() {
+        first.spans.push(Span::styled(
+            "  :snooze or :dismiss",
+            Style::default().add_modifier(Modifier::ITALIC),
+        ));
+    }
+
+    lines
+}
+
+/// Seven three-column days with a space between them, plus the borders.
+const CALENDAR_WIDTH: u16 = 7 * 4 - 1 + 2;
+
+/// The month grid. Days with a file are marked `·`, days with open tasks `•`,
+/// today is underlined and the highlighted day is reversed.
+fn calendar_lines(picker: &CalendarPicker, today: NaiveDate) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from(Span::styled(
+        "Mo  Tu  We  Th  Fr  Sa  Su",
+        Style::default().fg(Color::DarkGray),
+    ))];
+
+    for week in picker.weeks() {
+        let mut spans = Vec::new();
+        for (column, date) in week.into_iter().enumerate() {
+            if column > 0 {
+                spans.push(Span::raw(" "));
+            }
+            let Some(date) = date else {
+                spans.push(Span::raw("   "));
+                continue;
+            };
+
+            let (marker, mut style) = match picker.mark(date) {
+                Some(DayMark::OpenTasks) => ('•', Style::default().fg(Color::Yellow)),
+                Some(DayMark::File) => ('·', Style::default()),
+                None => (' ', Style::default().fg(Color::DarkGray)),
+            };
+            if date == today {
+                style = style.add_modifier(Modifier::UNDERLINED);
+            }
+            if date == picker.selected {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+            spans.push(Span::styled(format!("{:>2}{marker}", date.day()), style));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    lines.push(Line::from(Span::styled(
+        "• open tasks  · entries",
+        Style::default().fg(Color::DarkGray),
+    )));
+    lines
+}
+
+/// A rect of at most `width` columns and `height` rows, centered in `area`.
+fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+fn draw_journal_area(frame: &mut Frame, area: ratatui::layout::Rect, app: &App, hits: &mut HitMap) {
+    let area = match app.detail_note() {
+        Some(note) => {
+            let (journal, detail) = detail_note_areas(area);
+            draw_detail_note(frame, detail, note);
+            journal
+        }
+        None => area,
+    };
+
     if let Some(split) = app.split_view() {
-        draw_split_journal(frame, area, app, split);
+        draw_split_journal(frame, area, app, split, hits);
     } else {
-        draw_journal(
+        hits.push(area, HitTarget::Pane(0));
+        let rows = draw_journal(
             frame,
             area,
+            app,
             &app.journal,
             app.selected,
             matches!(app.focus, Focus::Journal),
             matches!(app.focus, Focus::Journal),
         );
+        for (row, index) in rows {
+            hits.push(row, HitTarget::Entry { pane: 0, index });
+        }
     }
 }
 
@@ -44,56 +383,154 @@ fn draw_split_journal(
     area: ratatui::layout::Rect,
     app: &App,
     split: &SplitJournalView,
+    hits: &mut HitMap,
 ) {
-    let direction = if area.width >= SPLIT_SIDE_BY_SIDE_MIN_WIDTH {
+    for (index, (pane, area)) in split
+        .panes
+        .iter()
+        .zip(split_pane_areas(area, split.panes.len()))
+        .enumerate()
+    {
+        hits.push(area, HitTarget::Pane(index));
+        let rows = draw_journal(
+            frame,
+            area,
+            app,
+            &pane.journal,
+            pane.selected,
+            split.active == index,
+            matches!(app.focus, Focus::Journal) && split.active == index,
+        );
+        for (row, entry) in rows {
+            hits.push(
+                row,
+                HitTarget::Entry {
+                    pane: index,
+                    index: entry,
+                },
+            );
+        }
+    }
+}
+
+/// Splits the journal area between the list and the detail note, side by side
+/// when there is room and stacked otherwise.
+fn detail_note_areas(area: Rect) -> (Rect, Rect) {
+    let direction = if area.width >= DETAIL_SIDE_BY_SIDE_MIN_WIDTH {
         Direction::Horizontal
     } else {
         Direction::Vertical
     };
-
     let chunks = Layout::default()
         .direction(direction)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
         .split(area);
+    (chunks[0], chunks[1])
+}
 
-    draw_journal(
-        frame,
-        chunks[0],
-        &split.older.journal,
-        split.older.selected,
-        split.active == SplitPane::Older,
-        matches!(app.focus, Focus::Journal) && split.active == SplitPane::Older,
-    );
-    draw_journal(
-        frame,
-        chunks[1],
-        &split.newer.journal,
-        split.newer.selected,
-        split.active == SplitPane::Newer,
-        matches!(app.focus, Focus::Journal) && split.active == SplitPane::Newer,
+/// Previews the highlighted entry's detail note. Headings are bold and the
+/// rest gets the same inline styling as entries.
+fn draw_detail_note(frame: &mut Frame, area: Rect, note: &LoadedNote) {
+    let dim = Style::default().fg(Color::DarkGray);
+    let mut lines = match &note.body {
+        Ok(body) => body
+            .lines()
+            .map(|line| {
+                if line.starts_with('#') {
+                    Line::from(Span::styled(
+                        line.to_string(),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ))
+                } else {
+                    Line::from(inline_spans(line, Style::default()))
+                }
+            })
+            .collect::<Vec<_>>(),
+        Err(message) => vec![Line::from(Span::styled(message.clone(), dim))],
+    };
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled("Empty note.", dim)));
+    }
+
+    let block = Block::default()
+        .title(format!("Details {}", note.name))
+        .borders(Borders::ALL)
+        .border_style(border_style(false));
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        area,
     );
 }
 
+/// Lays panes out in rows, as many side by side as fit at half the two-pane
+/// side-by-side width. Narrow screens stack every pane.
+fn split_pane_areas(area: Rect, count: usize) -> Vec<Rect> {
+    let pane_min_width = SPLIT_SIDE_BY_SIDE_MIN_WIDTH / 2;
+    let columns = usize::from(area.width / pane_min_width).clamp(1, count.max(1));
+    let rows = count.div_ceil(columns);
+
+    let row_areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Ratio(1, rows as u32); rows])
+        .split(area);
+
+    (0..count)
+        .collect::<Vec<_>>()
+        .chunks(columns)
+        .zip(row_areas.iter())
+        .flat_map(|(row, row_area)| {
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(vec![Constraint::Ratio(1, row.len() as u32); row.len()])
+                .split(*row_area)
+                .to_vec()
+        })
+        .collect()
+}
+
+/// Draws one day and returns the screen row of each visible entry.
 fn draw_journal(
     frame: &mut Frame,
     area: ratatui::layout::Rect,
+    app: &App,
     journal: &Journal,
     selected: Option<usize>,
     is_active: bool,
     show_selection: bool,
-) {
+) -> Vec<(Rect, usize)> {
     let block = Block::default()
         .title(journal.title())
         .borders(Borders::ALL)
         .border_style(border_style(is_active));
 
+    if app.show_timeline {
+        draw_timeline(frame, area, app, journal, block);
+        return Vec::new();
+    }
+    let inner = block.inner(area);
+
     let items = if journal.entries.is_empty() {
         vec![ListItem::new(Line::from(Span::styled(
             "No entries yet.",
             Style::default().fg(Color::DarkGray),
         )))]
     } else {
-        journal.entries.iter().map(entry_item).collect::<Vec<_>>()
+        let visual = app.visual_range().filter(|_| show_selection);
+        journal
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                let item = entry_item(entry, app.show_timestamps, inner.width as usize);
+                if visual.as_ref().is_some_and(|range| range.contains(&index)) {
+                    item.style(Style::default().bg(Color::Blue))
+                } else {
+                    item
+                }
+            })
+            .collect::<Vec<_>>()
     };
 
     let mut state = ListState::default();
@@ -101,65 +538,320 @@ fn draw_journal(
         state.select(selected);
     }
 
-    let list = List::new(items).block(block).highlight_style(
-        Style::default()
-            .fg(Color::White)
-            .bg(Color::DarkGray)
-            .add_modifier(Modifier::BOLD),
-    );
+    let heights = items.iter().map(ListItem::height).collect::<Vec<_>>();
+    let list = List::new(items)
+        .block(block)
+        .scroll_padding(SCROLL_OFF)
+        .highlight_style(
+            Style::default()
+                .fg(Color::White)
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        );
 
     frame.render_stateful_widget(list, area, &mut state);
+
+    let total = heights.iter().sum::<usize>();
+    let viewport = inner.height as usize;
+    let offset = state.offset().min(heights.len());
+    if total > viewport {
+        let top = heights[..offset].iter().sum::<usize>();
+        let mut scrollbar = ScrollbarState::new(total - viewport + 1)
+            .position(top)
+            .viewport_content_length(viewport);
+        frame.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None),
+            area.inner(Margin::new(0, 1)),
+            &mut scrollbar,
+        );
+    }
+
+    let mut rows = Vec::new();
+    let mut y = inner.y;
+    for (index, height) in heights
+        .iter()
+        .enumerate()
+        .take(journal.entries.len())
+        .skip(offset)
+    {
+        let row = Rect::new(inner.x, y, inner.width, *height as u16).intersection(inner);
+        if row.is_empty() {
+            break;
+        }
+        rows.push((row, index));
+        y = y.saturating_add(*height as u16);
+    }
+    rows
 }
 
-fn entry_item(entry: &JournalEntry) -> ListItem<'static> {
+/// An entry soft-wrapped to `width`, with later lines indented to start under
+/// the text after the signifier.
+fn entry_item(entry: &JournalEntry, show_timestamps: bool, width: usize) -> ListItem<'static> {
     let symbol = match entry.kind {
         EntryKind::Note => "-",
         EntryKind::Event => "◦",
         EntryKind::Feeling => "=",
-        EntryKind::Task => {
-            if entry.state == crate::journal::EntryState::Completed {
-                "X"
-            } else {
-                "·"
-            }
-        }
+        EntryKind::Task => match entry.state {
+            EntryState::Completed => "X",
+            EntryState::Migrated => "→",
+            EntryState::Open | EntryState::Cancelled => "·",
+        },
         EntryKind::Raw => "",
     };
 
     let text_style = if entry.is_struck() {
         Style::default().add_modifier(Modifier::CROSSED_OUT)
+    } else if entry.state == EntryState::Migrated {
+        Style::default().fg(Color::DarkGray)
     } else {
         Style::default()
     };
 
-    let mut spans = Vec::new();
+    let mut prefix = Vec::new();
     if entry.important {
-        spans.push(Span::styled(
+        prefix.push(Span::styled(
             "*",
             Style::default()
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
         ));
-        spans.push(Span::raw(" "));
+        prefix.push(Span::raw(" "));
     } else {
-        spans.push(Span::raw("  "));
+        prefix.push(Span::raw("  "));
     }
 
+    let mut spans = Vec::new();
     if entry.kind == EntryKind::Raw {
         spans.push(Span::styled(entry.text.clone(), text_style));
     } else {
-        spans.push(Span::styled(
+        prefix.push(Span::styled(
             symbol.to_string(),
             Style::default().fg(Color::Yellow),
         ));
-        spans.push(Span::raw(" "));
-        spans.push(Span::styled(entry.text.clone(), text_style));
+        prefix.push(Span::raw(" "));
+        if let Some(time) = entry.time {
+            spans.push(Span::styled(format!("{time} "), text_style.fg(Color::Cyan)));
+        }
+        spans.extend(inline_spans(&entry.text, text_style));
+        if entry.note.is_some() {
+            spans.push(Span::styled(" ¶", Style::default().fg(Color::DarkGray)));
+        }
+    }
+
+    if show_timestamps {
+        if let Some(timestamps) = entry_timestamps(entry) {
+            spans.push(Span::styled(
+                format!("  {timestamps}"),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
     }
 
-    ListItem::new(Line::from(spans))
+    ListItem::new(wrap_spans(prefix, spans, width))
+}
+
+/// Entry text with its inline Markdown shown as styles instead of markers.
+fn inline_spans(text: &str, style: Style) -> Vec<Span<'static>> {
+    parse_inline(text)
+        .into_iter()
+        .map(|run| match run {
+            Inline::Text(text) => Span::styled(text, style),
+            Inline::Bold(text) => Span::styled(text, style.add_modifier(Modifier::BOLD)),
+            Inline::Italic(text) => Span::styled(text, style.add_modifier(Modifier::ITALIC)),
+            Inline::Code(text) => Span::styled(text, style.fg(Color::Green)),
+            Inline::Strikethrough(text) => {
+                Span::styled(text, style.add_modifier(Modifier::CROSSED_OUT))
+            }
+            Inline::Link { label, .. } => Span::styled(
+                label,
+                style.fg(Color::Blue).add_modifier(Modifier::UNDERLINED),
+            ),
+        })
+        .collect()
 }
 
-fn draw_command(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+/// Lays `body` out after `prefix` in lines no wider than `width`, breaking
+/// between words where it can. Lines after the first are indented by the
+/// width of `prefix`, and words too long for a line are broken anywhere.
+fn wrap_spans(
+    prefix: Vec<Span<'static>>,
+    body: Vec<Span<'static>>,
+    width: usize,
+) -> Vec<Line<'static>> {
+    let indent = prefix.iter().map(Span::width).sum::<usize>();
+    let room = width.saturating_sub(indent).max(1);
+    let mut lines = Vec::new();
+    let mut line = prefix;
+    let mut used = 0;
+    let mut space: Option<(String, Style)> = None;
+
+    let mut words = Vec::new();
+    for span in &body {
+        let mut rest = span.content.as_ref();
+        while let Some(first) = rest.chars().next() {
+            let end = rest
+                .find(|character: char| character.is_whitespace() != first.is_whitespace())
+                .unwrap_or(rest.len());
+            words.push((&rest[..end], span.style, first.is_whitespace()));
+            rest = &rest[end..];
+        }
+    }
+
+    for (word, style, is_space) in words {
+        if is_space {
+            if used > 0 {
+                space = Some((word.to_string(), style));
+            }
+            continue;
+        }
+
+        let word_width = text_width(word);
+        let space_width = space.as_ref().map_or(0, |(space, _)| text_width(space));
+        if used > 0 && used + space_width + word_width > room {
+            lines.push(Line::from(std::mem::take(&mut line)));
+            line.push(Span::raw(" ".repeat(indent)));
+            used = 0;
+            space = None;
+        }
+        if let Some((space, style)) = space.take() {
+            used += text_width(&space);
+            push_styled(&mut line, space, style);
+        }
+
+        for character in word.chars() {
+            let width = text_width(character.encode_utf8(&mut [0; 4]));
+            if used > 0 && used + width > room {
+                lines.push(Line::from(std::mem::take(&mut line)));
+                line.push(Span::raw(" ".repeat(indent)));
+                used = 0;
+            }
+            used += width;
+            push_styled(&mut line, character.to_string(), style);
+        }
+    }
+
+    lines.push(Line::from(line));
+    lines
+}
+
+/// Adds text to the end of a line, joining it to the last span when the style
+/// matches.
+fn push_styled(line: &mut Vec<Span<'static>>, text: String, style: Style) {
+    match line.last_mut() {
+        Some(last) if last.style == style => {
+            last.content.to_mut().push_str(&text);
+        }
+        _ => line.push(Span::styled(text, style)),
+    }
+}
+
+fn text_width(text: &str) -> usize {
+    Span::raw(text).width()
+}
+
+/// Lists timed events in start order with the free time between them. Today's
+/// timeline also marks the current time.
+fn draw_timeline(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    app: &App,
+    journal: &Journal,
+    block: Block,
+) {
+    let dim = Style::default().fg(Color::DarkGray);
+    let now = app.now();
+    let mut now_marker = (now.date() == journal.date).then_some(now.time());
+    let mut lines = Vec::new();
+    let mut previous_end: Option<NaiveTime> = None;
+
+    for event in journal.timed_events() {
+        let Some(time) = event.time else {
+            continue;
+        };
+
+        if let Some(end) = previous_end.filter(|end| time.start > *end) {
+            lines.push(Line::from(Span::styled(
+                format!("{:<13}{} free", "", format_duration(time.start - end)),
+                dim,
+            )));
+        }
+
+        if let Some(marker) = now_marker.filter(|marker| *marker < time.start) {
+            lines.push(now_line(marker));
+            now_marker = None;
+        }
+
+        let text_style = if event.is_struck() {
+            Style::default().add_modifier(Modifier::CROSSED_OUT)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:<13}", time.to_string()),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled("◦ ", Style::default().fg(Color::Yellow)),
+            Span::styled(event.text.clone(), text_style),
+        ]));
+
+        let end = time.end_or_start();
+        previous_end = Some(previous_end.map_or(end, |previous| previous.max(end)));
+    }
+
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled("No timed events.", dim)));
+    }
+    if let Some(marker) = now_marker {
+        lines.push(now_line(marker));
+    }
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn now_line(time: NaiveTime) -> Line<'static> {
+    Line::from(Span::styled(
+        format!("── now {} ──", time.format("%H:%M")),
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+    ))
+}
+
+fn format_duration(duration: chrono::TimeDelta) -> String {
+    let minutes = duration.num_minutes();
+    match (minutes / 60, minutes % 60) {
+        (0, minutes) => format!("{minutes}m"),
+        (hours, 0) => format!("{hours}h"),
+        (hours, minutes) => format!("{hours}h {minutes}m"),
+    }
+}
+
+fn entry_timestamps(entry: &JournalEntry) -> Option<String> {
+    let format_time = |at: chrono::NaiveDateTime| {
+        if at.date() == entry.created_on {
+            at.format("%H:%M").to_string()
+        } else {
+            format!("{} {}", at.format("%-m.%-d"), at.format("%H:%M"))
+        }
+    };
+
+    let mut parts = Vec::new();
+    if let Some(created_at) = entry.created_at {
+        parts.push(format_time(created_at));
+    }
+    if let Some(change) = entry.last_state_change() {
+        parts.push(format!(
+            "{} {}",
+            state_change_label(change.state),
+            format_time(change.at)
+        ));
+    }
+
+    (!parts.is_empty()).then(|| parts.join(" · "))
+}
+
+fn draw_command(frame: &mut Frame, area: ratatui::layout::Rect, app: &App, hits: &mut HitMap) {
     let is_focused = matches!(app.focus, Focus::Command);
     let title = match app.focus {
         Focus::Command => app.command_title(),
@@ -188,6 +880,10 @@ fn draw_command(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
             )));
         } else {
             for (index, result) in results {
+                // The border and the input line come before the results.
+                let row = Rect::new(area.x, area.y + lines.len() as u16 + 1, area.width, 1)
+                    .intersection(area);
+                hits.push(row, HitTarget::CommandResult(index));
                 let selected = index == app.command_result_index;
                 let marker_style = if selected {
                     Style::default().fg(Color::Cyan)
@@ -212,9 +908,11 @@ fn draw_command(frame: &mut Frame, area: ratatui::layout::Rect, app: &App) {
         }
     }
 
+    let clock = Line::from(format!(" {} ", app.now().format("%H:%M"))).right_aligned();
     let paragraph = Paragraph::new(lines).block(
         Block::default()
             .title(title)
+            .title(clock)
             .borders(Borders::ALL)
             .border_style(border_style(is_focused)),
     );
@@ -280,7 +978,6 @@ mod tests {
         app.focus = Focus::Command;
         app.command_mode = CommandPaneMode::Search;
         app.command_input = input.to_string();
-        app.status = String::from("Search commands.");
         Ok((app, root))
     }
 
@@ -325,10 +1022,19 @@ mod tests {
     fn render_buffer_with_size(app: &App, width: u16, height: u16) -> io::Result<Buffer> {
         let backend = TestBackend::new(width, height);
         let mut terminal = Terminal::new(backend)?;
-        terminal.draw(|frame| draw(frame, app))?;
+        terminal.draw(|frame| {
+            draw(frame, app);
+        })?;
         Ok(terminal.backend().buffer().clone())
     }
 
+    fn render_hits(app: &App) -> io::Result<HitMap> {
+        let mut terminal = Terminal::new(TestBackend::new(80, 20))?;
+        let mut hits = HitMap::default();
+        terminal.draw(|frame| hits = draw(frame, app))?;
+        Ok(hits)
+    }
+
     fn buffer_text(buffer: &Buffer) -> String {
         let width = buffer.area.width as usize;
         let mut text = String::new();
@@ -447,6 +1153,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn week_view_renders_a_grid_of_days() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        fs::write(root.join("2026-05-23.md"), "- saturday note\n")?;
+        let journal = Journal::load_for_date(&root, date())?;
+        let mut app = App::new(journal);
+        app.handle_key(KeyEvent::new(KeyCode::Char(':'), KeyModifiers::NONE))?;
+        for character in "week".chars() {
+            app.handle_key(KeyEvent::new(KeyCode::Char(character), KeyModifiers::NONE))?;
+        }
+        app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))?;
+
+        let buffer = render_buffer_with_size(&app, 200, 30)?;
+        let monday_row = row_containing(&buffer, "5.18.M");
+        assert_eq!(row_containing(&buffer, "5.21.Th"), monday_row);
+        assert!(row_containing(&buffer, "5.22.F") > monday_row);
+        assert_eq!(
+            row_containing(&buffer, "5.24.Su"),
+            row_containing(&buffer, "5.22.F")
+        );
+        assert!(buffer_text(&buffer).contains("saturday note"));
+
+        let buffer = render_buffer_with_size(&app, 80, 40)?;
+        assert!(row_containing(&buffer, "5.18.M") < row_containing(&buffer, "5.19.Tu"));
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
     #[test]
     fn hides_no_match_message_for_exact_shortcut_input() -> io::Result<()> {
         let (app, root) = search_app("n exact note")?;
@@ -472,13 +1208,476 @@ mod tests {
     }
 
     #[test]
-    fn does_not_render_app_status_in_command_pane() -> io::Result<()> {
-        let (mut app, root) = search_app("n")?;
-        app.status = String::from("Search commands.");
+    fn renders_app_status_in_status_bar_not_command_pane() -> io::Result<()> {
+        let root = test_root();
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+        app.handle_key(key(KeyCode::Char(':')))?;
+
+        let buffer = render_buffer(&app)?;
+        let status_row = row_containing(&buffer, "Search commands.");
+        assert_eq!(status_row, buffer.area.height as usize - 1);
+        assert!(row_containing(&buffer, "Search Commands") < status_row);
+        assert_eq!(
+            row_containing(&buffer, "Thu 2026-05-21 · search · command"),
+            status_row
+        );
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn renders_entry_timestamps_only_when_enabled() -> io::Result<()> {
+        let root = test_root();
+        let mut journal = Journal::load_for_date(&root, date())?;
+        let entry = journal.add_entry(EntryKind::Task, "stamped task");
+        entry.stamp_created(date().and_hms_opt(9, 14, 0).unwrap());
+        entry.toggle_complete().unwrap();
+        entry.record_state_change(
+            NaiveDate::from_ymd_opt(2026, 5, 22)
+                .unwrap()
+                .and_hms_opt(8, 5, 0)
+                .unwrap(),
+        );
+
+        let mut app = App::new(journal);
+        assert!(!render_text(&app)?.contains("09:14"));
+
+        app.show_timestamps = true;
+        let rendered = render_text(&app)?;
+        assert!(rendered.contains("X stamped task  09:14 · completed 5.22 08:05"));
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn renders_timeline_in_time_order_with_gaps_and_now_marker() -> io::Result<()> {
+        let root = test_root();
+        let journal = Journal::load_for_date(&root, date())?;
+        let mut app = App::new(journal);
+        app.clock = || {
+            NaiveDate::from_ymd_opt(2026, 5, 21)
+                .unwrap()
+                .and_hms_opt(11, 15, 0)
+                .unwrap()
+        };
+        app.journal.entries = crate::journal::parse_markdown(
+            "◦ 14:00-15:00 design review\n- a note\n◦ 09:00-10:30 standup\n◦ untimed\n",
+            date(),
+        );
+        app.show_timeline = true;
+
+        let rendered = render_text(&app)?;
+        let lines = rendered
+            .lines()
+            .map(|line| line.trim_matches(|c| c == '│' || c == ' '))
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>();
+        let standup = lines
+            .iter()
+            .position(|line| line.contains("09:00-10:30  ◦ standup"))
+            .expect("standup should be drawn");
+        let gap = lines
+            .iter()
+            .position(|line| line.ends_with("3h 30m free"))
+            .expect("the free time should be drawn");
+        let now = lines
+            .iter()
+            .position(|line| line.contains("── now 11:15 ──"))
+            .expect("the now marker should be drawn");
+        let review = lines
+            .iter()
+            .position(|line| line.contains("14:00-15:00  ◦ design review"))
+            .expect("design review should be drawn");
+        assert!(standup < gap && gap < now && now < review, "{rendered}");
+        assert!(!rendered.contains("a note"));
+        assert!(!rendered.contains("untimed"));
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn renders_recurring_rules_overlay() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        fs::write(
+            root.join("recurring.md"),
+            "- r1 weekly mon from 2026-05-18: ◦ 14:00 1:1\n",
+        )?;
+        let journal = Journal::load_for_date(&root, date())?;
+        let mut app = App::new(journal);
+
+        app.handle_key(KeyEvent::new(KeyCode::Char(':'), KeyModifiers::NONE))?;
+        for character in "recur".chars() {
+            app.handle_key(KeyEvent::new(KeyCode::Char(character), KeyModifiers::NONE))?;
+        }
+        app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))?;
+
+        let rendered = render_text(&app)?;
+        assert!(rendered.contains("Recurring"));
+        assert!(rendered.contains("r1 weekly mon from 2026-05-18: ◦ 14:00 1:1"));
+        assert!(rendered.contains("Esc to close"));
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn renders_calendar_with_day_marks_and_selection() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        fs::write(root.join("2026-05-04.md"), "  - a note\n")?;
+        fs::write(root.join("2026-05-05.md"), "  · open task\n")?;
+        let journal = Journal::load_for_date(&root, date())?;
+        let mut app = App::new(journal);
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('C'), KeyModifiers::SHIFT))?;
+
+        let buffer = render_buffer(&app)?;
+        let rendered = buffer_text(&buffer);
+        assert!(rendered.contains("May 2026"));
+        assert!(rendered.contains("Mo  Tu  We  Th  Fr  Sa  Su"));
+        assert!(rendered.contains(" 4·  5•  6 "));
+
+        let row = row_containing(&buffer, "18  19  20  21");
+        let row_text = (0..buffer.area.width)
+            .map(|x| buffer[(x, row as u16)].symbol())
+            .collect::<String>();
+        let column = row_text[..row_text.find("21").unwrap()].chars().count() as u16;
+        let selected = &buffer[(column, row as u16)];
+        assert!(selected.modifier.contains(Modifier::REVERSED));
+        assert!(selected.modifier.contains(Modifier::UNDERLINED));
+        assert!(!buffer[(column - 4, row as u16)]
+            .modifier
+            .contains(Modifier::REVERSED));
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn renders_clock_in_command_pane_title() -> io::Result<()> {
+        let root = test_root();
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+        app.clock = || date().and_hms_opt(16, 5, 0).unwrap();
+
+        let buffer = render_buffer(&app)?;
+        let row = row_containing(&buffer, "Command");
+        let row_text = (0..buffer.area.width)
+            .map(|x| buffer[(x, row as u16)].symbol())
+            .collect::<String>();
+        assert!(row_text.trim_end().ends_with(" 16:05 ┐"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn renders_fired_reminders_above_the_command_pane() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        fs::write(root.join("2026-05-21.md"), "  ◦ 14:00 standup\n")?;
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+        app.clock = || date().and_hms_opt(13, 55, 0).unwrap();
+        app.tick();
+
+        let buffer = render_buffer(&app)?;
+        let banner = row_containing(&buffer, "Reminder: 2026-05-21 14:00 standup");
+        assert_eq!(row_containing(&buffer, ":snooze or :dismiss"), banner);
+        assert_eq!(banner + 1, row_containing(&buffer, "Command"));
+        assert_eq!(buffer[(0, banner as u16)].bg, Color::Yellow);
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn styles_status_by_severity_and_scrolls_message_history() -> io::Result<()> {
+        let root = test_root();
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+        app.handle_key(key(KeyCode::Char('[')))?;
+        app.handle_key(key(KeyCode::Char('[')))?;
+
+        let buffer = render_buffer(&app)?;
+        let row = row_containing(&buffer, "No earlier day files. ×2");
+        assert_eq!(row, buffer.area.height as usize - 1);
+        assert_eq!(buffer[(1, row as u16)].fg, Color::Yellow);
+
+        app.handle_key(key(KeyCode::Char(':')))?;
+        type_text(&mut app, "messages")?;
+        app.handle_key(key(KeyCode::Enter))?;
+        let rendered = render_text(&app)?;
+        assert!(rendered.contains("Messages 3/3"));
+        assert!(rendered.contains(" Ready."));
+        assert_eq!(rendered.matches("Search commands.").count(), 2);
 
+        app.handle_key(key(KeyCode::Up))?;
         let rendered = render_text(&app)?;
+        assert!(rendered.contains("Messages 2/3"));
+        assert_eq!(rendered.matches("Search commands.").count(), 1);
+
+        app.handle_key(key(KeyCode::Esc))?;
+        assert!(app.overlay.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn renders_help_sections_in_a_scrollable_popup() -> io::Result<()> {
+        let (mut app, root) = search_app("")?;
+        app.handle_key(key(KeyCode::Esc))?;
+        app.handle_key(key(KeyCode::Char('?')))?;
+
+        let buffer = render_buffer_with_size(&app, 80, 30)?;
+        let text = buffer_text(&buffer);
+        assert!(text.contains("Help 1-22/"));
+        assert!(text.contains("Journal pane"));
+        assert!(text.contains("j Down     Highlight the next entry"));
+        assert_eq!(modifier_for_text(&buffer, "Journal pane"), Modifier::BOLD);
+
+        app.handle_key(key(KeyCode::End))?;
+        let text = render_text(&app)?;
+        assert!(text.contains(":help"));
+        assert!(!text.contains("Highlight the previous entry"));
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn renders_leader_menu_levels() -> io::Result<()> {
+        let (mut app, root) = search_app("")?;
+        app.handle_key(key(KeyCode::Esc))?;
+        app.handle_key(key(KeyCode::Char(' ')))?;
+
+        let buffer = render_buffer_with_size(&app, 80, 30)?;
+        let text = buffer_text(&buffer);
+        assert!(text.contains("Leader"));
+        assert!(text.contains(" n  note"));
+        assert!(text.contains(" g  +go to"));
+        assert_eq!(modifier_for_text(&buffer, " n  "), Modifier::BOLD);
+
+        app.handle_key(key(KeyCode::Char('v')))?;
+        let text = render_text(&app)?;
+        assert!(text.contains("Leader v"));
+        assert!(text.contains(" s  split"));
+        assert!(!text.contains(" n  note"));
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn shows_pending_keys_in_the_status_bar() -> io::Result<()> {
+        let (mut app, root) = search_app("")?;
+        app.handle_key(key(KeyCode::Esc))?;
+        type_text(&mut app, "5d")?;
+
+        assert!(render_text(&app)?.contains(" 5d · Thu 2026-05-21 · normal · journal "));
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn highlights_the_visual_range() -> io::Result<()> {
+        let (mut app, root) = search_app("")?;
+        app.journal.entries = crate::journal::parse_markdown("  · a\n  · b\n  · c\n", date());
+        app.handle_key(key(KeyCode::Esc))?;
+        type_text(&mut app, "ggVj")?;
+
+        let buffer = render_buffer_with_size(&app, 80, 20)?;
+        let rows = (0..buffer.area.height)
+            .filter(|&y| {
+                (0..buffer.area.width).any(|x| buffer[(x, y)].style().bg == Some(Color::Blue))
+            })
+            .count();
+        assert_eq!(rows, 1);
+        assert!(render_text(&app)?.contains(" · visual · journal "));
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn maps_entries_panes_and_command_results_for_the_mouse() -> io::Result<()> {
+        let (mut app, root) = split_app()?;
+        let hits = render_hits(&app)?;
+        let text = render_text(&app)?;
+        let today = text
+            .lines()
+            .position(|line| line.contains("today note"))
+            .expect("today should be drawn") as u16;
+        assert_eq!(
+            hits.target_at(1, 1),
+            Some(HitTarget::Entry { pane: 0, index: 0 })
+        );
+        assert_eq!(hits.target_at(1, 2), Some(HitTarget::Pane(0)));
+        assert_eq!(
+            hits.target_at(1, today),
+            Some(HitTarget::Entry { pane: 1, index: 0 })
+        );
+        assert_eq!(hits.target_at(1, today + 1), Some(HitTarget::Pane(1)));
+
+        app.handle_key(key(KeyCode::Char(':')))?;
+        let hits = render_hits(&app)?;
+        let text = render_text(&app)?;
+        let row = text
+            .lines()
+            .position(|line| line.contains("  note :n"))
+            .expect("note should be a search result");
+        // The highlighted note's important, move, copy and details actions
+        // come first.
+        assert_eq!(
+            hits.target_at(3, row as u16),
+            Some(HitTarget::CommandResult(4))
+        );
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn wraps_long_entries_under_their_text() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(&root)?;
+        fs::write(
+            root.join("2026-05-20.md"),
+            "- a deliberately long note that wraps\n",
+        )?;
+        fs::write(
+            root.join("2026-05-21.md"),
+            "- the quick brown fox jumps over the lazy dog\n- short\n",
+        )?;
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+
+        let text = buffer_text(&render_buffer_with_size(&app, 30, 20)?);
+        assert!(text.contains("│  - the quick brown fox     │"));
+        assert!(text.contains("│    jumps over the lazy dog │"));
+        assert!(text.contains("│  - short"));
+
+        toggle_split(&mut app)?;
+        let text = buffer_text(&render_buffer_with_size(&app, 24, 24)?);
+        assert!(text.contains("│  - a deliberately    │"));
+        assert!(text.contains("│    long note that    │"));
+        assert!(text.contains("│    wraps             │"));
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn scrolls_with_padding_around_the_highlight_and_a_scrollbar() -> io::Result<()> {
+        let (mut app, root) = search_app("")?;
+        let contents = (0..30)
+            .map(|index| format!("- note {index}\n"))
+            .collect::<String>();
+        app.journal.entries = crate::journal::parse_markdown(&contents, date());
+        app.handle_key(key(KeyCode::Esc))?;
+        app.selected = Some(20);
+
+        let buffer = render_buffer_with_size(&app, 30, 20)?;
+        let text = buffer_text(&buffer);
+        assert!(text.contains("- note 9 "));
+        assert!(!text.contains("- note 8 "));
+        assert!(text.contains("- note 22"));
+        assert!(!text.contains("- note 23"));
+        let thumb = (1..15)
+            .filter(|&y| buffer[(29, y)].symbol() == "█")
+            .collect::<Vec<_>>();
+        assert_eq!(thumb.len(), 7);
+
+        let hits = render_hits(&app)?;
+        assert_eq!(
+            hits.target_at(1, 1),
+            Some(HitTarget::Entry { pane: 0, index: 9 })
+        );
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn styles_inline_markdown_in_entry_text() -> io::Result<()> {
+        let (mut app, root) = search_app("")?;
+        app.journal.entries = crate::journal::parse_markdown(
+            "- ship **v2** via `make` per [spec](https://example.com) ~~later~~ _today_\n",
+            date(),
+        );
+
+        let buffer = render_buffer(&app)?;
+        let text = buffer_text(&buffer);
+        assert!(text.contains("- ship v2 via make per spec later today"));
+        assert!(modifier_for_text(&buffer, "v2").contains(Modifier::BOLD));
+        assert!(modifier_for_text(&buffer, "spec").contains(Modifier::UNDERLINED));
+        assert!(modifier_for_text(&buffer, "later").contains(Modifier::CROSSED_OUT));
+        assert!(modifier_for_text(&buffer, "today").contains(Modifier::ITALIC));
+        assert_eq!(app.journal.entries[0].text.matches("**").count(), 2);
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn renders_the_link_picker() -> io::Result<()> {
+        let (mut app, root) = search_app("")?;
+        app.overlay = Some(Overlay::Links {
+            links: vec![
+                String::from("https://example.com"),
+                String::from("./plan.md"),
+            ],
+            selected: 1,
+            copy: true,
+        });
+
+        let buffer = render_buffer(&app)?;
+        let text = buffer_text(&buffer);
+        assert!(text.contains("Copy link"));
+        assert!(text.contains("  https://example.com"));
+        assert!(text.contains("> ./plan.md"));
+        assert!(modifier_for_text(&buffer, "./plan.md").contains(Modifier::BOLD));
+
+        let _ = fs::remove_dir_all(root);
+        Ok(())
+    }
+
+    #[test]
+    fn previews_the_highlighted_entrys_detail_note() -> io::Result<()> {
+        let root = test_root();
+        fs::create_dir_all(root.join("notes"))?;
+        fs::write(
+            root.join("2026-05-21.md"),
+            "◦ planning <!-- note 2026-05-21-1 -->\n- loose\n",
+        )?;
+        fs::write(
+            root.join("notes").join("2026-05-21-1.md"),
+            "# Planning\n\nAgenda: **budget** and hiring.\n",
+        )?;
+        let mut app = App::new(Journal::load_for_date(&root, date())?);
+        app.clock = || date().and_hms_opt(9, 0, 0).unwrap();
+        app.selected = Some(0);
+        app.tick();
+
+        let buffer = render_buffer(&app)?;
+        assert_eq!(row_containing(&buffer, "Details 2026-05-21-1"), 0);
+        assert_eq!(
+            row_containing(&buffer, "◦ planning ¶"),
+            row_containing(&buffer, "# Planning")
+        );
+        assert!(buffer_text(&buffer).contains("Agenda: budget and hiring."));
+        assert!(modifier_for_text(&buffer, "# Planning").contains(Modifier::BOLD));
+        assert!(modifier_for_text(&buffer, "budget").contains(Modifier::BOLD));
+
+        let narrow = render_buffer_with_size(&app, 60, 24)?;
+        assert!(
+            row_containing(&narrow, "Details 2026-05-21-1")
+                > row_containing(&narrow, "◦ planning ¶")
+        );
 
-        assert!(!rendered.contains("Search commands."));
+        app.selected = Some(1);
+        app.tick();
+        assert!(!render_text(&app)?.contains("Details"));
 
         let _ = fs::remove_dir_all(root);
         Ok(())